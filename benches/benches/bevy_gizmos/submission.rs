@@ -0,0 +1,74 @@
+use bevy_app::{App, Update};
+use bevy_asset::{AssetApp, AssetPlugin};
+use bevy_core::{TaskPoolPlugin, TypeRegistrationPlugin};
+use bevy_ecs::schedule::IntoSystemConfigs;
+use bevy_gizmos::{prelude::*, GizmoPlugin};
+use bevy_math::prelude::*;
+use bevy_render::{color::Color, render_resource::Shader};
+use bevy_time::TimePlugin;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+criterion_group!(benches, lines, strips, circles);
+criterion_main!(benches);
+
+const COUNTS: [u32; 2] = [1_000, 10_000];
+
+/// An app running `system` every update, which submits gizmos without rendering them.
+fn app<M>(system: impl IntoSystemConfigs<M>) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        TaskPoolPlugin::default(),
+        TypeRegistrationPlugin,
+        TimePlugin,
+        AssetPlugin::default(),
+    ))
+    .init_asset::<Shader>()
+    .add_plugins(GizmoPlugin)
+    .add_systems(Update, system);
+    app
+}
+
+fn lines(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gizmo_lines");
+    for count in COUNTS {
+        let mut app = app(move |mut gizmos: Gizmos| {
+            for i in 0..count {
+                let x = i as f32;
+                gizmos.line(Vec3::new(x, 0., 0.), Vec3::new(x, 1., 0.), Color::RED);
+            }
+        });
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter(|| app.update());
+        });
+    }
+    group.finish();
+}
+
+fn strips(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gizmo_strips");
+    for count in COUNTS {
+        let mut app = app(move |mut gizmos: Gizmos| {
+            let positions = (0..count).map(|i| Vec3::new(i as f32, (i % 2) as f32, 0.));
+            gizmos.linestrip(positions, Color::RED);
+        });
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter(|| app.update());
+        });
+    }
+    group.finish();
+}
+
+fn circles(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gizmo_circles");
+    for count in COUNTS {
+        let mut app = app(move |mut gizmos: Gizmos| {
+            for i in 0..count {
+                gizmos.circle(Vec3::new(i as f32, 0., 0.), Vec3::Z, 1., Color::RED);
+            }
+        });
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter(|| app.update());
+        });
+    }
+    group.finish();
+}
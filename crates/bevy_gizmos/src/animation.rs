@@ -0,0 +1,290 @@
+//! Gizmos for animations.
+//!
+//! Includes the [`AnimationPathGizmo`] component, the `AnimationPathGizmoPlugin` drawing it
+//! and the implementation of `Gizmos::animation_path`.
+
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+
+#[cfg(feature = "bevy_animation")]
+use crate::{color_from_entity, gizmos::Gizmos, GizmoConfig};
+#[cfg(feature = "bevy_animation")]
+use bevy_animation::{
+    AnimationClip, AnimationPlayer, EntityPath, Interpolation, Keyframes, VariableCurve,
+};
+#[cfg(feature = "bevy_animation")]
+use bevy_app::{Plugin, PostUpdate};
+#[cfg(feature = "bevy_animation")]
+use bevy_asset::Assets;
+#[cfg(feature = "bevy_animation")]
+use bevy_core::Name;
+#[cfg(feature = "bevy_animation")]
+use bevy_ecs::{
+    entity::Entity,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res},
+};
+#[cfg(feature = "bevy_animation")]
+use bevy_hierarchy::Parent;
+#[cfg(feature = "bevy_animation")]
+use bevy_math::Vec3;
+#[cfg(feature = "bevy_animation")]
+use bevy_transform::{components::GlobalTransform, TransformPoint, TransformSystem};
+
+/// Configuration for drawing the paths of animated entities.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct AnimationPathGizmoConfig {
+    /// The default color for animation paths.
+    ///
+    /// A random color is chosen per entity if `None`.
+    ///
+    /// Defaults to `None`.
+    pub default_color: Option<Color>,
+    /// The length of the ticks marking the keyframes of animation paths.
+    ///
+    /// No ticks are drawn if `None`.
+    ///
+    /// Defaults to `Some(0.05)`.
+    pub keyframe_tick_length: Option<f32>,
+}
+
+impl Default for AnimationPathGizmoConfig {
+    fn default() -> Self {
+        Self {
+            default_color: None,
+            keyframe_tick_length: Some(0.05),
+        }
+    }
+}
+
+/// Add this [`Component`] to an entity animated by an `AnimationPlayer` on itself
+/// or one of its ancestors to draw the path the current animation moves it along.
+///
+/// The entity and its ancestors up to the animation player must have a `Name`
+/// so that the [`EntityPath`](bevy_animation::EntityPath) of its curves can be found,
+/// like when the animation is played.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct AnimationPathGizmo {
+    /// The color of the path.
+    ///
+    /// The default color from the [`GizmoConfig`](crate::GizmoConfig) resource is used if `None`.
+    pub color: Option<Color>,
+}
+
+/// The number of line-segments drawn between two keyframes of a cubic spline curve.
+#[cfg(feature = "bevy_animation")]
+const CUBIC_SPLINE_SEGMENTS: usize = 8;
+
+#[cfg(feature = "bevy_animation")]
+impl<'s> Gizmos<'s> {
+    /// Draw the path of the translation keyframes of `curve`, transformed by `transform`,
+    /// which is usually the [`GlobalTransform`] of the animated entity's parent.
+    ///
+    /// Curves that don't animate a translation aren't drawn.
+    ///
+    /// This should be called for each frame the path needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_animation::{Interpolation, Keyframes, VariableCurve};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     let curve = VariableCurve {
+    ///         keyframe_timestamps: vec![0., 1., 2.],
+    ///         keyframes: Keyframes::Translation(vec![Vec3::ZERO, Vec3::X, Vec3::ONE]),
+    ///         interpolation: Interpolation::Linear,
+    ///     };
+    ///     gizmos
+    ///         .animation_path(&curve, GlobalTransform::IDENTITY, Color::YELLOW)
+    ///         .keyframe_ticks(0.05);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn animation_path<'a, 'c, T: TransformPoint>(
+        &'a mut self,
+        curve: &'c VariableCurve,
+        transform: T,
+        color: Color,
+    ) -> AnimationPathBuilder<'a, 's, 'c, T> {
+        AnimationPathBuilder {
+            gizmos: self,
+            curve,
+            transform,
+            color,
+            keyframe_ticks: None,
+        }
+    }
+}
+
+/// A builder returned by `Gizmos::animation_path`.
+#[cfg(feature = "bevy_animation")]
+pub struct AnimationPathBuilder<'a, 's, 'c, T: TransformPoint> {
+    gizmos: &'a mut Gizmos<'s>,
+    curve: &'c VariableCurve,
+    transform: T,
+    color: Color,
+    keyframe_ticks: Option<f32>,
+}
+
+#[cfg(feature = "bevy_animation")]
+impl<T: TransformPoint> AnimationPathBuilder<'_, '_, '_, T> {
+    /// Mark each keyframe with ticks of `length` along the X, Y and Z axes.
+    pub fn keyframe_ticks(mut self, length: f32) -> Self {
+        self.keyframe_ticks = Some(length);
+        self
+    }
+}
+
+#[cfg(feature = "bevy_animation")]
+impl<T: TransformPoint> Drop for AnimationPathBuilder<'_, '_, '_, T> {
+    fn drop(&mut self) {
+        let Keyframes::Translation(keyframes) = &self.curve.keyframes else {
+            return;
+        };
+        let timestamps = &self.curve.keyframe_timestamps;
+        let transform = &self.transform;
+
+        // The values of cubic spline keyframes are between their in and out tangents.
+        let cubic_spline = matches!(self.curve.interpolation, Interpolation::CubicSpline);
+        let (first_value, stride) = if cubic_spline { (1, 3) } else { (0, 1) };
+        let values = keyframes.iter().skip(first_value).step_by(stride);
+
+        if cubic_spline {
+            let positions = timestamps.windows(2).enumerate().flat_map(|(i, window)| {
+                let duration = window[1] - window[0];
+                let value_start = keyframes[i * 3 + 1];
+                let tangent_out_start = keyframes[i * 3 + 2];
+                let tangent_in_end = keyframes[(i + 1) * 3];
+                let value_end = keyframes[(i + 1) * 3 + 1];
+                // The end of each step is the start of the next one, except for the last step.
+                let last = usize::from(i + 2 == timestamps.len());
+                (0..CUBIC_SPLINE_SEGMENTS + last).map(move |segment| {
+                    let t = segment as f32 / CUBIC_SPLINE_SEGMENTS as f32;
+                    let value = cubic_spline_interpolation(
+                        value_start,
+                        tangent_out_start,
+                        tangent_in_end,
+                        value_end,
+                        t,
+                        duration,
+                    );
+                    transform.transform_point(value)
+                })
+            });
+            self.gizmos.linestrip(positions, self.color);
+        } else {
+            let positions = values
+                .clone()
+                .map(|&value| transform.transform_point(value));
+            self.gizmos.linestrip(positions, self.color);
+        }
+
+        if let Some(length) = self.keyframe_ticks {
+            for &value in values {
+                for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+                    let offset = axis * length / 2.;
+                    self.gizmos.line(
+                        transform.transform_point(value - offset),
+                        transform.transform_point(value + offset),
+                        self.color,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Mirrors the cubic spline interpolation of `bevy_animation`.
+#[cfg(feature = "bevy_animation")]
+fn cubic_spline_interpolation(
+    value_start: Vec3,
+    tangent_out_start: Vec3,
+    tangent_in_end: Vec3,
+    value_end: Vec3,
+    t: f32,
+    duration: f32,
+) -> Vec3 {
+    value_start * (2. * t.powi(3) - 3. * t.powi(2) + 1.)
+        + tangent_out_start * duration * (t.powi(3) - 2. * t.powi(2) + t)
+        + value_end * (-2. * t.powi(3) + 3. * t.powi(2))
+        + tangent_in_end * duration * (t.powi(3) - t.powi(2))
+}
+
+/// A [`Plugin`] that draws the [`AnimationPathGizmo`]s,
+/// added by the [`GizmoPlugin`](crate::GizmoPlugin).
+#[cfg(feature = "bevy_animation")]
+pub struct AnimationPathGizmoPlugin;
+
+#[cfg(feature = "bevy_animation")]
+impl Plugin for AnimationPathGizmoPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(
+            PostUpdate,
+            draw_animation_paths.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+#[cfg(feature = "bevy_animation")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn draw_animation_paths(
+    query: Query<(Entity, &AnimationPathGizmo)>,
+    players: Query<&AnimationPlayer>,
+    names: Query<&Name>,
+    parents: Query<&Parent>,
+    transforms: Query<&GlobalTransform>,
+    clips: Res<Assets<AnimationClip>>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let animation_config = &config.animation_path;
+    for (entity, gizmo) in &query {
+        // Walk up to the animation player, collecting the names of the path in reverse.
+        let mut path = EntityPath::default();
+        let mut current = entity;
+        let player = loop {
+            let Ok(name) = names.get(current) else {
+                break None;
+            };
+            path.parts.push(name.clone());
+            if let Ok(player) = players.get(current) {
+                break Some(player);
+            }
+            let Ok(parent) = parents.get(current) else {
+                break None;
+            };
+            current = parent.get();
+        };
+        let Some(curves) = player
+            .and_then(|player| clips.get(player.animation_clip()))
+            .and_then(|clip| {
+                path.parts.reverse();
+                clip.get_curves_by_path(&path)
+            })
+        else {
+            continue;
+        };
+
+        let color = gizmo
+            .color
+            .or(animation_config.default_color)
+            .unwrap_or_else(|| color_from_entity(entity));
+        let parent_transform = parents
+            .get(entity)
+            .and_then(|parent| transforms.get(parent.get()))
+            .copied()
+            .unwrap_or_default();
+        for curve in curves {
+            let builder = gizmos.animation_path(curve, parent_transform, color);
+            if let Some(length) = animation_config.keyframe_tick_length {
+                builder.keyframe_ticks(length);
+            }
+        }
+    }
+}
@@ -3,11 +3,14 @@
 //! Includes the implementation of [`Gizmos::arc_2d`],
 //! and assorted support items.
 
-use crate::circles::DEFAULT_CIRCLE_SEGMENTS;
+use crate::circles::arc_positions;
+use crate::colormap::GizmoColormap;
+use crate::gizmos::{skip_invalid, PendingArc};
 use crate::prelude::Gizmos;
-use bevy_math::Vec2;
+use crate::testing::CapturedShape;
+use bevy_math::{Quat, Vec2};
 use bevy_render::color::Color;
-use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
 
 impl<'s> Gizmos<'s> {
     /// Draw an arc, which is a part of the circumference of a circle, in 2D.
@@ -30,7 +33,8 @@ impl<'s> Gizmos<'s> {
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.arc_2d(Vec2::ZERO, 0., PI / 4., 1., Color::GREEN);
     ///
-    ///     // Arcs have 32 line-segments by default.
+    ///     // A full circle has 32 line-segments by default and arcs a proportional amount,
+    ///     // or as many as their size on screen requires if `GizmoConfig::adaptive_segments` is enabled.
     ///     // You may want to increase this for larger arcs.
     ///     gizmos
     ///         .arc_2d(Vec2::ZERO, 0., PI / 4., 5., Color::RED)
@@ -41,7 +45,7 @@ impl<'s> Gizmos<'s> {
     #[inline]
     pub fn arc_2d(
         &mut self,
-        position: Vec2,
+        position: impl Into<Vec2>,
         direction_angle: f32,
         arc_angle: f32,
         radius: f32,
@@ -49,7 +53,7 @@ impl<'s> Gizmos<'s> {
     ) -> Arc2dBuilder<'_, 's> {
         Arc2dBuilder {
             gizmos: self,
-            position,
+            position: position.into(),
             direction_angle,
             arc_angle,
             radius,
@@ -76,33 +80,69 @@ impl Arc2dBuilder<'_, '_> {
         self.segments = Some(segments);
         self
     }
+
+    /// Set the color to that of `value` within `range` in `colormap`.
+    pub fn color_by(
+        mut self,
+        value: f32,
+        range: RangeInclusive<f32>,
+        colormap: &GizmoColormap,
+    ) -> Self {
+        self.color = colormap.color_by(value, range);
+        self
+    }
 }
 
 impl Drop for Arc2dBuilder<'_, '_> {
     fn drop(&mut self) {
-        let segments = match self.segments {
-            Some(segments) => segments,
-            // Do a linear interpolation between 1 and `DEFAULT_CIRCLE_SEGMENTS`
-            // using the arc angle as scalar.
-            None => ((self.arc_angle.abs() / TAU) * DEFAULT_CIRCLE_SEGMENTS as f32).ceil() as usize,
+        skip_invalid!(
+            self.radius < 0.
+                || !self.radius.is_finite()
+                || !self.position.is_finite()
+                || !self.direction_angle.is_finite()
+                || !self.arc_angle.is_finite()
+                || self.segments == Some(0),
+            "Skipped drawing an arc with a negative or non-finite radius, a non-finite position or angle or zero segments, got a radius of {} at {}, the angles {} and {} and {:?} segments",
+            self.radius,
+            self.position,
+            self.direction_angle,
+            self.arc_angle,
+            self.segments
+        );
+        if self.radius == 0. || self.arc_angle == 0. {
+            return;
+        }
+        let shape = || CapturedShape::Arc2d {
+            position: self.position,
+            direction_angle: self.direction_angle,
+            arc_angle: self.arc_angle,
+            radius: self.radius,
+            color: self.color,
         };
+        self.gizmos.draw_2d(true, |gizmos| {
+            gizmos.capture(shape, |gizmos| {
+                let start_angle = self.direction_angle - self.arc_angle / 2.;
+                let Some(segments) = self.segments else {
+                    // The number of segments is proportional to the arc angle,
+                    // see `circles::resolve_pending_arcs`.
+                    gizmos.pending_arc(PendingArc {
+                        center: self.position.extend(0.),
+                        rotation: Quat::IDENTITY,
+                        radius: self.radius,
+                        start_angle,
+                        arc_angle: self.arc_angle,
+                        color: self.color.as_linear_rgba_f32(),
+                        billboard: false,
+                        view: None,
+                        tag: None,
+                    });
+                    return;
+                };
 
-        let positions = arc_inner(self.direction_angle, self.arc_angle, self.radius, segments)
-            .map(|vec2| vec2 + self.position);
-        self.gizmos.linestrip_2d(positions, self.color);
+                let positions = arc_positions(start_angle, self.arc_angle, self.radius, segments)
+                    .map(|vec2| vec2 + self.position);
+                gizmos.linestrip_2d(positions, self.color);
+            });
+        });
     }
 }
-
-fn arc_inner(
-    direction_angle: f32,
-    arc_angle: f32,
-    radius: f32,
-    segments: usize,
-) -> impl Iterator<Item = Vec2> {
-    (0..segments + 1).map(move |i| {
-        let start = direction_angle - arc_angle / 2.;
-
-        let angle = start + (i as f32 * (arc_angle / segments as f32));
-        Vec2::from(angle.sin_cos()) * radius
-    })
-}
@@ -1,11 +1,15 @@
 //! Additional [`Gizmos`] Functions -- Arrows
 //!
-//! Includes the implementation of [`Gizmos::arrow`] and [`Gizmos::arrow_2d`],
+//! Includes the implementation of [`Gizmos::arrow`], [`Gizmos::arrow_2d`] and [`Gizmos::axes`],
 //! and assorted support items.
 
-use crate::prelude::Gizmos;
+use crate::{
+    colormap::GizmoColormap, gizmos::skip_invalid, prelude::Gizmos, testing::CapturedShape,
+};
 use bevy_math::{Quat, Vec2, Vec3};
 use bevy_render::color::Color;
+use bevy_transform::TransformPoint;
+use std::ops::RangeInclusive;
 
 /// A builder returned by [`Gizmos::arrow`] and [`Gizmos::arrow_2d`]
 pub struct ArrowBuilder<'a, 's> {
@@ -14,6 +18,8 @@ pub struct ArrowBuilder<'a, 's> {
     end: Vec3,
     color: Color,
     tip_length: f32,
+    /// Whether the arrow was drawn with [`Gizmos::arrow_2d`].
+    is_2d: bool,
 }
 
 impl ArrowBuilder<'_, '_> {
@@ -35,31 +41,61 @@ impl ArrowBuilder<'_, '_> {
     pub fn with_tip_length(&mut self, length: f32) {
         self.tip_length = length;
     }
+
+    /// Set the color to that of `value` within `range` in `colormap`.
+    pub fn color_by(
+        mut self,
+        value: f32,
+        range: RangeInclusive<f32>,
+        colormap: &GizmoColormap,
+    ) -> Self {
+        self.color = colormap.color_by(value, range);
+        self
+    }
 }
 
 impl Drop for ArrowBuilder<'_, '_> {
     /// Draws the arrow, by drawing lines with the stored [`Gizmos`]
     fn drop(&mut self) {
-        // first, draw the body of the arrow
-        self.gizmos.line(self.start, self.end, self.color);
-        // now the hard part is to draw the head in a sensible way
-        // put us in a coordinate system where the arrow is pointing towards +x and ends at the origin
-        let pointing = (self.end - self.start).normalize();
-        let rotation = Quat::from_rotation_arc(Vec3::X, pointing);
-        let tips = [
-            Vec3::new(-1., 1., 0.),
-            Vec3::new(-1., 0., 1.),
-            Vec3::new(-1., -1., 0.),
-            Vec3::new(-1., 0., -1.),
-        ];
-        // - extend the vectors so their length is `tip_length`
-        // - rotate the world so +x is facing in the same direction as the arrow
-        // - translate over to the tip of the arrow
-        let tips = tips.map(|v| rotation * (v.normalize() * self.tip_length) + self.end);
-        for v in tips {
-            // then actually draw the tips
-            self.gizmos.line(self.end, v, self.color);
+        skip_invalid!(
+            !self.start.is_finite() || !self.end.is_finite() || !self.tip_length.is_finite(),
+            "Skipped drawing an arrow with a non-finite position, got an arrow from {} to {}",
+            self.start,
+            self.end
+        );
+        // An arrow of zero length has no direction to point its tips in.
+        if self.start == self.end {
+            return;
         }
+        let shape = || CapturedShape::Arrow {
+            start: self.start,
+            end: self.end,
+            color: self.color,
+        };
+        self.gizmos.draw_2d(self.is_2d, |gizmos| {
+            gizmos.capture(shape, |gizmos| {
+                // first, draw the body of the arrow
+                gizmos.line(self.start, self.end, self.color);
+                // now the hard part is to draw the head in a sensible way
+                // put us in a coordinate system where the arrow is pointing towards +x and ends at the origin
+                let pointing = (self.end - self.start).normalize();
+                let rotation = Quat::from_rotation_arc(Vec3::X, pointing);
+                let tips = [
+                    Vec3::new(-1., 1., 0.),
+                    Vec3::new(-1., 0., 1.),
+                    Vec3::new(-1., -1., 0.),
+                    Vec3::new(-1., 0., -1.),
+                ];
+                // - extend the vectors so their length is `tip_length`
+                // - rotate the world so +x is facing in the same direction as the arrow
+                // - translate over to the tip of the arrow
+                let tips = tips.map(|v| rotation * (v.normalize() * self.tip_length) + self.end);
+                for v in tips {
+                    // then actually draw the tips
+                    gizmos.line(self.end, v, self.color);
+                }
+            });
+        });
     }
 }
 
@@ -78,7 +114,13 @@ impl<'s> Gizmos<'s> {
     /// }
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
-    pub fn arrow(&mut self, start: Vec3, end: Vec3, color: Color) -> ArrowBuilder<'_, 's> {
+    pub fn arrow(
+        &mut self,
+        start: impl Into<Vec3>,
+        end: impl Into<Vec3>,
+        color: Color,
+    ) -> ArrowBuilder<'_, 's> {
+        let (start, end) = (start.into(), end.into());
         let length = (end - start).length();
         ArrowBuilder {
             gizmos: self,
@@ -86,6 +128,7 @@ impl<'s> Gizmos<'s> {
             end,
             color,
             tip_length: length / 10.,
+            is_2d: false,
         }
     }
 
@@ -103,7 +146,44 @@ impl<'s> Gizmos<'s> {
     /// }
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
-    pub fn arrow_2d(&mut self, start: Vec2, end: Vec2, color: Color) -> ArrowBuilder<'_, 's> {
-        self.arrow(start.extend(0.), end.extend(0.), color)
+    pub fn arrow_2d(
+        &mut self,
+        start: impl Into<Vec2>,
+        end: impl Into<Vec2>,
+        color: Color,
+    ) -> ArrowBuilder<'_, 's> {
+        let mut arrow = self.arrow(start.into().extend(0.), end.into().extend(0.), color);
+        arrow.is_2d = true;
+        arrow
+    }
+
+    /// Draw the local X, Y and Z axes of `transform` as red, green and blue arrows.
+    ///
+    /// The arrows are `base_length` long before being scaled by the transform.
+    ///
+    /// This should be called for each frame the axes need to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// fn system(query: Query<&GlobalTransform>, mut gizmos: Gizmos) {
+    ///     for &transform in &query {
+    ///         gizmos.axes(transform, 1.);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn axes(&mut self, transform: impl TransformPoint, base_length: f32) {
+        let start = transform.transform_point(Vec3::ZERO);
+        for (axis, color) in [
+            (Vec3::X, Color::RED),
+            (Vec3::Y, Color::GREEN),
+            (Vec3::Z, Color::BLUE),
+        ] {
+            let end = transform.transform_point(axis * base_length);
+            self.arrow(start, end, color);
+        }
     }
 }
@@ -0,0 +1,170 @@
+//! Gizmos for spatial audio.
+//!
+//! Includes the [`AudioGizmo`] component and the `AudioGizmoPlugin` drawing it.
+
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+
+#[cfg(feature = "bevy_audio")]
+use crate::{gizmos::Gizmos, GizmoConfig};
+#[cfg(feature = "bevy_audio")]
+use bevy_app::{Plugin, PostUpdate};
+#[cfg(feature = "bevy_audio")]
+use bevy_audio::{PlaybackSettings, SpatialAudioSink, SpatialListener, SpatialScale};
+#[cfg(feature = "bevy_audio")]
+use bevy_ecs::{
+    query::AnyOf,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res},
+};
+#[cfg(feature = "bevy_audio")]
+use bevy_math::{Quat, Vec2, Vec3};
+#[cfg(feature = "bevy_audio")]
+use bevy_transform::{components::GlobalTransform, TransformSystem};
+#[cfg(feature = "bevy_audio")]
+use std::f32::consts::TAU;
+
+/// Configuration for drawing spatial audio emitters and listeners.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct AudioGizmoConfig {
+    /// Draws all spatial audio emitters and listeners in the scene when set to `true`.
+    ///
+    /// To draw a specific emitter or listener, you can add the [`AudioGizmo`] component.
+    ///
+    /// Defaults to `false`.
+    pub draw_all: bool,
+    /// The default color for spatial audio emitters.
+    ///
+    /// Defaults to `Color::ORANGE`.
+    pub emitter_color: Color,
+    /// The default color for spatial audio listeners.
+    ///
+    /// Defaults to `Color::CYAN`.
+    pub listener_color: Color,
+    /// The volumes to draw the range of spatial audio emitters at.
+    ///
+    /// Spatial audio is attenuated with the inverse square of its distance to the listener's ears
+    /// after being scaled by the `SpatialScale` resource, but never amplified,
+    /// so a volume of `1.0` draws the range within which an emitter is heard at its full volume.
+    ///
+    /// Defaults to `vec![1.0, 0.25]`.
+    pub volumes: Vec<f32>,
+}
+
+impl Default for AudioGizmoConfig {
+    fn default() -> Self {
+        Self {
+            draw_all: false,
+            emitter_color: Color::ORANGE,
+            listener_color: Color::CYAN,
+            volumes: vec![1., 0.25],
+        }
+    }
+}
+
+/// Add this [`Component`] to a spatial audio emitter or listener to draw it.
+///
+/// An emitter is drawn as the ranges at which it is attenuated to each of the
+/// [`AudioGizmoConfig::volumes`], and a listener as the line between its ears
+/// and an arrow in the direction it is facing.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct AudioGizmo {
+    /// The color of the audio gizmo.
+    ///
+    /// The default emitter or listener color from the [`GizmoConfig`](crate::GizmoConfig)
+    /// resource is used if `None`.
+    pub color: Option<Color>,
+}
+
+/// A [`Plugin`] that draws the [`AudioGizmo`]s, added by the [`GizmoPlugin`](crate::GizmoPlugin).
+#[cfg(feature = "bevy_audio")]
+pub struct AudioGizmoPlugin;
+
+#[cfg(feature = "bevy_audio")]
+impl Plugin for AudioGizmoPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(
+            PostUpdate,
+            draw_audio.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// The number of line-segments of the ellipses drawn for the range of emitters.
+#[cfg(feature = "bevy_audio")]
+const RANGE_SEGMENTS: usize = 32;
+
+#[cfg(feature = "bevy_audio")]
+pub(crate) fn draw_audio(
+    emitters: Query<(
+        &GlobalTransform,
+        AnyOf<(&PlaybackSettings, &SpatialAudioSink)>,
+        Option<&AudioGizmo>,
+    )>,
+    listeners: Query<(&GlobalTransform, &SpatialListener, Option<&AudioGizmo>)>,
+    spatial_scale: Option<Res<SpatialScale>>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let audio_config = &config.audio;
+    let is_drawn = |gizmo: Option<&AudioGizmo>| audio_config.draw_all || gizmo.is_some();
+
+    // The range at a volume of 1 along each axis, infinite along the axes the scale ignores.
+    let unit_range = spatial_scale.map_or(Vec3::ONE, |scale| scale.0.abs().recip());
+    for (transform, (settings, sink), gizmo) in &emitters {
+        let spatial = sink.is_some() || settings.is_some_and(|settings| settings.spatial);
+        if !spatial || !is_drawn(gizmo) {
+            continue;
+        }
+        let color = gizmo
+            .and_then(|gizmo| gizmo.color)
+            .unwrap_or(audio_config.emitter_color);
+        let center = transform.translation();
+        for &volume in &audio_config.volumes {
+            if volume <= 0. {
+                continue;
+            }
+            let range = unit_range / volume.sqrt();
+            for (rotation, radii) in [
+                (Quat::IDENTITY, Vec2::new(range.x, range.y)),
+                (Quat::from_rotation_x(TAU / 4.), Vec2::new(range.x, range.z)),
+                (Quat::from_rotation_y(TAU / 4.), Vec2::new(range.z, range.y)),
+            ] {
+                draw_ellipse(&mut gizmos, center, rotation, radii, color);
+            }
+        }
+    }
+
+    for (transform, listener, gizmo) in &listeners {
+        if !is_drawn(gizmo) {
+            continue;
+        }
+        let color = gizmo
+            .and_then(|gizmo| gizmo.color)
+            .unwrap_or(audio_config.listener_color);
+        let left_ear = transform.transform_point(listener.left_ear_offset);
+        let right_ear = transform.transform_point(listener.right_ear_offset);
+        gizmos.line(left_ear, right_ear, color);
+
+        let start = transform.translation();
+        let length = left_ear.distance(right_ear) / 2.;
+        let direction = transform.forward().normalize_or_zero();
+        gizmos.arrow(start, start + direction * length, color);
+    }
+}
+
+/// Draws an ellipse in the local XY plane of `rotation`, skipping it if a radius is infinite.
+#[cfg(feature = "bevy_audio")]
+fn draw_ellipse(gizmos: &mut Gizmos, center: Vec3, rotation: Quat, radii: Vec2, color: Color) {
+    if !radii.is_finite() {
+        return;
+    }
+    let positions = (0..=RANGE_SEGMENTS).map(|i| {
+        let angle = i as f32 * TAU / RANGE_SEGMENTS as f32;
+        center + rotation * (Vec2::from(angle.sin_cos()) * radii).extend(0.)
+    });
+    gizmos.linestrip(positions, color);
+}
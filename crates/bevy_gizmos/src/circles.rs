@@ -1,18 +1,40 @@
 //! Additional [`Gizmos`] Functions -- Circles
 //!
-//! Includes the implementation of [`Gizmos::circle`] and [`Gizmos::circle_2d`],
+//! Includes the implementation of [`Gizmos::circle`], [`Gizmos::circle_2d`]
+//! and [`Gizmos::billboard_circle`],
 //! and assorted support items.
 
-use crate::prelude::Gizmos;
-use bevy_math::{Quat, Vec2, Vec3};
-use bevy_render::color::Color;
-use std::f32::consts::TAU;
+use crate::{
+    colormap::GizmoColormap,
+    gizmos::{skip_invalid, GizmoStorage, PendingArc},
+    prelude::Gizmos,
+    testing::CapturedShape,
+    GizmoConfig,
+};
+use bevy_ecs::{
+    entity::Entity,
+    system::{Local, Query, Res, ResMut},
+};
+use bevy_math::{Mat4, Quat, Vec2, Vec3};
+use bevy_render::{camera::Camera, color::Color};
+use bevy_transform::components::GlobalTransform;
+use std::{f32::consts::TAU, iter, mem, ops::RangeInclusive};
 
 pub(crate) const DEFAULT_CIRCLE_SEGMENTS: usize = 32;
 
 fn circle_inner(radius: f32, segments: usize) -> impl Iterator<Item = Vec2> {
+    arc_positions(0., TAU, radius, segments)
+}
+
+/// The positions of an arc starting at the clockwise `start_angle` from [`Vec2::Y`].
+pub(crate) fn arc_positions(
+    start_angle: f32,
+    arc_angle: f32,
+    radius: f32,
+    segments: usize,
+) -> impl Iterator<Item = Vec2> {
     (0..segments + 1).map(move |i| {
-        let angle = i as f32 * TAU / segments as f32;
+        let angle = start_angle + i as f32 * arc_angle / segments as f32;
         Vec2::from(angle.sin_cos()) * radius
     })
 }
@@ -20,6 +42,9 @@ fn circle_inner(radius: f32, segments: usize) -> impl Iterator<Item = Vec2> {
 impl<'s> Gizmos<'s> {
     /// Draw a circle in 3D at `position` with the flat side facing `normal`.
     ///
+    /// `normal` can be a [`Direction3d`](bevy_math::primitives::Direction3d),
+    /// or a [`Vec3`] that must be normalized.
+    ///
     /// This should be called for each frame the circle needs to be rendered.
     ///
     /// # Example
@@ -27,10 +52,13 @@ impl<'s> Gizmos<'s> {
     /// # use bevy_gizmos::prelude::*;
     /// # use bevy_render::prelude::*;
     /// # use bevy_math::prelude::*;
+    /// # use bevy_math::primitives::Direction3d;
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.circle(Vec3::ZERO, Vec3::Z, 1., Color::GREEN);
+    ///     gizmos.circle(Vec3::ZERO, Direction3d::Y, 1., Color::GREEN);
     ///
-    ///     // Circles have 32 line-segments by default.
+    ///     // Circles have 32 line-segments by default,
+    ///     // or as many as their size on screen requires if `GizmoConfig::adaptive_segments` is enabled.
     ///     // You may want to increase this for larger circles.
     ///     gizmos
     ///         .circle(Vec3::ZERO, Vec3::Z, 5., Color::RED)
@@ -41,18 +69,23 @@ impl<'s> Gizmos<'s> {
     #[inline]
     pub fn circle(
         &mut self,
-        position: Vec3,
-        normal: Vec3,
+        position: impl Into<Vec3>,
+        normal: impl Into<Vec3>,
         radius: f32,
         color: Color,
     ) -> CircleBuilder<'_, 's> {
+        let normal = normal.into();
+        debug_assert!(
+            normal == Vec3::ZERO || normal.is_normalized(),
+            "the normal of a circle must be normalized, got {normal}"
+        );
         CircleBuilder {
             gizmos: self,
-            position,
+            position: position.into(),
             normal,
             radius,
             color,
-            segments: DEFAULT_CIRCLE_SEGMENTS,
+            segments: None,
         }
     }
 
@@ -68,7 +101,8 @@ impl<'s> Gizmos<'s> {
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.circle_2d(Vec2::ZERO, 1., Color::GREEN);
     ///
-    ///     // Circles have 32 line-segments by default.
+    ///     // Circles have 32 line-segments by default,
+    ///     // or as many as their size on screen requires if `GizmoConfig::adaptive_segments` is enabled.
     ///     // You may want to increase this for larger circles.
     ///     gizmos
     ///         .circle_2d(Vec2::ZERO, 5., Color::RED)
@@ -79,18 +113,66 @@ impl<'s> Gizmos<'s> {
     #[inline]
     pub fn circle_2d(
         &mut self,
-        position: Vec2,
+        position: impl Into<Vec2>,
         radius: f32,
         color: Color,
     ) -> Circle2dBuilder<'_, 's> {
         Circle2dBuilder {
             gizmos: self,
-            position,
+            position: position.into(),
             radius,
             color,
-            segments: DEFAULT_CIRCLE_SEGMENTS,
+            segments: None,
         }
     }
+
+    /// Draw a circle in 3D at `position` that always faces the camera,
+    /// such as to show the range of an effect without the clutter of a [sphere](Gizmos::sphere).
+    ///
+    /// Its orientation is resolved at the end of the frame, after cameras have moved.
+    /// When several cameras are active, each of them draws a circle facing itself.
+    ///
+    /// This should be called for each frame the circle needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.billboard_circle(Vec3::ZERO, 5., Color::YELLOW);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn billboard_circle(&mut self, position: impl Into<Vec3>, radius: f32, color: Color) {
+        let position = position.into();
+        skip_invalid!(
+            radius < 0. || !radius.is_finite() || !position.is_finite(),
+            "Skipped drawing a billboard circle with a negative or non-finite radius or a non-finite position, got a radius of {radius} at {position}"
+        );
+        if radius == 0. {
+            return;
+        }
+        let shape = || CapturedShape::BillboardCircle {
+            position,
+            radius,
+            color,
+        };
+        self.capture(shape, |gizmos| {
+            gizmos.pending_arc(PendingArc {
+                center: position,
+                rotation: Quat::IDENTITY,
+                radius,
+                start_angle: 0.,
+                arc_angle: TAU,
+                color: color.as_linear_rgba_f32(),
+                billboard: true,
+                view: None,
+                tag: None,
+            });
+        });
+    }
 }
 
 /// A builder returned by [`Gizmos::circle`].
@@ -100,23 +182,73 @@ pub struct CircleBuilder<'a, 's> {
     normal: Vec3,
     radius: f32,
     color: Color,
-    segments: usize,
+    segments: Option<usize>,
 }
 
 impl CircleBuilder<'_, '_> {
     /// Set the number of line-segments for this circle.
     pub fn segments(mut self, segments: usize) -> Self {
-        self.segments = segments;
+        self.segments = Some(segments);
+        self
+    }
+
+    /// Set the color to that of `value` within `range` in `colormap`.
+    pub fn color_by(
+        mut self,
+        value: f32,
+        range: RangeInclusive<f32>,
+        colormap: &GizmoColormap,
+    ) -> Self {
+        self.color = colormap.color_by(value, range);
         self
     }
 }
 
 impl Drop for CircleBuilder<'_, '_> {
     fn drop(&mut self) {
-        let rotation = Quat::from_rotation_arc(Vec3::Z, self.normal);
-        let positions = circle_inner(self.radius, self.segments)
-            .map(|vec2| self.position + rotation * vec2.extend(0.));
-        self.gizmos.linestrip(positions, self.color);
+        skip_invalid!(
+            self.radius < 0.
+                || !self.radius.is_finite()
+                || !self.position.is_finite()
+                || !self.normal.is_finite()
+                || self.normal == Vec3::ZERO
+                || self.segments == Some(0),
+            "Skipped drawing a circle with a negative or non-finite radius, a non-finite position, a zero or non-finite normal or zero segments, got a radius of {} at {}, the normal {} and {:?} segments",
+            self.radius,
+            self.position,
+            self.normal,
+            self.segments
+        );
+        if self.radius == 0. {
+            return;
+        }
+        let shape = || CapturedShape::Circle {
+            position: self.position,
+            normal: self.normal,
+            radius: self.radius,
+            color: self.color,
+        };
+        self.gizmos.capture(shape, |gizmos| {
+            let rotation = Quat::from_rotation_arc(Vec3::Z, self.normal);
+            let Some(segments) = self.segments else {
+                gizmos.pending_arc(PendingArc {
+                    center: self.position,
+                    rotation,
+                    radius: self.radius,
+                    start_angle: 0.,
+                    arc_angle: TAU,
+                    color: self.color.as_linear_rgba_f32(),
+                    billboard: false,
+                    view: None,
+                    tag: None,
+                });
+                return;
+            };
+
+            let positions = circle_inner(self.radius, segments)
+                .map(|vec2| self.position + rotation * vec2.extend(0.));
+            gizmos.linestrip(positions, self.color);
+        });
     }
 }
 
@@ -126,20 +258,201 @@ pub struct Circle2dBuilder<'a, 's> {
     position: Vec2,
     radius: f32,
     color: Color,
-    segments: usize,
+    segments: Option<usize>,
 }
 
 impl Circle2dBuilder<'_, '_> {
     /// Set the number of line-segments for this circle.
     pub fn segments(mut self, segments: usize) -> Self {
-        self.segments = segments;
+        self.segments = Some(segments);
+        self
+    }
+
+    /// Set the color to that of `value` within `range` in `colormap`.
+    pub fn color_by(
+        mut self,
+        value: f32,
+        range: RangeInclusive<f32>,
+        colormap: &GizmoColormap,
+    ) -> Self {
+        self.color = colormap.color_by(value, range);
         self
     }
 }
 
 impl Drop for Circle2dBuilder<'_, '_> {
     fn drop(&mut self) {
-        let positions = circle_inner(self.radius, self.segments).map(|vec2| vec2 + self.position);
-        self.gizmos.linestrip_2d(positions, self.color);
+        skip_invalid!(
+            self.radius < 0.
+                || !self.radius.is_finite()
+                || !self.position.is_finite()
+                || self.segments == Some(0),
+            "Skipped drawing a circle with a negative or non-finite radius, a non-finite position or zero segments, got a radius of {} at {} and {:?} segments",
+            self.radius,
+            self.position,
+            self.segments
+        );
+        if self.radius == 0. {
+            return;
+        }
+        let shape = || CapturedShape::Circle {
+            position: self.position.extend(0.),
+            normal: Vec3::Z,
+            radius: self.radius,
+            color: self.color,
+        };
+        self.gizmos.draw_2d(true, |gizmos| {
+            gizmos.capture(shape, |gizmos| {
+                let Some(segments) = self.segments else {
+                    gizmos.pending_arc(PendingArc {
+                        center: self.position.extend(0.),
+                        rotation: Quat::IDENTITY,
+                        radius: self.radius,
+                        start_angle: 0.,
+                        arc_angle: TAU,
+                        color: self.color.as_linear_rgba_f32(),
+                        billboard: false,
+                        view: None,
+                        tag: None,
+                    });
+                    return;
+                };
+
+                let positions =
+                    circle_inner(self.radius, segments).map(|vec2| vec2 + self.position);
+                gizmos.linestrip_2d(positions, self.color);
+            });
+        });
+    }
+}
+
+/// Resolves the number of segments of the circles and arcs drawn this frame without an explicit
+/// number of segments and adds them to the [`GizmoStorage`].
+///
+/// Their number of segments is based on their largest size on the screen of any active camera if
+/// [`GizmoConfig::adaptive_segments`] is enabled, or proportional to [`DEFAULT_CIRCLE_SEGMENTS`] otherwise.
+/// It is then scaled by the detail at their distance to the closest active camera
+/// if [`GizmoConfig::lod`] is enabled.
+///
+/// Billboards are turned to face each active camera and added to the lines only drawn in its view,
+/// see [`GizmoStorage::view_mut`]. Arcs drawn for the view of one camera, such as those of
+/// [screen-sized](crate::gizmos::Gizmos::screen_sized) gizmos, are only added to that view.
+pub(crate) fn resolve_pending_arcs(
+    config: Res<GizmoConfig>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    mut storage: ResMut<GizmoStorage>,
+    // Each active camera's view projection and the pixels per unit at a clip space `w` of 1.
+    mut views: Local<Vec<(Mat4, f32)>>,
+    mut camera_positions: Local<Vec<(Entity, Vec3)>>,
+    // The rotations of an arc and the cameras it is only drawn for, if any.
+    mut rotations: Local<Vec<(Quat, Option<Entity>)>>,
+) {
+    if storage.pending_arcs.is_empty() {
+        return;
+    }
+
+    let adaptive = &config.adaptive_segments;
+    let lod = &config.lod;
+    camera_positions.clear();
+    camera_positions.extend(
+        cameras
+            .iter()
+            .filter(|(_, camera, _)| camera.is_active)
+            .map(|(entity, _, transform)| (entity, transform.translation())),
+    );
+    views.clear();
+    if adaptive.enabled {
+        views.extend(cameras.iter().filter_map(|(_, camera, transform)| {
+            let viewport_size = camera
+                .physical_viewport_size()
+                .filter(|_| camera.is_active)?;
+            let projection = camera.projection_matrix();
+            Some((
+                projection * transform.compute_matrix().inverse(),
+                projection.y_axis.y * viewport_size.y as f32 / 2.,
+            ))
+        }));
+    }
+
+    let storage = &mut *storage;
+    let mut pending_arcs = mem::take(&mut storage.pending_arcs);
+    for (index, arc) in pending_arcs.drain(..).enumerate() {
+        let fraction = arc.arc_angle.abs() / TAU;
+        let circle_segments = if views.is_empty() {
+            DEFAULT_CIRCLE_SEGMENTS as f32
+        } else {
+            let pixels = views
+                .iter()
+                .map(|(view_projection, pixels_per_unit)| {
+                    let w = view_projection.row(3).dot(arc.center.extend(1.));
+                    if w > 0. {
+                        arc.radius.abs() * pixels_per_unit / w
+                    } else {
+                        0.
+                    }
+                })
+                .fold(0., f32::max);
+            (TAU * pixels / adaptive.segment_length)
+                .clamp(adaptive.min_segments as f32, adaptive.max_segments as f32)
+        };
+        let circle_segments = if !lod.enabled || camera_positions.is_empty() {
+            circle_segments
+        } else {
+            let distance = camera_positions
+                .iter()
+                .map(|(_, position)| position.distance(arc.center))
+                .fold(f32::INFINITY, f32::min);
+            (circle_segments * lod.detail(distance)).max(3.)
+        };
+        let segments = ((circle_segments * fraction).ceil() as usize).max(1);
+
+        let rotation = |camera_position: Vec3| {
+            if !arc.billboard {
+                return arc.rotation;
+            }
+            let normal = (camera_position - arc.center).try_normalize();
+            Quat::from_rotation_arc(Vec3::Z, normal.unwrap_or(Vec3::Z))
+        };
+        rotations.clear();
+        match arc.view {
+            // The camera may have been deactivated since, in which case the arc isn't drawn.
+            Some(view) => rotations.extend(
+                camera_positions
+                    .iter()
+                    .filter(|(camera, _)| *camera == view)
+                    .map(|&(camera, camera_position)| (rotation(camera_position), Some(camera))),
+            ),
+            None if arc.billboard && !camera_positions.is_empty() => rotations.extend(
+                camera_positions
+                    .iter()
+                    .map(|&(camera, camera_position)| (rotation(camera_position), Some(camera))),
+            ),
+            None => rotations.push((arc.rotation, None)),
+        }
+
+        for &(rotation, camera) in rotations.iter() {
+            let target = match camera {
+                Some(camera) => storage.view_mut(camera),
+                None => &mut *storage,
+            };
+            // One position per segment, plus the start and the NaN separator.
+            if !target.make_room(&config, segments + 2) {
+                continue;
+            }
+
+            let positions = arc_positions(arc.start_angle, arc.arc_angle, arc.radius, segments)
+                .map(|vec2| (arc.center + rotation * vec2.extend(0.)).to_array());
+            let strip_start = target.strip_positions.len();
+            target.strip_positions.extend(positions);
+            target.strip_positions.push([f32::NAN; 3]);
+            target
+                .strip_colors
+                .extend(iter::repeat(arc.color).take(segments + 1));
+            target.strip_colors.push([f32::NAN; 4]);
+            target.tag_lines(arc.tag, target.list_positions.len(), strip_start);
+            storage.count_resolved_usage(|usage| &usage.arcs, index, segments + 2);
+        }
     }
+    // Hand the allocation back for the next frame.
+    storage.pending_arcs = pending_arcs;
 }
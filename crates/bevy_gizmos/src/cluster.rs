@@ -0,0 +1,117 @@
+//! Gizmos for the clusters lights are assigned to for clustered forward rendering.
+//!
+//! Includes the `ClusterGizmoPlugin` drawing them.
+
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+
+#[cfg(feature = "bevy_pbr")]
+use crate::{aabb_transform, gizmos::Gizmos, GizmoConfig};
+#[cfg(feature = "bevy_pbr")]
+use bevy_app::{App, Plugin, PostUpdate};
+#[cfg(feature = "bevy_pbr")]
+use bevy_ecs::{
+    schedule::IntoSystemConfigs,
+    system::{Query, Res},
+};
+#[cfg(feature = "bevy_pbr")]
+use bevy_math::UVec3;
+#[cfg(feature = "bevy_pbr")]
+use bevy_pbr::{Clusters, SimulationLightSystems};
+#[cfg(feature = "bevy_pbr")]
+use bevy_render::camera::Camera;
+#[cfg(feature = "bevy_pbr")]
+use bevy_transform::components::GlobalTransform;
+
+/// A [`Plugin`] that draws the light clusters of each active camera,
+/// configured by [`GizmoConfig::cluster`](crate::GizmoConfig::cluster).
+///
+/// Unlike most other gizmo plugins, it isn't added by the [`GizmoPlugin`](crate::GizmoPlugin),
+/// as drawing thousands of clusters every frame is expensive.
+#[cfg(feature = "bevy_pbr")]
+pub struct ClusterGizmoPlugin;
+
+#[cfg(feature = "bevy_pbr")]
+impl Plugin for ClusterGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            draw_clusters.after(SimulationLightSystems::AssignLightsToClusters),
+        );
+    }
+}
+
+/// Configuration for the `ClusterGizmoPlugin`.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct ClusterGizmoConfig {
+    /// Draws the clusters no light is assigned to when set to `true`.
+    ///
+    /// Defaults to `false`.
+    pub draw_empty: bool,
+    /// The color of the clusters.
+    ///
+    /// Defaults to `Color::GRAY`.
+    pub color: Color,
+    /// Colors the clusters by the number of lights assigned to them when set to `true`,
+    /// from green for a single light to red for [`ClusterGizmoConfig::max_light_count`].
+    ///
+    /// As gizmos are made of lines, only the edges of the clusters are colored.
+    ///
+    /// Defaults to `true`.
+    pub color_by_light_count: bool,
+    /// The number of lights a cluster is colored red at.
+    ///
+    /// Defaults to `16`.
+    pub max_light_count: usize,
+}
+
+impl Default for ClusterGizmoConfig {
+    fn default() -> Self {
+        Self {
+            draw_empty: false,
+            color: Color::GRAY,
+            color_by_light_count: true,
+            max_light_count: 16,
+        }
+    }
+}
+
+#[cfg(feature = "bevy_pbr")]
+pub(crate) fn draw_clusters(
+    cameras: Query<(&Camera, &GlobalTransform, &Clusters)>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let cluster_config = &config.cluster;
+    for (camera, transform, clusters) in &cameras {
+        if !camera.is_active {
+            continue;
+        }
+        let dimensions = clusters.dimensions();
+        for (index, lights) in clusters.lights().iter().enumerate() {
+            let light_count = lights.len();
+            if light_count == 0 && !cluster_config.draw_empty {
+                continue;
+            }
+            let index = index as u32;
+            let ijk = UVec3::new(
+                index / dimensions.z % dimensions.x,
+                index / dimensions.z / dimensions.x,
+                index % dimensions.z,
+            );
+            let Some(aabb) = clusters.cluster_aabb(camera, ijk) else {
+                continue;
+            };
+
+            let color = if cluster_config.color_by_light_count && light_count > 0 {
+                let max = cluster_config.max_light_count.max(2);
+                let heat = (light_count - 1).min(max - 1) as f32 / (max - 1) as f32;
+                Color::hsl(120. * (1. - heat), 1., 0.5)
+            } else {
+                cluster_config.color
+            };
+            gizmos.cuboid(aabb_transform(aabb, *transform), color);
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Colormaps for gizmos encoding scalar values, such as heat, cost or density.
+//!
+//! Includes the [`GizmoColormap`] used by the `color_by` methods of the gizmo builders.
+
+use bevy_math::{Vec3, Vec4};
+use bevy_render::color::Color;
+use std::ops::RangeInclusive;
+
+/// A map from scalar values to colors, for gizmos encoding values such as heat, cost or density.
+///
+/// The built-in colormaps are perceptually uniform or close to it,
+/// so equal steps in value look like equal steps in color, unlike a hand-rolled red to green ramp.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// fn system(mut gizmos: Gizmos) {
+///     # let samples: [(Vec3, f32); 0] = [];
+///     for (position, cost) in samples {
+///         let color = GizmoColormap::Viridis.color_by(cost, 0.0..=10.0);
+///         gizmos.line(position, position + Vec3::Y * cost, color);
+///
+///         // Builders can be colored by value directly.
+///         gizmos
+///             .circle(position, Vec3::Y, 0.5, Color::WHITE)
+///             .color_by(cost, 0.0..=10.0, &GizmoColormap::Turbo);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+///
+/// // Custom colormaps interpolate between stops.
+/// let traffic = GizmoColormap::custom([(0., Color::GREEN), (0.5, Color::YELLOW), (1., Color::RED)]);
+/// assert_eq!(traffic.sample(1.), Color::RED);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum GizmoColormap {
+    /// The perceptually uniform dark blue to green to yellow colormap of matplotlib,
+    /// which is also readable with color vision deficiencies.
+    Viridis,
+    /// The blue to green to red rainbow colormap by Google,
+    /// for values whose small differences matter more than perceptual uniformity.
+    Turbo,
+    /// Black to white.
+    Grayscale,
+    /// Linear interpolation between colors at positions from `0.0` to `1.0`, in increasing order.
+    ///
+    /// Use [`GizmoColormap::custom`] to create it from stops in any order.
+    Custom(Vec<(f32, Color)>),
+}
+
+impl GizmoColormap {
+    /// Create a colormap interpolating between colors at positions from `0.0` to `1.0`.
+    pub fn custom(stops: impl IntoIterator<Item = (f32, Color)>) -> Self {
+        let mut stops: Vec<_> = stops.into_iter().collect();
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self::Custom(stops)
+    }
+
+    /// The color at `t`, from `0.0` at the start of the colormap to `1.0` at its end.
+    ///
+    /// `t` is clamped to that range, and NaN maps to the start.
+    pub fn sample(&self, t: f32) -> Color {
+        let t = if t.is_nan() { 0. } else { t.clamp(0., 1.) };
+        match self {
+            Self::Viridis => srgb(polynomial(t, &VIRIDIS)),
+            Self::Turbo => srgb(polynomial(t, &TURBO)),
+            Self::Grayscale => Color::rgb(t, t, t),
+            Self::Custom(stops) => {
+                let Some(&(_, first)) = stops.first() else {
+                    return Color::NONE;
+                };
+                let next = stops.partition_point(|&(position, _)| position <= t);
+                if next == 0 {
+                    return first;
+                }
+                let (start, start_color) = stops[next - 1];
+                let Some(&(end, end_color)) = stops.get(next) else {
+                    return start_color;
+                };
+                let fraction = (t - start) / (end - start);
+                let [start_color, end_color] =
+                    [start_color, end_color].map(|color| Vec4::from(color.as_rgba_f32()));
+                let [red, green, blue, alpha] = start_color.lerp(end_color, fraction).to_array();
+                Color::rgba(red, green, blue, alpha)
+            }
+        }
+    }
+
+    /// The color of `value` within `range`, which spans the whole colormap.
+    pub fn color_by(&self, value: f32, range: RangeInclusive<f32>) -> Color {
+        let (start, end) = range.into_inner();
+        let t = if start == end {
+            0.
+        } else {
+            (value - start) / (end - start)
+        };
+        self.sample(t)
+    }
+}
+
+/// The coefficients of a polynomial fit of viridis, from the lowest degree.
+const VIRIDIS: [[f32; 3]; 7] = [
+    [0.277_727_33, 0.005_407_344_5, 0.334_099_8],
+    [0.105_093_04, 1.404_613_5, 1.384_590_1],
+    [-0.330_861_84, 0.214_847_56, 0.095_095_16],
+    [-4.634_230_5, -5.799_101, -19.332_441],
+    [6.228_27, 14.179_933, 56.690_55],
+    [4.776_385, -13.745_145, -65.353_03],
+    [-5.435_456, 4.645_852_6, 26.312_435],
+];
+
+/// The coefficients of a polynomial fit of turbo, from the lowest degree.
+const TURBO: [[f32; 3]; 6] = [
+    [0.135_721_38, 0.091_402_61, 0.106_673_3],
+    [4.615_392_6, 2.194_188_4, 12.641_946],
+    [-42.660_32, 4.842_966_6, -60.582_05],
+    [132.131_08, -14.185_033, 110.362_77],
+    [-152.942_4, 4.277_298_5, -89.903_11],
+    [59.286_38, 2.829_566, 27.348_25],
+];
+
+fn polynomial(t: f32, coefficients: &[[f32; 3]]) -> Vec3 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Vec3::ZERO, |sum, &coefficient| {
+            sum * t + Vec3::from(coefficient)
+        })
+}
+
+fn srgb(color: Vec3) -> Color {
+    let [red, green, blue] = color.clamp(Vec3::ZERO, Vec3::ONE).to_array();
+    Color::rgb(red, green, blue)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::Vec4;
+    use bevy_render::color::Color;
+
+    use super::GizmoColormap;
+
+    fn assert_color_near(color: Color, expected: Color, tolerance: f32) {
+        let [color, expected] = [color, expected].map(|color| Vec4::from(color.as_rgba_f32()));
+        assert!(
+            color.abs_diff_eq(expected, tolerance),
+            "{color} isn't within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn clamps_values_out_of_range() {
+        for colormap in [
+            GizmoColormap::Viridis,
+            GizmoColormap::Turbo,
+            GizmoColormap::Grayscale,
+        ] {
+            let [start, end] = [0., 10.].map(|value| colormap.color_by(value, 0.0..=10.0));
+            assert_eq!(colormap.color_by(-5., 0.0..=10.0), start);
+            assert_eq!(colormap.color_by(f32::NEG_INFINITY, 0.0..=10.0), start);
+            assert_eq!(colormap.color_by(f32::NAN, 0.0..=10.0), start);
+            assert_eq!(colormap.color_by(15., 0.0..=10.0), end);
+            assert_eq!(colormap.color_by(f32::INFINITY, 0.0..=10.0), end);
+        }
+    }
+
+    #[test]
+    fn maps_the_ends_of_the_range_to_the_ends_of_the_colormap() {
+        let grayscale = GizmoColormap::Grayscale;
+        assert_eq!(grayscale.color_by(2., 2.0..=4.0), Color::rgb(0., 0., 0.));
+        assert_eq!(grayscale.color_by(4., 2.0..=4.0), Color::rgb(1., 1., 1.));
+        // Reversed ranges map their start to the start of the colormap too.
+        assert_eq!(grayscale.color_by(4., 4.0..=2.0), Color::rgb(0., 0., 0.));
+
+        let traffic = GizmoColormap::custom([(1., Color::RED), (0., Color::GREEN)]);
+        assert_eq!(traffic.sample(0.), Color::GREEN);
+        assert_eq!(traffic.sample(1.), Color::RED);
+
+        // The ends of matplotlib's viridis, #440154 and #fde725, which the polynomial fit is close to.
+        let viridis = GizmoColormap::Viridis;
+        assert_color_near(viridis.sample(0.), Color::hex("440154").unwrap(), 0.02);
+        assert_color_near(viridis.sample(1.), Color::hex("fde725").unwrap(), 0.02);
+    }
+
+    #[test]
+    fn interpolates_the_middle_of_the_range() {
+        let grayscale = GizmoColormap::Grayscale;
+        assert_eq!(grayscale.color_by(3., 2.0..=4.0), Color::rgb(0.5, 0.5, 0.5));
+
+        let custom = GizmoColormap::custom([
+            (0., Color::rgba(0., 0., 0., 0.)),
+            (1., Color::rgba(1., 0.5, 0., 1.)),
+        ]);
+        assert_eq!(custom.sample(0.5), Color::rgba(0.5, 0.25, 0., 0.5));
+
+        // The middle of matplotlib's viridis, #21918c.
+        let viridis = GizmoColormap::Viridis;
+        assert_color_near(viridis.sample(0.5), Color::hex("21918c").unwrap(), 0.02);
+    }
+}
@@ -0,0 +1,150 @@
+//! Diagnostics for the amount of gizmos drawn each frame.
+
+use crate::{gizmos, prepare_line_gizmos, GpuLineGizmos, LineGizmo, LineGizmoFrame};
+use bevy_app::{App, Last, Plugin};
+use bevy_asset::Assets;
+use bevy_diagnostic::{Diagnostic, DiagnosticId, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::{
+    schedule::IntoSystemConfigs,
+    system::{Res, Resource},
+};
+use bevy_render::{Render, RenderApp, RenderSet};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Adds gizmo diagnostics to an App, namely the number of line gizmo vertices,
+/// the size of their GPU buffers and the number of draw calls issued for them.
+///
+/// The GPU buffer size and draw calls are measured by the render world,
+/// so they lag behind the vertex counts by a frame when rendering is pipelined.
+#[derive(Default)]
+pub struct GizmoDiagnosticsPlugin;
+
+impl Plugin for GizmoDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(
+            Self::LIST_VERTEX_COUNT,
+            "gizmo_list_vertex_count",
+            20,
+        ))
+        .register_diagnostic(Diagnostic::new(
+            Self::STRIP_VERTEX_COUNT,
+            "gizmo_strip_vertex_count",
+            20,
+        ))
+        .register_diagnostic(Diagnostic::new(
+            Self::RETAINED_VERTEX_COUNT,
+            "gizmo_retained_vertex_count",
+            20,
+        ))
+        .register_diagnostic(
+            Diagnostic::new(Self::GPU_BUFFER_SIZE, "gizmo_gpu_buffer_size", 20).with_suffix("B"),
+        )
+        .register_diagnostic(Diagnostic::new(Self::DRAW_CALLS, "gizmo_draw_calls", 20));
+
+        if gizmos::DISABLED {
+            return;
+        }
+
+        let render_diagnostics = GizmoRenderDiagnostics::default();
+        app.insert_resource(render_diagnostics.clone())
+            .add_systems(Last, Self::diagnostic_system);
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.insert_resource(render_diagnostics).add_systems(
+            Render,
+            measure_gpu_line_gizmos
+                .in_set(RenderSet::PrepareResources)
+                .after(prepare_line_gizmos),
+        );
+    }
+}
+
+impl GizmoDiagnosticsPlugin {
+    /// The number of vertices of the immediate mode line list gizmos drawn this frame.
+    pub const LIST_VERTEX_COUNT: DiagnosticId =
+        DiagnosticId::from_u128(320428031590148882026136793567464092713);
+    /// The number of vertices of the immediate mode line strip gizmos drawn this frame,
+    /// including the separators between strips.
+    pub const STRIP_VERTEX_COUNT: DiagnosticId =
+        DiagnosticId::from_u128(276982224507773491062243812706453261535);
+    /// The number of vertices of all retained [`LineGizmo`] assets.
+    pub const RETAINED_VERTEX_COUNT: DiagnosticId =
+        DiagnosticId::from_u128(207491055779022487250105274925137422454);
+    /// The size in bytes of the GPU buffers holding line gizmos.
+    pub const GPU_BUFFER_SIZE: DiagnosticId =
+        DiagnosticId::from_u128(1060415026516375375602266471218719423);
+    /// The number of draw calls issued for line gizmos in the last rendered frame, across all views.
+    pub const DRAW_CALLS: DiagnosticId =
+        DiagnosticId::from_u128(148957240611389213484063372934516830861);
+
+    fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        frame: Res<LineGizmoFrame>,
+        line_gizmos: Res<Assets<LineGizmo>>,
+        render_diagnostics: Res<GizmoRenderDiagnostics>,
+    ) {
+        diagnostics.add_measurement(Self::LIST_VERTEX_COUNT, || {
+            frame.list.positions.len() as f64
+        });
+        diagnostics.add_measurement(Self::STRIP_VERTEX_COUNT, || {
+            frame.strip.positions.len() as f64
+        });
+        diagnostics.add_measurement(Self::RETAINED_VERTEX_COUNT, || {
+            line_gizmos
+                .iter()
+                .map(|(_, line_gizmo)| line_gizmo.positions.len())
+                .sum::<usize>() as f64
+        });
+        diagnostics.add_measurement(Self::GPU_BUFFER_SIZE, || {
+            render_diagnostics.0.buffer_size.load(Ordering::Relaxed) as f64
+        });
+        diagnostics.add_measurement(Self::DRAW_CALLS, || {
+            render_diagnostics.0.draw_calls.swap(0, Ordering::Relaxed) as f64
+        });
+    }
+}
+
+/// The measurements taken by the render world, shared with the main world.
+#[derive(Resource, Clone, Default)]
+pub(crate) struct GizmoRenderDiagnostics(Arc<GizmoRenderMeasurements>);
+
+#[derive(Default)]
+pub(crate) struct GizmoRenderMeasurements {
+    buffer_size: AtomicUsize,
+    draw_calls: AtomicUsize,
+}
+
+impl GizmoRenderDiagnostics {
+    /// Counts the draw calls issued for a line gizmo.
+    pub(crate) fn add_draw_calls(&self, count: usize) {
+        self.0.draw_calls.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+fn measure_gpu_line_gizmos(
+    gpu_line_gizmos: Res<GpuLineGizmos>,
+    render_diagnostics: Res<GizmoRenderDiagnostics>,
+) {
+    let buffer_size: u64 = gpu_line_gizmos
+        .0
+        .values()
+        .map(|line_gizmo| {
+            line_gizmo.position_buffer.size()
+                + line_gizmo.color_buffer.size()
+                + line_gizmo
+                    .index_buffer
+                    .as_ref()
+                    .map_or(0, |buffer| buffer.size())
+        })
+        .sum();
+    render_diagnostics
+        .0
+        .buffer_size
+        .store(buffer_size as usize, Ordering::Relaxed);
+}
@@ -0,0 +1,133 @@
+//! Environment variable driven initial [`GizmoConfig`].
+//!
+//! This allows turning gizmos on and tweaking them in builds that have no debug menu:
+//!
+//! ```text
+//! BEVY_GIZMOS=aabb BEVY_GIZMOS_WIDTH=3 ./my_game
+//! ```
+
+use crate::GizmoConfig;
+use bevy_ecs::system::ResMut;
+use bevy_log::warn;
+
+/// The environment variable holding a comma separated list of gizmo layers to enable.
+///
+/// Supported layers are:
+/// - `aabb`: draws all bounding boxes, see [`AabbGizmoConfig::draw_all`](crate::AabbGizmoConfig::draw_all).
+/// - `bounding_sphere`: draws all bounding spheres, see [`BoundingSphereGizmoConfig::draw_all`](crate::BoundingSphereGizmoConfig::draw_all).
+/// - `axes`: draws the axes of all entities, see [`AxesGizmoConfig::draw_all`](crate::AxesGizmoConfig::draw_all).
+/// - `audio`: draws all audio emitters and listeners, see [`AudioGizmoConfig::draw_all`](crate::audio::AudioGizmoConfig::draw_all).
+/// - `frustum`: draws the frusta of all cameras, see [`FrustumGizmoConfig::draw_all`](crate::frustum::FrustumGizmoConfig::draw_all).
+/// - `light`: draws all lights, see [`LightGizmoConfig::draw_all`](crate::light::LightGizmoConfig::draw_all).
+/// - `name`: draws the names of all entities, see [`NameGizmoConfig::draw_all`](crate::name::NameGizmoConfig::draw_all).
+/// - `skeleton`: draws all skeletons, see [`SkeletonGizmoConfig::draw_all`](crate::skeleton::SkeletonGizmoConfig::draw_all).
+/// - `sprite`: draws the bounds of all sprites, see [`SpriteGizmoConfig::draw_all`](crate::sprite::SpriteGizmoConfig::draw_all).
+/// - `viewport`: draws the visible areas of all cameras, see [`ViewportGizmoConfig::draw_all`](crate::viewport::ViewportGizmoConfig::draw_all).
+/// - `all`: enables every layer listed above.
+/// - `none` or `off`: disables gizmos entirely.
+///
+/// Setting this variable (even to an empty string) enables gizmos unless `none` is given.
+pub const GIZMOS_ENV_VAR: &str = "BEVY_GIZMOS";
+
+/// The environment variable holding the line width of gizmos in pixels, see [`GizmoConfig::line_width`].
+pub const GIZMOS_WIDTH_ENV_VAR: &str = "BEVY_GIZMOS_WIDTH";
+
+/// A system that applies [`GIZMOS_ENV_VAR`] and [`GIZMOS_WIDTH_ENV_VAR`] to the [`GizmoConfig`] resource.
+///
+/// This is not added by [`GizmoPlugin`](crate::GizmoPlugin).
+/// Add it to the [`Startup`](bevy_app::Startup) schedule to opt in.
+///
+/// # Example
+/// ```
+/// # use bevy_app::{App, Startup};
+/// # use bevy_gizmos::env::gizmo_config_from_env;
+/// App::new().add_systems(Startup, gizmo_config_from_env);
+/// ```
+pub fn gizmo_config_from_env(mut config: ResMut<GizmoConfig>) {
+    if let Ok(layers) = std::env::var(GIZMOS_ENV_VAR) {
+        config.enabled = true;
+
+        for layer in layers.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            match layer.to_ascii_lowercase().as_str() {
+                "all" => {
+                    for layer in LAYERS {
+                        *draw_all(&mut config, layer).unwrap() = true;
+                    }
+                }
+                "none" | "off" => config.enabled = false,
+                lowercase => match draw_all(&mut config, lowercase) {
+                    Some(draw_all) => *draw_all = true,
+                    None => warn!("Unknown gizmo layer `{layer}` in {GIZMOS_ENV_VAR}"),
+                },
+            }
+        }
+    }
+
+    if let Ok(width) = std::env::var(GIZMOS_WIDTH_ENV_VAR) {
+        match width.trim().parse::<f32>() {
+            Ok(width) if width.is_finite() && width >= 0. => config.line_width = width,
+            _ => warn!("Invalid gizmo line width `{width}` in {GIZMOS_WIDTH_ENV_VAR}"),
+        }
+    }
+}
+
+/// The layers of [`GIZMOS_ENV_VAR`] enabled by `all`.
+const LAYERS: [&str; 10] = [
+    "aabb",
+    "bounding_sphere",
+    "axes",
+    "audio",
+    "frustum",
+    "light",
+    "name",
+    "skeleton",
+    "sprite",
+    "viewport",
+];
+
+/// The `draw_all` flag of the sub-config enabled by a layer of [`GIZMOS_ENV_VAR`].
+fn draw_all<'a>(config: &'a mut GizmoConfig, layer: &str) -> Option<&'a mut bool> {
+    let draw_all = match layer {
+        "aabb" => &mut config.aabb.draw_all,
+        "bounding_sphere" => &mut config.bounding_sphere.draw_all,
+        "axes" => &mut config.axes.draw_all,
+        "audio" => &mut config.audio.draw_all,
+        "frustum" => &mut config.frustum.draw_all,
+        "light" => &mut config.light.draw_all,
+        "name" => &mut config.name.draw_all,
+        "skeleton" => &mut config.skeleton.draw_all,
+        "sprite" => &mut config.sprite.draw_all,
+        "viewport" => &mut config.viewport.draw_all,
+        _ => return None,
+    };
+    Some(draw_all)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::ReflectRef;
+
+    use super::{draw_all, LAYERS};
+    use crate::GizmoConfig;
+
+    #[test]
+    fn every_draw_all_flag_has_a_layer() {
+        let config = GizmoConfig::default();
+        for (name, sub_config) in config.sub_configs() {
+            let ReflectRef::Struct(sub_config) = sub_config.reflect_ref() else {
+                unreachable!();
+            };
+            if sub_config.field("draw_all").is_some() {
+                assert!(LAYERS.contains(&name), "`{name}` has no layer");
+            }
+        }
+
+        let mut config = GizmoConfig::default();
+        for layer in LAYERS {
+            assert!(
+                draw_all(&mut config, layer).is_some(),
+                "`{layer}` has no flag"
+            );
+        }
+    }
+}
@@ -0,0 +1,529 @@
+//! Exporting the gizmos drawn in a frame to files.
+//!
+//! Includes the [`GizmoExporter`] resource and the system writing the files it queues.
+
+use crate::{
+    lines::{GizmoLines, GizmoVertex},
+    LineGizmo,
+};
+use bevy_asset::{Assets, Handle};
+use bevy_core::{cast_slice, Name};
+use bevy_ecs::{
+    entity::Entity,
+    system::{Query, Res, ResMut, Resource},
+};
+use bevy_log::error;
+use bevy_render::{color::Color, view::InheritedVisibility};
+use std::{fmt::Write, fs, path::PathBuf};
+
+/// A file format the [`GizmoExporter`] can write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GizmoExportFormat {
+    /// An SVG image of the XY plane, for 2D gizmos.
+    Svg,
+    /// A Wavefront OBJ file with a line element per line, for 3D gizmos.
+    ///
+    /// Vertex colors are written after the positions, as supported by most tools.
+    Obj,
+    /// An ASCII PLY file with an edge element per segment, for 3D gizmos.
+    Ply,
+    /// A binary glTF file with a `LINES` primitive per retained [`LineGizmo`] entity,
+    /// to review annotations in other tools alongside the level geometry.
+    ///
+    /// Each entity is a node named after its [`Name`], if any. The gizmos drawn
+    /// in the frame are a node named `Gizmos`. Positions are in world space.
+    Glb,
+}
+
+/// A [`Resource`] that writes the gizmos drawn in a frame to a file,
+/// to share the exact debug geometry or to inspect it in other tools.
+///
+/// The files are written at the end of the frame the export is queued in,
+/// with the gizmos drawn during the whole frame.
+/// When a frame of the [`GizmoRecorder`](crate::recorder::GizmoRecorder) is being played back,
+/// that frame is exported instead.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::prelude::*;
+/// fn system(mut exporter: ResMut<GizmoExporter>, keys: Res<ButtonInput<KeyCode>>) {
+///     if keys.just_pressed(KeyCode::F9) {
+///         exporter.export(GizmoExportFormat::Svg, "gizmos.svg");
+///         exporter.export(GizmoExportFormat::Obj, "gizmos.obj");
+///         exporter.export(GizmoExportFormat::Glb, "annotations.glb");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct GizmoExporter {
+    queued: Vec<(GizmoExportFormat, PathBuf)>,
+}
+
+impl GizmoExporter {
+    /// Write the gizmos of this frame to the file at `path` in `format`,
+    /// replacing it if it exists.
+    ///
+    /// Errors are logged.
+    pub fn export(&mut self, format: GizmoExportFormat, path: impl Into<PathBuf>) {
+        self.queued.push((format, path.into()));
+    }
+}
+
+/// The lines of a frame, as the indices of their vertices.
+struct ExportedLines {
+    positions: Vec<[f32; 3]>,
+    colors: Vec<[f32; 4]>,
+    /// The indices of the vertices of each line, two for segments, more for strips.
+    lines: Vec<Vec<usize>>,
+}
+
+impl ExportedLines {
+    fn new(lines: &GizmoLines) -> Self {
+        let mut exported = ExportedLines {
+            positions: Vec::new(),
+            colors: Vec::new(),
+            lines: Vec::new(),
+        };
+        for line in lines.lines() {
+            exported.add_line(line);
+        }
+        for strip in lines.strips() {
+            exported.add_line(strip.vertices());
+        }
+        exported
+    }
+
+    fn from_line_gizmo(line_gizmo: &LineGizmo) -> Self {
+        let mut exported = ExportedLines {
+            positions: Vec::new(),
+            colors: Vec::new(),
+            lines: Vec::new(),
+        };
+        let mut add_line = |positions: &[[f32; 3]], colors: &[[f32; 4]]| {
+            let start = exported.positions.len();
+            exported.positions.extend_from_slice(positions);
+            exported.colors.extend_from_slice(colors);
+            exported
+                .lines
+                .push((start..exported.positions.len()).collect());
+        };
+        let len = line_gizmo.positions.len().min(line_gizmo.colors.len());
+        let (positions, colors) = (&line_gizmo.positions[..len], &line_gizmo.colors[..len]);
+        if line_gizmo.strip {
+            let mut start = 0;
+            // Strips are separated by a NaN position.
+            for strip in positions.split(|position| position[0].is_nan()) {
+                let end = start + strip.len();
+                if strip.len() >= 2 {
+                    add_line(strip, &colors[start..end]);
+                }
+                start = end + 1;
+            }
+        } else {
+            for (positions, colors) in positions.chunks_exact(2).zip(colors.chunks_exact(2)) {
+                add_line(positions, colors);
+            }
+        }
+        exported
+    }
+
+    fn add_line(&mut self, vertices: impl IntoIterator<Item = GizmoVertex>) {
+        let start = self.positions.len();
+        for vertex in vertices {
+            self.positions.push(vertex.position.to_array());
+            self.colors.push(vertex.color.as_linear_rgba_f32());
+        }
+        self.lines.push((start..self.positions.len()).collect());
+    }
+
+    /// The sRGB color of the vertex at `index`, with components from `0` to `255`.
+    fn color_bytes(&self, index: usize) -> [u8; 4] {
+        let [red, green, blue, alpha] = self.colors[index];
+        // Round rather than truncate, as the conversion to sRGB can land just below `1.0`.
+        Color::rgba_linear(red, green, blue, alpha)
+            .as_rgba_f32()
+            .map(|channel| (channel.clamp(0., 1.) * 255.).round() as u8)
+    }
+
+    fn write_svg(&self) -> String {
+        let points = self
+            .lines
+            .iter()
+            .flatten()
+            .map(|&index| self.positions[index]);
+        let (min, max) = points.fold(
+            ([f32::INFINITY; 2], [f32::NEG_INFINITY; 2]),
+            |(min, max), [x, y, _]| {
+                (
+                    [min[0].min(x), min[1].min(y)],
+                    [max[0].max(x), max[1].max(y)],
+                )
+            },
+        );
+        let (min, max) = if min[0] <= max[0] {
+            (min, max)
+        } else {
+            ([0.; 2], [0.; 2])
+        };
+        // Leave some room for the strokes on the edges.
+        let padding = (max[0] - min[0]).max(max[1] - min[1]).max(1.) * 0.05;
+
+        let mut svg = String::new();
+        // SVG goes down along `+Y`, so flip the gizmos upright.
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            min[0] - padding,
+            -max[1] - padding,
+            max[0] - min[0] + 2. * padding,
+            max[1] - min[1] + 2. * padding,
+        );
+        for line in &self.lines {
+            // SVG strokes have a single color, so split lines at color changes.
+            let mut start = 0;
+            while start + 1 < line.len() {
+                let color = self.colors[line[start]];
+                let end = (start + 1..line.len())
+                    .find(|&i| self.colors[line[i]] != color)
+                    .unwrap_or(line.len() - 1);
+                let [red, green, blue, alpha] = self.color_bytes(line[start]);
+                let _ = write!(svg, r#"<polyline points=""#);
+                for &index in &line[start..=end] {
+                    let [x, y, _] = self.positions[index];
+                    // Subtracting from zero flips `0` to `0` rather than `-0`.
+                    let _ = write!(svg, "{x},{} ", 0. - y);
+                }
+                let _ = writeln!(
+                    svg,
+                    r#"" fill="none" stroke="rgb({red},{green},{blue})" stroke-opacity="{}" stroke-width="2" vector-effect="non-scaling-stroke"/>"#,
+                    alpha as f32 / 255.,
+                );
+                start = end;
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn write_obj(&self) -> String {
+        let mut obj = String::new();
+        for (index, [x, y, z]) in self.positions.iter().enumerate() {
+            // Use the bytes of the other formats, so full channels are `1` rather than `0.99999994`.
+            let [red, green, blue, _] = self.color_bytes(index).map(|byte| byte as f32 / 255.);
+            let _ = writeln!(obj, "v {x} {y} {z} {red} {green} {blue}");
+        }
+        for line in &self.lines {
+            obj.push('l');
+            for index in line {
+                // OBJ indices start at 1.
+                let _ = write!(obj, " {}", index + 1);
+            }
+            obj.push('\n');
+        }
+        obj
+    }
+
+    fn write_ply(&self) -> String {
+        let edge_count: usize = self.lines.iter().map(|line| line.len() - 1).sum();
+        let mut ply = String::new();
+        let _ = write!(
+            ply,
+            "ply\n\
+            format ascii 1.0\n\
+            element vertex {}\n\
+            property float x\n\
+            property float y\n\
+            property float z\n\
+            property uchar red\n\
+            property uchar green\n\
+            property uchar blue\n\
+            property uchar alpha\n\
+            element edge {edge_count}\n\
+            property int vertex1\n\
+            property int vertex2\n\
+            end_header\n",
+            self.positions.len(),
+        );
+        for (index, [x, y, z]) in self.positions.iter().enumerate() {
+            let [red, green, blue, alpha] = self.color_bytes(index);
+            let _ = writeln!(ply, "{x} {y} {z} {red} {green} {blue} {alpha}");
+        }
+        for line in &self.lines {
+            for segment in line.windows(2) {
+                let _ = writeln!(ply, "{} {}", segment[0], segment[1]);
+            }
+        }
+        ply
+    }
+}
+
+/// Writes `meshes` as a binary glTF file, with a node and a `LINES` primitive per mesh.
+fn write_glb<'a>(meshes: impl IntoIterator<Item = (&'a str, &'a ExportedLines)>) -> Vec<u8> {
+    const ARRAY_BUFFER: u32 = 34962;
+    const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+    const FLOAT: u32 = 5126;
+    const UNSIGNED_INT: u32 = 5125;
+    const LINES: u32 = 1;
+
+    let mut bin = Vec::new();
+    let mut views = Vec::new();
+    let mut accessors = Vec::new();
+    let mut nodes = Vec::new();
+    let mut gltf_meshes = Vec::new();
+    let mut add_view = |bytes: &[u8], target: u32| {
+        views.push(format!(
+            r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{target}}}"#,
+            bin.len(),
+            bytes.len(),
+        ));
+        bin.extend_from_slice(bytes);
+        views.len() - 1
+    };
+
+    for (name, lines) in meshes {
+        let indices: Vec<u32> = lines
+            .lines
+            .iter()
+            .flat_map(|line| line.windows(2))
+            .flat_map(|segment| [segment[0] as u32, segment[1] as u32])
+            .collect();
+        if indices.is_empty() {
+            continue;
+        }
+        let (min, max) = lines.positions.iter().fold(
+            ([f32::INFINITY; 3], [f32::NEG_INFINITY; 3]),
+            |(min, max), position| {
+                (
+                    [0, 1, 2].map(|i| min[i].min(position[i])),
+                    [0, 1, 2].map(|i| max[i].max(position[i])),
+                )
+            },
+        );
+
+        let positions = add_view(cast_slice(&lines.positions), ARRAY_BUFFER);
+        let colors = add_view(cast_slice(&lines.colors), ARRAY_BUFFER);
+        let index_view = add_view(cast_slice(&indices), ELEMENT_ARRAY_BUFFER);
+        let count = lines.positions.len();
+        accessors.push(format!(
+            r#"{{"bufferView":{positions},"componentType":{FLOAT},"count":{count},"type":"VEC3","min":{min:?},"max":{max:?}}}"#,
+        ));
+        accessors.push(format!(
+            r#"{{"bufferView":{colors},"componentType":{FLOAT},"count":{count},"type":"VEC4"}}"#,
+        ));
+        accessors.push(format!(
+            r#"{{"bufferView":{index_view},"componentType":{UNSIGNED_INT},"count":{},"type":"SCALAR"}}"#,
+            indices.len(),
+        ));
+        let accessor = accessors.len() - 3;
+        gltf_meshes.push(format!(
+            r#"{{"primitives":[{{"attributes":{{"POSITION":{},"COLOR_0":{}}},"indices":{},"mode":{LINES}}}]}}"#,
+            accessor,
+            accessor + 1,
+            accessor + 2,
+        ));
+        nodes.push(format!(
+            r#"{{"name":{},"mesh":{}}}"#,
+            json_string(name),
+            gltf_meshes.len() - 1
+        ));
+    }
+
+    let node_indices: Vec<String> = (0..nodes.len()).map(|i| i.to_string()).collect();
+    let mut json = format!(
+        r#"{{"asset":{{"version":"2.0","generator":"bevy_gizmos"}},"scene":0,"scenes":[{{"nodes":[{}]}}],"nodes":[{}],"meshes":[{}],"accessors":[{}],"bufferViews":[{}],"buffers":[{{"byteLength":{}}}]}}"#,
+        node_indices.join(","),
+        nodes.join(","),
+        gltf_meshes.join(","),
+        accessors.join(","),
+        views.join(","),
+        bin.len(),
+    );
+    // Chunks are aligned to 4 bytes, the JSON with spaces and the binary data with zeros.
+    while json.len() % 4 != 0 {
+        json.push(' ');
+    }
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+
+    let mut glb = Vec::with_capacity(12 + 8 + json.len() + 8 + bin.len());
+    let length = glb.capacity() as u32;
+    glb.extend_from_slice(b"glTF");
+    glb.extend_from_slice(&2u32.to_le_bytes());
+    glb.extend_from_slice(&length.to_le_bytes());
+    glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"JSON");
+    glb.extend_from_slice(json.as_bytes());
+    glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"BIN\0");
+    glb.extend_from_slice(&bin);
+    glb
+}
+
+/// Quotes and escapes `value` as a JSON string.
+fn json_string(value: &str) -> String {
+    let mut json = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                json.push('\\');
+                json.push(c);
+            }
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Writes the files queued in the [`GizmoExporter`].
+pub(crate) fn export_gizmos(
+    mut exporter: ResMut<GizmoExporter>,
+    lines: GizmoLines,
+    line_gizmos: Res<Assets<LineGizmo>>,
+    retained_line_gizmos: Query<(
+        Entity,
+        &Handle<LineGizmo>,
+        Option<&Name>,
+        Option<&InheritedVisibility>,
+    )>,
+) {
+    if exporter.queued.is_empty() {
+        return;
+    }
+    let lines = ExportedLines::new(&lines);
+    for (format, path) in exporter.queued.drain(..) {
+        let contents = match format {
+            GizmoExportFormat::Svg => lines.write_svg().into_bytes(),
+            GizmoExportFormat::Obj => lines.write_obj().into_bytes(),
+            GizmoExportFormat::Ply => lines.write_ply().into_bytes(),
+            GizmoExportFormat::Glb => {
+                let retained = retained_line_gizmos
+                    .iter()
+                    .filter(|(.., visibility)| {
+                        visibility.map_or(true, |visibility| visibility.get())
+                    })
+                    .filter_map(|(entity, handle, name, _)| {
+                        let name = name.map_or_else(|| format!("{entity:?}"), ToString::to_string);
+                        let line_gizmo = line_gizmos.get(handle)?;
+                        Some((name, ExportedLines::from_line_gizmo(line_gizmo)))
+                    });
+                let retained: Vec<_> = retained.collect();
+                let meshes = retained.iter().map(|(name, lines)| (name.as_str(), lines));
+                write_glb(meshes.chain([("Gizmos", &lines)]))
+            }
+        };
+        if let Err(error) = fs::write(&path, contents) {
+            error!("Failed to export gizmos to {}: {error}", path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_glb, ExportedLines};
+    use crate::LineGizmo;
+
+    /// A red strip along the X axis and a blue strip above it, separated by a NaN position.
+    fn two_strips() -> ExportedLines {
+        let [red, blue] = [[1., 0., 0., 1.], [0., 0., 1., 1.]];
+        let line_gizmo = LineGizmo::new(
+            vec![
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [f32::NAN; 3],
+                [0., 1., 0.],
+                [1., 1., 0.],
+            ],
+            vec![red, red, [f32::NAN; 4], blue, blue],
+            true,
+        );
+        ExportedLines::from_line_gizmo(&line_gizmo)
+    }
+
+    #[test]
+    fn writes_svg() {
+        let expected = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-0.05 -1.05 1.1 1.1">
+<polyline points="0,0 1,0 " fill="none" stroke="rgb(255,0,0)" stroke-opacity="1" stroke-width="2" vector-effect="non-scaling-stroke"/>
+<polyline points="0,-1 1,-1 " fill="none" stroke="rgb(0,0,255)" stroke-opacity="1" stroke-width="2" vector-effect="non-scaling-stroke"/>
+</svg>
+"#;
+        assert_eq!(two_strips().write_svg(), expected);
+    }
+
+    #[test]
+    fn writes_obj() {
+        let expected = "\
+v 0 0 0 1 0 0
+v 1 0 0 1 0 0
+v 0 1 0 0 0 1
+v 1 1 0 0 0 1
+l 1 2
+l 3 4
+";
+        assert_eq!(two_strips().write_obj(), expected);
+    }
+
+    #[test]
+    fn writes_ply() {
+        let expected = "\
+ply
+format ascii 1.0
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+property uchar alpha
+element edge 2
+property int vertex1
+property int vertex2
+end_header
+0 0 0 255 0 0 255
+1 0 0 255 0 0 255
+0 1 0 0 0 255 255
+1 1 0 0 0 255 255
+0 1
+2 3
+";
+        assert_eq!(two_strips().write_ply(), expected);
+    }
+
+    #[test]
+    fn writes_glb_lines() {
+        let empty = ExportedLines::from_line_gizmo(&LineGizmo::new(Vec::new(), Vec::new(), true));
+        let glb = write_glb([("Empty", &empty), ("Strips", &two_strips())]);
+
+        let word = |offset: usize| u32::from_le_bytes(glb[offset..offset + 4].try_into().unwrap());
+        assert_eq!(&glb[..4], b"glTF");
+        assert_eq!(word(4), 2);
+        assert_eq!(word(8) as usize, glb.len());
+        let json_len = word(12) as usize;
+        assert_eq!(&glb[16..20], b"JSON");
+        let json = std::str::from_utf8(&glb[20..20 + json_len]).unwrap();
+        let bin_offset = 20 + json_len;
+        assert_eq!(&glb[bin_offset + 4..bin_offset + 8], b"BIN\0");
+        // 4 positions and 4 colors, and 2 indices for each of the 2 segments.
+        assert_eq!(word(bin_offset), 4 * 12 + 4 * 16 + 4 * 4);
+
+        // Meshes without lines are left out.
+        assert!(json.contains(r#""nodes":[{"name":"Strips","mesh":0}]"#));
+        assert!(json.contains(
+            r#""primitives":[{"attributes":{"POSITION":0,"COLOR_0":1},"indices":2,"mode":1}]"#
+        ));
+        let accessors = [
+            r#"{"bufferView":0,"componentType":5126,"count":4,"type":"VEC3","min":[0.0, 0.0, 0.0],"max":[1.0, 1.0, 0.0]}"#,
+            r#"{"bufferView":1,"componentType":5126,"count":4,"type":"VEC4"}"#,
+            r#"{"bufferView":2,"componentType":5125,"count":4,"type":"SCALAR"}"#,
+        ];
+        assert!(json.contains(&format!(r#""accessors":[{}]"#, accessors.join(","))));
+    }
+}
@@ -0,0 +1,126 @@
+//! Gizmos for camera frustums.
+//!
+//! Includes the [`FrustumGizmo`] component and the system drawing it.
+
+use crate::{color_from_entity, gizmos::Gizmos, GizmoConfig};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::{AnyOf, With},
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_math::Vec3A;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    camera::{Camera, CameraProjection, OrthographicProjection, PerspectiveProjection, Projection},
+    color::Color,
+};
+use bevy_transform::components::GlobalTransform;
+
+/// Configuration for drawing the frustums of cameras.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct FrustumGizmoConfig {
+    /// Draws the frustums of all cameras in the scene when set to `true`.
+    ///
+    /// To draw a specific camera's frustum, you can add the [`FrustumGizmo`] component.
+    ///
+    /// Defaults to `false`.
+    pub draw_all: bool,
+    /// The default color for frustum gizmos.
+    ///
+    /// A random color is chosen per camera if `None`.
+    ///
+    /// Defaults to `None`.
+    pub default_color: Option<Color>,
+    /// Draws an arrow from each camera in the direction it is looking when set to `true`.
+    ///
+    /// Defaults to `true`.
+    pub draw_direction: bool,
+}
+
+impl Default for FrustumGizmoConfig {
+    fn default() -> Self {
+        Self {
+            draw_all: false,
+            default_color: None,
+            draw_direction: true,
+        }
+    }
+}
+
+/// Add this [`Component`] to a [`Camera`] to draw the edges of its frustum,
+/// including its near and far planes.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct FrustumGizmo {
+    /// The color of the frustum.
+    ///
+    /// The default color from the [`GizmoConfig`] resource is used if `None`.
+    pub color: Option<Color>,
+}
+
+/// The length of the arrow drawn in the direction a camera is looking.
+const DIRECTION_ARROW_LENGTH: f32 = 1.;
+
+type CameraProjections<'a> = AnyOf<(
+    &'a Projection,
+    &'a PerspectiveProjection,
+    &'a OrthographicProjection,
+)>;
+
+pub(crate) fn draw_frustums(
+    query: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            CameraProjections,
+            Option<&FrustumGizmo>,
+        ),
+        With<Camera>,
+    >,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let frustum_config = &config.frustum;
+    for (entity, transform, projections, gizmo) in &query {
+        if !frustum_config.draw_all && gizmo.is_none() {
+            continue;
+        }
+        let color = gizmo
+            .and_then(|gizmo| gizmo.color)
+            .or(frustum_config.default_color)
+            .unwrap_or_else(|| color_from_entity(entity));
+
+        let corners = match projections {
+            (Some(Projection::Perspective(projection)), ..) | (None, Some(projection), _) => {
+                frustum_corners(projection, projection.near)
+            }
+            (Some(Projection::Orthographic(projection)), ..) | (None, None, Some(projection)) => {
+                frustum_corners(projection, projection.near)
+            }
+            (None, None, None) => continue,
+        }
+        .map(|corner| transform.transform_point(corner.into()));
+
+        // The near and far planes, then the edges connecting them.
+        for plane in [&corners[..4], &corners[4..]] {
+            gizmos.linestrip(plane.iter().copied().chain([plane[0]]), color);
+        }
+        for i in 0..4 {
+            gizmos.line(corners[i], corners[i + 4], color);
+        }
+
+        if frustum_config.draw_direction {
+            let start = transform.translation();
+            let direction = transform.forward().normalize_or_zero();
+            gizmos.arrow(start, start + direction * DIRECTION_ARROW_LENGTH, color);
+        }
+    }
+}
+
+/// The corners of the near plane followed by the corners of the far plane, in view space.
+fn frustum_corners(projection: &impl CameraProjection, near: f32) -> [Vec3A; 8] {
+    projection.get_frustum_corners(-near, -projection.far())
+}
@@ -1,25 +1,796 @@
 //! A module for the [`Gizmos`] [`SystemParam`].
 
-use std::iter;
+use std::{
+    f32::consts::{PI, TAU},
+    hash::{Hash, Hasher},
+    iter, mem,
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut, Range, RangeInclusive},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Mutex, PoisonError,
+    },
+    thread,
+};
 
-use crate::circles::DEFAULT_CIRCLE_SEGMENTS;
+use crate::{
+    circles::{arc_positions, DEFAULT_CIRCLE_SEGMENTS},
+    colormap::GizmoColormap,
+    inspector::GizmoUsage,
+    testing::{CapturedGizmos, CapturedShape, ShapeCapture},
+    GizmoBudgetOverflow, GizmoConfig,
+};
 use bevy_ecs::{
-    system::{Deferred, Resource, SystemBuffer, SystemMeta, SystemParam},
-    world::World,
+    component::Tick,
+    entity::Entity,
+    system::{
+        Commands, Deferred, Local, Query, ReadOnlySystemParam, Res, ResMut, Resource, SystemBuffer,
+        SystemMeta, SystemParam,
+    },
+    world::{unsafe_world_cell::UnsafeWorldCell, FromWorld, Mut, World},
 };
-use bevy_math::{Mat2, Quat, Vec2, Vec3};
-use bevy_render::color::Color;
-use bevy_transform::TransformPoint;
+use bevy_log::warn;
+use bevy_math::{Affine3A, Mat2, Quat, Vec2, Vec3, Vec3Swizzles};
+use bevy_render::{camera::Camera, color::Color};
+#[cfg(all(feature = "multi-threaded", not(target_arch = "wasm32")))]
+use bevy_tasks::AsyncComputeTaskPool;
+use bevy_tasks::{block_on, Task};
+use bevy_time::Time;
+use bevy_transform::{components::GlobalTransform, TransformPoint};
+use bevy_utils::{synccell::SyncCell, AHasher, Duration, Entry, HashMap, Instant};
+
+/// Whether gizmos are compiled out of this build.
+///
+/// This is `true` in release builds when the `strip_in_release` feature is enabled,
+/// in which case every drawing method is a no-op.
+pub(crate) const STRIPPED: bool = cfg!(all(not(debug_assertions), feature = "strip_in_release"));
+
+/// Whether the crate is built without a renderer, with neither the `bevy_sprite` nor the `bevy_pbr` feature,
+/// such as for dedicated servers.
+///
+/// Nothing can be drawn then, so drawing methods are no-ops unless the shapes are captured
+/// by the [`GizmoTestPlugin`](crate::testing::GizmoTestPlugin).
+pub(crate) const HEADLESS: bool = !cfg!(any(feature = "bevy_sprite", feature = "bevy_pbr"));
+
+/// Whether gizmos are never drawn in this build, see [`STRIPPED`] and [`HEADLESS`].
+pub(crate) const DISABLED: bool = STRIPPED || HEADLESS;
+
+/// Draws a gizmo only when `debug_assertions` are enabled, by calling a [`Gizmos`] method
+/// on the given `gizmos`.
+///
+/// The arguments are still type checked in release builds, but the call is optimized out,
+/// without stripping every gizmo like the `strip_in_release` feature.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// fn system(mut gizmos: Gizmos) {
+///     debug_gizmo!(gizmos, line(Vec3::ZERO, Vec3::X, Color::RED));
+///     debug_gizmo!(gizmos, circle(Vec3::ZERO, Vec3::Y, 1., Color::RED).segments(64));
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+#[macro_export]
+macro_rules! debug_gizmo {
+    ($gizmos:expr, $($call:tt)*) => {
+        if cfg!(debug_assertions) {
+            $gizmos.$($call)*;
+        }
+    };
+}
+
+/// Returns without drawing a shape when its inputs are `invalid`,
+/// logging the warning in builds with `debug_assertions`.
+///
+/// The warning is logged once per use of the macro, which is once per kind of shape
+/// rather than once per place the shape is drawn from.
+macro_rules! skip_invalid {
+    ($invalid:expr, $($warning:tt)+) => {
+        if $invalid {
+            if cfg!(debug_assertions) {
+                bevy_log::warn_once!($($warning)+);
+            }
+            return;
+        }
+    };
+}
+pub(crate) use skip_invalid;
 
 type PositionItem = [f32; 3];
 type ColorItem = [f32; 4];
 
+/// Whether `color` is [`Gizmos::DEFAULT_COLOR`], whose alpha no actual color has.
+///
+/// Only the alpha is compared, so the sentinel is still found if its other channels change,
+/// such as when converting it to another color space.
+#[inline]
+fn is_default_color_item(color: ColorItem) -> bool {
+    color[3] == f32::NEG_INFINITY
+}
+
 #[derive(Resource, Default)]
 pub(crate) struct GizmoStorage {
     pub list_positions: Vec<PositionItem>,
     pub list_colors: Vec<ColorItem>,
     pub strip_positions: Vec<PositionItem>,
     pub strip_colors: Vec<ColorItem>,
+    /// Circles and arcs without an explicit number of segments,
+    /// which is resolved at the end of the frame.
+    pub pending_arcs: Vec<PendingArc>,
+    /// Texts, which are laid out at the end of the frame with [`GizmoConfig::text`].
+    pub pending_texts: Vec<PendingText>,
+    /// Gizmos being drawn on the [`AsyncComputeTaskPool`], see [`Gizmos::draw_async`].
+    pub tasks: Vec<Task<GizmoBuffer>>,
+    /// Gizmos drawn again every frame until they expire, see [`Gizmos::persist`].
+    pub persistent: Vec<PersistentGizmos>,
+    /// Gizmos whose size on screen is resolved at the end of the frame, see [`Gizmos::screen_sized`].
+    pub screen_sized: Vec<ScreenSizedGizmos>,
+    /// Whether gizmos were dropped this frame because of the [vertex budget](GizmoConfig::vertex_budget).
+    pub truncated: bool,
+    pub last_truncation_warning: Option<Instant>,
+    /// The lines applied by each system, to render them in the same order every frame,
+    /// see [`GizmoStorage::sort_chunks`].
+    pub chunks: Vec<GizmoChunk>,
+    /// The lines drawn in [`Gizmos::tagged`] scopes.
+    pub tags: Vec<TaggedLines>,
+    /// Whether the lines of the frame were already sent to the render world,
+    /// so gizmos applied now are only rendered in the next frame.
+    pub frame_sent: bool,
+    /// The gizmos applied by each system, counted while there is a [`GizmoUsage`] resource.
+    pub usage: Vec<SystemUsage>,
+    /// The lines only drawn in the view of one camera, such as billboards turned to face it,
+    /// see [`GizmoStorage::view_mut`].
+    pub views: Vec<(Entity, GizmoStorage)>,
+}
+
+/// A tag attached to the gizmos drawn in a [`Gizmos::tagged`] scope,
+/// such as the entity they belong to or the id of the tool that drew them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GizmoTag(pub u64);
+
+impl GizmoTag {
+    /// The entity of a tag created from an [`Entity`], or `None` if it isn't a valid entity.
+    pub fn entity(self) -> Option<Entity> {
+        Entity::try_from_bits(self.0).ok()
+    }
+}
+
+impl From<u64> for GizmoTag {
+    fn from(tag: u64) -> Self {
+        Self(tag)
+    }
+}
+
+impl From<Entity> for GizmoTag {
+    fn from(entity: Entity) -> Self {
+        Self(entity.to_bits())
+    }
+}
+
+/// The lines of a [`GizmoStorage`] or [`GizmoBuffer`] drawn in a [`Gizmos::tagged`] scope.
+///
+/// The ranges of a buffer or storage don't overlap.
+#[derive(Clone, Debug)]
+pub(crate) struct TaggedLines {
+    pub tag: GizmoTag,
+    pub list: Range<usize>,
+    pub strip: Range<usize>,
+}
+
+/// The gizmos of the [`GizmoStorage`] applied by a system, see [`GizmoUsage`].
+#[derive(Clone, Debug)]
+pub(crate) struct SystemUsage {
+    pub system: String,
+    /// The number of vertices of the lines, and of the arcs and texts resolved so far.
+    pub vertices: usize,
+    /// The indices of the pending arcs applied by the system.
+    pub arcs: Range<usize>,
+    /// The indices of the pending texts applied by the system.
+    pub texts: Range<usize>,
+}
+
+/// The lines of the [`GizmoStorage`] applied by a system.
+#[derive(Clone, Debug)]
+pub(crate) struct GizmoChunk {
+    /// The hash of the system's name, which is the same every frame.
+    key: u64,
+    list: Range<usize>,
+    strip: Range<usize>,
+}
+
+/// A circle or arc whose number of segments is resolved from its size on screen,
+/// see [`GizmoConfig::adaptive_segments`].
+#[derive(Clone, Copy)]
+pub(crate) struct PendingArc {
+    pub center: Vec3,
+    /// Rotates the arc from the XY plane.
+    pub rotation: Quat,
+    pub radius: f32,
+    /// The clockwise angle from `Vec2::Y` the arc starts at.
+    pub start_angle: f32,
+    pub arc_angle: f32,
+    pub color: ColorItem,
+    /// Whether the arc is turned to face each active camera and only drawn in its view,
+    /// see [`Gizmos::billboard_circle`]. `rotation` is only used when there are none.
+    pub billboard: bool,
+    pub tag: Option<GizmoTag>,
+    /// The camera whose view the arc is only drawn in, such as for the arcs of screen-sized gizmos.
+    /// Billboards only face this camera.
+    pub view: Option<Entity>,
+}
+
+/// A text drawn by [`Gizmos::text`] or [`Gizmos::text_2d`], laid out with [`GizmoConfig::text`].
+#[derive(Clone)]
+pub(crate) struct PendingText {
+    /// Transforms the text from the XY plane, centered on the origin with glyphs 1 unit high.
+    pub transform: Affine3A,
+    pub text: String,
+    pub color: ColorItem,
+    pub tag: Option<GizmoTag>,
+    /// The camera whose view the text is only drawn in, such as for the texts of screen-sized gizmos.
+    pub view: Option<Entity>,
+}
+
+/// Gizmos drawn by [`Gizmos::persist`].
+pub(crate) struct PersistentGizmos {
+    /// The time left until they stop being drawn.
+    remaining: Duration,
+    buffer: GizmoBuffer,
+}
+
+/// Gizmos drawn by [`Gizmos::screen_sized`].
+pub(crate) struct ScreenSizedGizmos {
+    /// The position the gizmos are drawn around.
+    anchor: Vec3,
+    /// The number of logical pixels a unit spans.
+    size: f32,
+    buffer: GizmoBuffer,
+}
+
+impl ScreenSizedGizmos {
+    /// Copies the lines and arcs of these gizmos.
+    fn copy(&self) -> Self {
+        let mut buffer = GizmoBuffer::default();
+        self.buffer.copy_to(&mut buffer);
+        Self {
+            anchor: self.anchor,
+            size: self.size,
+            buffer,
+        }
+    }
+}
+
+impl GizmoStorage {
+    /// Draws the gizmos of `draw` into the storage, even in stripped and headless builds,
+    /// to turn them into geometry such as with the [`GizmoShapeMeshBuilder`](crate::mesh::GizmoShapeMeshBuilder).
+    pub(crate) fn draw_retained(&mut self, config: &GizmoConfig, draw: impl FnOnce(&mut Gizmos)) {
+        let mut buffer = GizmoBuffer {
+            retained: true,
+            ..Default::default()
+        };
+        draw(&mut Gizmos {
+            buffer: &mut buffer,
+            transform: None,
+        });
+        buffer.append_to(self, config);
+    }
+
+    pub(crate) fn vertex_count(&self) -> usize {
+        self.list_positions.len() + self.strip_positions.len()
+    }
+
+    /// Makes room for `count` more vertices within the [vertex budget](GizmoConfig::vertex_budget)
+    /// as set by [`GizmoConfig::budget_overflow`].
+    ///
+    /// Returns `false` if the vertices should be dropped instead.
+    pub(crate) fn make_room(&mut self, config: &GizmoConfig, count: usize) -> bool {
+        let Some(max_vertices) = config.vertex_budget() else {
+            return true;
+        };
+        let excess = (self.vertex_count() + count).saturating_sub(max_vertices);
+        if excess == 0 {
+            return true;
+        }
+        self.truncated = true;
+        match config.budget_overflow {
+            GizmoBudgetOverflow::DropOldest if count <= max_vertices => {
+                self.drop_oldest(excess);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Tags the lines added since `list_start` and `strip_start` with `tag`, if any.
+    pub(crate) fn tag_lines(
+        &mut self,
+        tag: Option<GizmoTag>,
+        list_start: usize,
+        strip_start: usize,
+    ) {
+        let Some(tag) = tag else {
+            return;
+        };
+        self.tags.push(TaggedLines {
+            tag,
+            list: list_start..self.list_positions.len(),
+            strip: strip_start..self.strip_positions.len(),
+        });
+    }
+
+    /// The storage of the lines only drawn in the view of `camera`.
+    ///
+    /// Its lines are added after the lines drawn in all views when they are sent to the render world.
+    pub(crate) fn view_mut(&mut self, camera: Entity) -> &mut GizmoStorage {
+        let index = self.view_index(camera);
+        &mut self.views[index].1
+    }
+
+    /// Adds the gizmos of `buffer` to the lines only drawn in the view of `camera`.
+    ///
+    /// Its arcs and texts are resolved with the others, but are only drawn in this view as well.
+    fn append_to_view(&mut self, camera: Entity, buffer: &mut GizmoBuffer, config: &GizmoConfig) {
+        let index = self.view_index(camera);
+        let view = &mut self.views[index].1;
+        buffer.append_to(view, config);
+        self.pending_arcs
+            .extend(view.pending_arcs.drain(..).map(|arc| PendingArc {
+                view: Some(camera),
+                ..arc
+            }));
+        self.pending_texts
+            .extend(view.pending_texts.drain(..).map(|text| PendingText {
+                view: Some(camera),
+                ..text
+            }));
+    }
+
+    fn view_index(&mut self, camera: Entity) -> usize {
+        match self.views.iter().position(|(view, _)| *view == camera) {
+            Some(index) => index,
+            None => {
+                self.views.push((camera, GizmoStorage::default()));
+                self.views.len() - 1
+            }
+        }
+    }
+
+    /// Counts the `vertices` of the lines resolved from the pending arc or text at `index`
+    /// of the ranges returned by `pending` towards the system that applied it.
+    pub(crate) fn count_resolved_usage(
+        &mut self,
+        pending: impl Fn(&SystemUsage) -> &Range<usize>,
+        index: usize,
+        vertices: usize,
+    ) {
+        if let Some(usage) = self
+            .usage
+            .iter_mut()
+            .find(|usage| pending(usage).contains(&index))
+        {
+            usage.vertices += vertices;
+        }
+    }
+
+    /// Removes all lines and arcs, keeping the allocations.
+    pub(crate) fn clear(&mut self) {
+        self.list_positions.clear();
+        self.list_colors.clear();
+        self.strip_positions.clear();
+        self.strip_colors.clear();
+        self.pending_arcs.clear();
+        self.pending_texts.clear();
+        self.screen_sized.clear();
+        self.chunks.clear();
+        self.tags.clear();
+        self.usage.clear();
+        self.views.clear();
+    }
+
+    /// Removes the lines drawn first until at least `count` vertices were removed,
+    /// see [`GizmoBudgetOverflow::DropOldest`].
+    ///
+    /// Returns the number of removed vertices.
+    pub(crate) fn drop_oldest(&mut self, count: usize) -> usize {
+        let (list_len, strip_len) = (self.list_positions.len(), self.strip_positions.len());
+        let removed = drop_oldest_lines(
+            [&mut self.list_positions, &mut self.strip_positions],
+            [&mut self.list_colors, &mut self.strip_colors],
+            count,
+        );
+        let list_removed = list_len - self.list_positions.len();
+        let strip_removed = strip_len - self.strip_positions.len();
+        for chunk in &mut self.chunks {
+            shift_removed(&mut chunk.list, list_removed);
+            shift_removed(&mut chunk.strip, strip_removed);
+        }
+        self.chunks
+            .retain(|chunk| !chunk.list.is_empty() || !chunk.strip.is_empty());
+        shift_removed_tags(&mut self.tags, list_removed, strip_removed);
+        removed
+    }
+
+    /// Marks the lines added since the last chunk as applied by the system with the `key`.
+    fn end_chunk(&mut self, key: u64) {
+        let (list_start, strip_start) = self
+            .chunks
+            .last()
+            .map_or((0, 0), |chunk| (chunk.list.end, chunk.strip.end));
+        let list = list_start..self.list_positions.len();
+        let strip = strip_start..self.strip_positions.len();
+        if list.is_empty() && strip.is_empty() {
+            return;
+        }
+        self.chunks.push(GizmoChunk { key, list, strip });
+    }
+
+    /// Reorders the lines applied by systems by the name of the system,
+    /// so that overlapping gizmos are rendered in the same order every frame
+    /// whatever order the systems ran in.
+    ///
+    /// Lines applied by the same system keep their order,
+    /// and lines added at the end of the frame stay last.
+    /// `scratch` is kept around to avoid allocating every frame.
+    pub(crate) fn sort_chunks(&mut self, scratch: &mut GizmoBuffer) {
+        let mut chunks = mem::take(&mut self.chunks);
+        if chunks.windows(2).any(|pair| pair[0].key > pair[1].key) {
+            let list_end = chunks.last().map_or(0, |chunk| chunk.list.end);
+            let strip_end = chunks.last().map_or(0, |chunk| chunk.strip.end);
+            // The sort is stable, so chunks of the same system keep their order.
+            chunks.sort_by_key(|chunk| chunk.key);
+            for chunk in &chunks {
+                let (list, strip) = (chunk.list.clone(), chunk.strip.clone());
+                scratch
+                    .list_positions
+                    .extend_from_slice(&self.list_positions[list.clone()]);
+                scratch
+                    .list_colors
+                    .extend_from_slice(&self.list_colors[list]);
+                scratch
+                    .strip_positions
+                    .extend_from_slice(&self.strip_positions[strip.clone()]);
+                scratch
+                    .strip_colors
+                    .extend_from_slice(&self.strip_colors[strip]);
+            }
+            scratch
+                .list_positions
+                .extend_from_slice(&self.list_positions[list_end..]);
+            scratch
+                .list_colors
+                .extend_from_slice(&self.list_colors[list_end..]);
+            scratch
+                .strip_positions
+                .extend_from_slice(&self.strip_positions[strip_end..]);
+            scratch
+                .strip_colors
+                .extend_from_slice(&self.strip_colors[strip_end..]);
+
+            mem::swap(&mut self.list_positions, &mut scratch.list_positions);
+            mem::swap(&mut self.list_colors, &mut scratch.list_colors);
+            mem::swap(&mut self.strip_positions, &mut scratch.strip_positions);
+            mem::swap(&mut self.strip_colors, &mut scratch.strip_colors);
+            scratch.list_positions.clear();
+            scratch.list_colors.clear();
+            scratch.strip_positions.clear();
+            scratch.strip_colors.clear();
+        }
+        // Hand the allocation back for the next frame.
+        chunks.clear();
+        self.chunks = chunks;
+    }
+
+    /// Removes the lines that are exact duplicates of earlier ones, see [`GizmoConfig::deduplicate`].
+    ///
+    /// `seen` is scratch space, kept around to avoid allocating every frame.
+    pub(crate) fn deduplicate(&mut self, seen: &mut HashMap<u64, Range<usize>>) {
+        deduplicate_lines(&mut self.list_positions, &mut self.list_colors, false, seen);
+        deduplicate_lines(
+            &mut self.strip_positions,
+            &mut self.strip_colors,
+            true,
+            seen,
+        );
+    }
+}
+
+/// Moves `range` back after `removed` items were removed from the start of its buffer.
+fn shift_removed(range: &mut Range<usize>, removed: usize) {
+    *range = range.start.saturating_sub(removed)..range.end.saturating_sub(removed);
+}
+
+/// Moves the ranges of `tags` back after vertices were removed from the start of their buffers,
+/// dropping the ranges that were removed entirely.
+fn shift_removed_tags(tags: &mut Vec<TaggedLines>, list_removed: usize, strip_removed: usize) {
+    for tagged in tags.iter_mut() {
+        shift_removed(&mut tagged.list, list_removed);
+        shift_removed(&mut tagged.strip, strip_removed);
+    }
+    tags.retain(|tagged| !tagged.list.is_empty() || !tagged.strip.is_empty());
+}
+
+/// Moves the ranges of `tags` to where their lines are appended to other buffers,
+/// at `list_offset` and `strip_offset`.
+fn offset_tags(
+    tags: impl IntoIterator<Item = TaggedLines>,
+    list_offset: usize,
+    strip_offset: usize,
+) -> impl Iterator<Item = TaggedLines> {
+    tags.into_iter().map(move |tagged| TaggedLines {
+        tag: tagged.tag,
+        list: tagged.list.start + list_offset..tagged.list.end + list_offset,
+        strip: tagged.strip.start + strip_offset..tagged.strip.end + strip_offset,
+    })
+}
+
+/// Removes complete lines from the start of the line list and then the line strip buffers
+/// until at least `count` vertices were removed.
+///
+/// Returns the number of removed vertices.
+fn drop_oldest_lines(
+    [list_positions, strip_positions]: [&mut Vec<PositionItem>; 2],
+    [list_colors, strip_colors]: [&mut Vec<ColorItem>; 2],
+    count: usize,
+) -> usize {
+    // Lines are made of two vertices, so only remove complete pairs.
+    let list_len = usize::min((count + 1) & !1, list_positions.len());
+    list_positions.drain(..list_len);
+    list_colors.drain(..list_len);
+
+    // Strips are terminated by a NaN separator, so only remove complete strips.
+    let strip_count = count.saturating_sub(list_len);
+    let strip_len = if strip_count == 0 {
+        0
+    } else {
+        strip_positions
+            .iter()
+            .enumerate()
+            .filter(|(_, position)| position[0].is_nan())
+            .map(|(index, _)| index + 1)
+            .find(|&len| len >= strip_count)
+            .unwrap_or(strip_positions.len())
+    };
+    strip_positions.drain(..strip_len);
+    strip_colors.drain(..strip_len);
+
+    list_len + strip_len
+}
+
+/// Removes the segments of a line list, or the strips of a line strip including their separator,
+/// whose positions and colors are bitwise identical to an earlier one.
+fn deduplicate_lines(
+    positions: &mut Vec<PositionItem>,
+    colors: &mut Vec<ColorItem>,
+    strip: bool,
+    seen: &mut HashMap<u64, Range<usize>>,
+) {
+    seen.clear();
+    let len = usize::min(positions.len(), colors.len());
+    let mut read = 0;
+    let mut write = 0;
+    while read < len {
+        let end = if strip {
+            positions[read..len]
+                .iter()
+                .position(|position| position.iter().any(|value| value.is_nan()))
+                .map_or(len, |separator| read + separator + 1)
+        } else {
+            usize::min(read + 2, len)
+        };
+        let line = read..end;
+        read = end;
+
+        let bits = |range: Range<usize>| {
+            let positions = positions[range.clone()].iter().flatten();
+            let colors = colors[range].iter().flatten();
+            positions.chain(colors).map(|value| value.to_bits())
+        };
+
+        let mut hasher = AHasher::default();
+        bits(line.clone()).for_each(|bits| hasher.write_u32(bits));
+        // Only the first line with a hash is kept to compare against, so a hash collision
+        // can let a duplicate through, but never drops a line that isn't one.
+        match seen.entry(hasher.finish()) {
+            Entry::Occupied(first) if bits(first.get().clone()).eq(bits(line.clone())) => {
+                continue;
+            }
+            Entry::Occupied(_) => {}
+            Entry::Vacant(entry) => {
+                entry.insert(write..write + line.len());
+            }
+        }
+
+        let line_len = line.len();
+        positions.copy_within(line.clone(), write);
+        colors.copy_within(line, write);
+        write += line_len;
+    }
+    positions.truncate(write);
+    colors.truncate(write);
+}
+
+/// The gizmos drawn during the fixed timestep schedules, which are kept until the next timestep.
+#[derive(Resource, Default)]
+pub(crate) struct FixedGizmoStorage {
+    /// The gizmos drawn during the current timestep while it runs,
+    /// and the gizmos drawn during the frame before it the rest of the time.
+    swapped: GizmoStorage,
+    /// The gizmos drawn during the last complete timestep.
+    last_timestep: GizmoStorage,
+}
+
+/// Marks the gizmos applied from now on as drawn in time for this frame.
+pub(crate) fn start_gizmo_frame(mut storage: ResMut<GizmoStorage>) {
+    storage.frame_sent = false;
+}
+
+/// Sets the gizmos of the frame aside when a fixed timestep starts,
+/// so that the [`GizmoStorage`] only receives the gizmos of the timestep.
+pub(crate) fn start_fixed_gizmos(
+    mut storage: ResMut<GizmoStorage>,
+    mut fixed: ResMut<FixedGizmoStorage>,
+) {
+    mem::swap(&mut *storage, &mut fixed.swapped);
+}
+
+/// Keeps the gizmos of a fixed timestep when it ends, replacing the previous timestep's ones,
+/// and restores the gizmos of the frame.
+pub(crate) fn end_fixed_gizmos(
+    config: Res<GizmoConfig>,
+    mut storage: ResMut<GizmoStorage>,
+    mut fixed: ResMut<FixedGizmoStorage>,
+) {
+    let FixedGizmoStorage {
+        swapped,
+        last_timestep,
+    } = &mut *fixed;
+    mem::swap(&mut *storage, swapped);
+
+    // Tasks and persistent gizmos can't be drawn again, so resolve or hand them to the frame.
+    while !swapped.tasks.is_empty() {
+        for task in mem::take(&mut swapped.tasks) {
+            block_on(task).append_to(swapped, &config);
+        }
+    }
+    storage.persistent.append(&mut swapped.persistent);
+    storage.truncated |= mem::take(&mut swapped.truncated);
+
+    mem::swap(swapped, last_timestep);
+    swapped.clear();
+}
+
+/// Adds the gizmos of the last fixed timestep to the [`GizmoStorage`].
+pub(crate) fn draw_fixed_gizmos(fixed: Res<FixedGizmoStorage>, mut storage: ResMut<GizmoStorage>) {
+    let last_timestep = &fixed.last_timestep;
+    let (arcs, texts) = (storage.pending_arcs.len(), storage.pending_texts.len());
+    let usage = last_timestep.usage.iter().map(|usage| SystemUsage {
+        system: usage.system.clone(),
+        vertices: usage.vertices,
+        arcs: usage.arcs.start + arcs..usage.arcs.end + arcs,
+        texts: usage.texts.start + texts..usage.texts.end + texts,
+    });
+    storage.usage.extend(usage);
+    let tags = offset_tags(
+        last_timestep.tags.iter().cloned(),
+        storage.list_positions.len(),
+        storage.strip_positions.len(),
+    );
+    storage.tags.extend(tags);
+    storage
+        .list_positions
+        .extend_from_slice(&last_timestep.list_positions);
+    storage
+        .list_colors
+        .extend_from_slice(&last_timestep.list_colors);
+    storage
+        .strip_positions
+        .extend_from_slice(&last_timestep.strip_positions);
+    storage
+        .strip_colors
+        .extend_from_slice(&last_timestep.strip_colors);
+    storage
+        .pending_arcs
+        .extend_from_slice(&last_timestep.pending_arcs);
+    storage
+        .pending_texts
+        .extend_from_slice(&last_timestep.pending_texts);
+    storage.screen_sized.extend(
+        last_timestep
+            .screen_sized
+            .iter()
+            .map(ScreenSizedGizmos::copy),
+    );
+}
+
+/// Adds the gizmos drawn by [`Gizmos::draw_async`] to the [`GizmoStorage`],
+/// waiting for the tasks that haven't finished yet.
+pub(crate) fn finish_gizmo_tasks(config: Res<GizmoConfig>, mut storage: ResMut<GizmoStorage>) {
+    // Tasks can draw further gizmos asynchronously themselves.
+    while !storage.tasks.is_empty() {
+        for task in mem::take(&mut storage.tasks) {
+            block_on(task).append_to(&mut storage, &config);
+        }
+    }
+}
+
+/// Adds the gizmos drawn by [`Gizmos::persist`] to the [`GizmoStorage`] again,
+/// until their duration has elapsed.
+pub(crate) fn draw_persistent_gizmos(
+    time: Res<Time>,
+    config: Res<GizmoConfig>,
+    mut storage: ResMut<GizmoStorage>,
+    mut scratch: Local<GizmoBuffer>,
+) {
+    let mut persistent = mem::take(&mut storage.persistent);
+    for gizmos in &mut persistent {
+        // Tasks can draw further gizmos asynchronously themselves.
+        while !gizmos.buffer.tasks.is_empty() {
+            for task in mem::take(&mut gizmos.buffer.tasks) {
+                gizmos.buffer.append(&mut block_on(task));
+            }
+        }
+        storage.persistent.append(&mut gizmos.buffer.persistent);
+
+        gizmos.buffer.copy_to(&mut scratch);
+        scratch.append_to(&mut storage, &config);
+        gizmos.remaining = gizmos.remaining.saturating_sub(time.delta());
+    }
+    persistent.retain(|gizmos| !gizmos.remaining.is_zero());
+    // Gizmos persisted by persistent gizmos' tasks were added in the meantime.
+    persistent.append(&mut storage.persistent);
+    storage.persistent = persistent;
+}
+
+/// Scales the gizmos drawn by [`Gizmos::screen_sized`] to their size on the screen
+/// of each active camera and adds them to the lines only drawn in its view,
+/// see [`GizmoStorage::view_mut`].
+pub(crate) fn resolve_screen_sized_gizmos(
+    config: Res<GizmoConfig>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    mut storage: ResMut<GizmoStorage>,
+    mut scratch: Local<GizmoBuffer>,
+) {
+    if storage.screen_sized.is_empty() {
+        return;
+    }
+    let mut screen_sized = mem::take(&mut storage.screen_sized);
+    for gizmos in &mut screen_sized {
+        // Tasks can draw further gizmos asynchronously themselves.
+        while !gizmos.buffer.tasks.is_empty() {
+            for task in mem::take(&mut gizmos.buffer.tasks) {
+                gizmos.buffer.append(&mut block_on(task));
+            }
+        }
+    }
+
+    let storage = &mut *storage;
+    for (entity, camera, transform) in &cameras {
+        let Some(viewport_size) = camera.logical_viewport_size().filter(|_| camera.is_active)
+        else {
+            continue;
+        };
+        let projection = camera.projection_matrix();
+        let view_projection = projection * transform.compute_matrix().inverse();
+        let pixels_per_unit = projection.y_axis.y * viewport_size.y / 2.;
+
+        for gizmos in &screen_sized {
+            let w = view_projection.row(3).dot(gizmos.anchor.extend(1.));
+            if w <= 0. {
+                continue;
+            }
+            let scale = gizmos.size * w / pixels_per_unit;
+            gizmos.buffer.copy_to(&mut scratch);
+            scratch.scale_around(gizmos.anchor, scale);
+            scratch.screen_sized.clear();
+            storage.append_to_view(entity, &mut scratch, &config);
+        }
+    }
+    // Hand the allocation back for the next frame.
+    screen_sized.clear();
+    storage.screen_sized = screen_sized;
 }
 
 /// A [`SystemParam`] for drawing gizmos.
@@ -27,32 +798,825 @@ pub(crate) struct GizmoStorage {
 /// They are drawn in immediate mode, which means they will be rendered only for
 /// the frames in which they are spawned.
 /// Gizmos should be spawned before the [`Last`](bevy_app::Last) schedule to ensure they are drawn.
-#[derive(SystemParam)]
+/// Gizmos of systems that run after the lines of the frame are sent to the render world,
+/// which happens after the [`ReadGizmos`](crate::ReadGizmos) set, are rendered in the next frame instead,
+/// and a warning naming the system is logged once in builds with `debug_assertions`.
+///
+/// Gizmos spawned in the fixed timestep schedules from [`FixedFirst`](bevy_app::FixedFirst)
+/// to [`FixedPostUpdate`](bevy_app::FixedPostUpdate) are instead rendered for every frame
+/// until the next fixed timestep, however many frames that is.
+///
+/// The gizmos of different systems are rendered in the same order every frame,
+/// whatever order the systems ran in, so overlapping translucent gizmos don't flicker.
+///
+/// Colors must not be NaN, which panics in builds with `debug_assertions`.
+/// Otherwise such gizmos may be split, connected to other gizmos or not drawn.
+///
+/// Shapes with invalid inputs, such as a negative or non-finite radius or size, a non-finite position,
+/// a zero normal or zero segments, are skipped and log a warning once per kind of shape
+/// in builds with `debug_assertions`.
+/// Empty shapes, such as a circle with a zero radius or an arrow of zero length,
+/// are skipped silently instead of being drawn as dots.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_app::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// fn degenerate(mut gizmos: Gizmos) {
+///     gizmos.circle(Vec3::ZERO, Vec3::ZERO, 1., Color::RED);
+///     gizmos.circle_2d(Vec2::ZERO, -1., Color::RED);
+///     gizmos.sphere(Vec3::ZERO, Quat::IDENTITY, 1., Color::RED).circle_segments(0);
+///     gizmos.arc_2d(Vec2::ZERO, 0., 0., 1., Color::RED);
+///     gizmos.rect_2d(Vec2::ZERO, 0., Vec2::ZERO, Color::RED);
+///     gizmos.arrow(Vec3::ONE, Vec3::ONE, Color::RED);
+///     gizmos.grid_2d(Vec2::ZERO, 0., UVec2::ZERO, Vec2::ONE, Color::RED);
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(GizmoTestPlugin).add_systems(Update, degenerate);
+/// app.update();
+/// assert!(app.world.resource::<CapturedGizmos>().is_empty());
+/// ```
+///
+/// Use [`ParallelGizmos`] to draw gizmos from parallel query iteration.
 pub struct Gizmos<'s> {
-    buffer: Deferred<'s, GizmoBuffer>,
+    buffer: &'s mut GizmoBuffer,
+    /// The transform of the current [`Gizmos::with_transform`] scope, if any.
+    transform: Option<Affine3A>,
+}
+
+// SAFETY: Only local state is accessed, like `Deferred`.
+unsafe impl ReadOnlySystemParam for Gizmos<'_> {}
+
+// SAFETY: Only local state is accessed, like `Deferred`.
+// This is implemented by hand so that `ParallelGizmos` can lend out `Gizmos` of its own buffers.
+unsafe impl SystemParam for Gizmos<'_> {
+    type State = GizmosState;
+    type Item<'w, 's> = Gizmos<'s>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        system_meta.set_has_deferred();
+        GizmosState(SyncCell::new(GizmoBuffer::for_world(world)))
+    }
+
+    fn apply(state: &mut Self::State, system_meta: &SystemMeta, world: &mut World) {
+        state.0.get().apply(system_meta, world);
+    }
+
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        _system_meta: &SystemMeta,
+        _world: UnsafeWorldCell<'w>,
+        _change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        Gizmos {
+            buffer: state.0.get(),
+            transform: None,
+        }
+    }
+}
+
+/// The [`SystemParam::State`] of [`Gizmos`].
+#[doc(hidden)]
+pub struct GizmosState(SyncCell<GizmoBuffer>);
+
+/// A [`SystemParam`] for drawing gizmos from several threads at once,
+/// such as inside [`Query::par_iter`](bevy_ecs::system::Query::par_iter).
+///
+/// Each thread draws into its own buffers, which are all merged once the system has run.
+/// They are merged in the order of the keys given to [`ParallelGizmos::draw`] rather than
+/// the order the threads drew them in, so that overlapping gizmos are rendered in the same order
+/// every frame, like the gizmos of different systems.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_transform::prelude::*;
+/// fn system(query: Query<(Entity, &GlobalTransform)>, gizmos: ParallelGizmos) {
+///     query.par_iter().for_each(|(entity, transform)| {
+///         gizmos.draw(entity, |gizmos| {
+///             gizmos.sphere(transform.translation(), Quat::IDENTITY, 1., Color::RED);
+///         });
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+#[derive(SystemParam)]
+pub struct ParallelGizmos<'s> {
+    buffer: Deferred<'s, ParallelGizmoBuffer>,
+}
+
+impl ParallelGizmos<'_> {
+    /// Draws gizmos into a buffer of the current thread.
+    ///
+    /// The gizmos of each call are merged in the order of the hash of their `key`,
+    /// such as the entity being iterated over. Calls with equal keys are merged in no particular order.
+    pub fn draw(&self, key: impl Hash, f: impl FnOnce(&mut Gizmos)) {
+        let mut hasher = AHasher::default();
+        key.hash(&mut hasher);
+        let shards = &self.buffer.shards;
+        let shard = &shards[SHARD.with(|shard| *shard) % shards.len()];
+        let mut shard = shard.lock().unwrap_or_else(PoisonError::into_inner);
+        f(&mut Gizmos {
+            buffer: shard.next_buffer(hasher.finish()),
+            transform: None,
+        });
+    }
+}
+
+static NEXT_SHARD: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Spreads threads evenly over the shards of a [`ParallelGizmoBuffer`].
+    static SHARD: usize = NEXT_SHARD.fetch_add(1, Ordering::Relaxed);
+}
+
+/// A [`GizmoShard`] per thread that can run a system, see [`ParallelGizmos`].
+struct ParallelGizmoBuffer {
+    shards: Vec<Mutex<GizmoShard>>,
+    /// The gizmos of all shards in the order of their keys.
+    merged: GizmoBuffer,
+}
+
+/// The buffers of the [`ParallelGizmos::draw`] calls of a thread.
+struct GizmoShard {
+    /// The hashed key and gizmos of each call.
+    ///
+    /// Buffers past `len` are kept around to avoid allocating every frame.
+    draws: Vec<(u64, GizmoBuffer)>,
+    len: usize,
+    /// Whether the buffers capture their shapes, see [`GizmoBuffer::for_world`].
+    capture: bool,
+}
+
+impl GizmoShard {
+    /// Returns an empty buffer for the call with the hashed `key`.
+    fn next_buffer(&mut self, key: u64) -> &mut GizmoBuffer {
+        if self.len == self.draws.len() {
+            let buffer = GizmoBuffer {
+                capture: self.capture.then(ShapeCapture::default),
+                ..Default::default()
+            };
+            self.draws.push((key, buffer));
+        }
+        let (draw_key, buffer) = &mut self.draws[self.len];
+        *draw_key = key;
+        self.len += 1;
+        buffer
+    }
+}
+
+impl FromWorld for ParallelGizmoBuffer {
+    fn from_world(world: &mut World) -> Self {
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let merged = GizmoBuffer::for_world(world);
+        let capture = merged.capture.is_some();
+        Self {
+            shards: iter::repeat_with(|| {
+                Mutex::new(GizmoShard {
+                    draws: Vec::new(),
+                    len: 0,
+                    capture,
+                })
+            })
+            .take(threads)
+            .collect(),
+            merged,
+        }
+    }
+}
+
+impl SystemBuffer for ParallelGizmoBuffer {
+    fn apply(&mut self, system_meta: &SystemMeta, world: &mut World) {
+        let mut draws: Vec<_> = self
+            .shards
+            .iter_mut()
+            .flat_map(|shard| {
+                let shard = shard.get_mut().unwrap_or_else(PoisonError::into_inner);
+                shard.draws[..mem::take(&mut shard.len)].iter_mut()
+            })
+            .collect();
+        draws.sort_unstable_by_key(|(key, _)| *key);
+        for (_, buffer) in draws {
+            self.merged.append(buffer);
+            if let (Some(merged), Some(capture)) = (&mut self.merged.capture, &mut buffer.capture) {
+                merged.shapes.append(&mut capture.shapes);
+            }
+        }
+        self.merged.apply(system_meta, world);
+    }
+}
+
+/// An extension trait for drawing gizmos with access to the [`World`], such as from
+/// exclusive systems, where no [`Gizmos`] system parameter is available.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// fn system(world: &mut World) {
+///     world.draw_gizmos(|gizmos| {
+///         gizmos.line(Vec3::ZERO, Vec3::X, Color::GREEN);
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+pub trait GizmoWorldExt {
+    /// Draws the gizmos of `draw` for this frame.
+    fn draw_gizmos(&mut self, draw: impl FnOnce(&mut Gizmos));
+}
+
+impl GizmoWorldExt for World {
+    fn draw_gizmos(&mut self, draw: impl FnOnce(&mut Gizmos)) {
+        if DISABLED {
+            return;
+        }
+        let mut buffer = GizmoBuffer::default();
+        draw(&mut Gizmos {
+            buffer: &mut buffer,
+            transform: None,
+        });
+        self.resource_scope(|world, mut storage: Mut<GizmoStorage>| {
+            buffer.append_to(&mut storage, world.resource::<GizmoConfig>());
+        });
+    }
+}
+
+/// An extension trait for drawing gizmos from places where only [`Commands`] are available.
+///
+/// The gizmos are drawn when the commands are applied, so commands applied after the
+/// [`Last`](bevy_app::Last) schedule has started draw them in the next frame.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// fn system(mut commands: Commands) {
+///     commands.draw_gizmos(|gizmos| {
+///         gizmos.sphere(Vec3::ZERO, Quat::IDENTITY, 1., Color::GREEN);
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+pub trait GizmoCommandsExt {
+    /// Queues drawing the gizmos of `draw`, see [`GizmoWorldExt::draw_gizmos`].
+    fn draw_gizmos(&mut self, draw: impl FnOnce(&mut Gizmos) + Send + 'static);
+}
+
+impl GizmoCommandsExt for Commands<'_, '_> {
+    fn draw_gizmos(&mut self, draw: impl FnOnce(&mut Gizmos) + Send + 'static) {
+        self.add(move |world: &mut World| world.draw_gizmos(draw));
+    }
+}
+
+/// A [`Resource`] that can be cloned and sent to other threads to draw gizmos from there,
+/// such as from background tasks visualizing their progress.
+///
+/// The gizmos are drawn in the frame they are received in at the start of the
+/// [`Last`](bevy_app::Last) schedule, use [`Gizmos::persist`] to keep them visible for longer.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// fn system(sender: Res<GizmoSender>) {
+///     let sender = sender.clone();
+///     std::thread::spawn(move || {
+///         for i in 0..10 {
+///             # let waypoint = Vec3::X * i as f32;
+///             sender.draw(|gizmos| {
+///                 gizmos.sphere(waypoint, Quat::IDENTITY, 0.1, Color::YELLOW);
+///             });
+///         }
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+#[derive(Resource, Clone)]
+pub struct GizmoSender {
+    sender: Sender<GizmoBuffer>,
+}
+
+impl GizmoSender {
+    /// Sends the gizmos of `draw` to be drawn in the frame they are received in.
+    pub fn draw(&self, draw: impl FnOnce(&mut Gizmos)) {
+        if DISABLED {
+            return;
+        }
+        let mut buffer = GizmoBuffer::default();
+        draw(&mut Gizmos {
+            buffer: &mut buffer,
+            transform: None,
+        });
+        // The receiver only goes away with the app, after which nothing is drawn anyway.
+        let _ = self.sender.send(buffer);
+    }
+}
+
+/// The [`Resource`] receiving the gizmos sent through the [`GizmoSender`].
+#[derive(Resource)]
+pub(crate) struct GizmoReceiver(SyncCell<Receiver<GizmoBuffer>>);
+
+/// Creates the [`GizmoSender`] and the [`GizmoReceiver`] of its gizmos.
+pub(crate) fn gizmo_channel() -> (GizmoSender, GizmoReceiver) {
+    let (sender, receiver) = mpsc::channel();
+    (
+        GizmoSender { sender },
+        GizmoReceiver(SyncCell::new(receiver)),
+    )
+}
+
+/// Adds the gizmos sent through the [`GizmoSender`] to the [`GizmoStorage`].
+pub(crate) fn receive_sent_gizmos(
+    config: Res<GizmoConfig>,
+    mut receiver: ResMut<GizmoReceiver>,
+    mut storage: ResMut<GizmoStorage>,
+) {
+    for mut buffer in receiver.0.get().try_iter() {
+        buffer.append_to(&mut storage, &config);
+    }
 }
 
 #[derive(Default)]
-struct GizmoBuffer {
+pub(crate) struct GizmoBuffer {
     list_positions: Vec<PositionItem>,
     list_colors: Vec<ColorItem>,
     strip_positions: Vec<PositionItem>,
     strip_colors: Vec<ColorItem>,
+    pending_arcs: Vec<PendingArc>,
+    pending_texts: Vec<PendingText>,
+    tasks: Vec<Task<GizmoBuffer>>,
+    persistent: Vec<PersistentGizmos>,
+    screen_sized: Vec<ScreenSizedGizmos>,
+    /// Whether any gizmo was drawn with [`Gizmos::DEFAULT_COLOR`].
+    default_colored: bool,
+    /// Whether the system was warned about drawing gizmos too late for the frame.
+    warned_late: bool,
+    /// The shapes drawn by the system, if the [`GizmoTestPlugin`](crate::testing::GizmoTestPlugin) is added.
+    capture: Option<ShapeCapture>,
+    /// Whether the gizmos are turned into geometry instead of being rendered,
+    /// so they are drawn even in stripped and headless builds, see [`GizmoStorage::draw_retained`].
+    retained: bool,
+    tags: Vec<TaggedLines>,
+    /// The gizmos drawn with the 2D methods, such as [`Gizmos::line_2d`], kept apart from the
+    /// 3D gizmos until they are stored, see [`GizmoConfig::enabled_2d`].
+    ///
+    /// They are stored after the 3D gizmos, so they are rendered after the 3D gizmos of the
+    /// same system rather than in the order they were drawn in.
+    gizmos_2d: Option<Box<GizmoBuffer>>,
+    /// Whether this is the [`GizmoBuffer::gizmos_2d`] of another buffer.
+    is_2d: bool,
+}
+
+/// The lengths of the items of a [`GizmoBuffer`] when a [`Gizmos::tagged`] scope starts.
+#[derive(Default)]
+struct BufferMark {
+    list: usize,
+    strip: usize,
+    tags: usize,
+    arcs: usize,
+    texts: usize,
+    persistent: usize,
+    screen_sized: usize,
+    gizmos_2d: Option<Box<BufferMark>>,
+}
+
+impl SystemBuffer for GizmoBuffer {
+    fn apply(&mut self, system_meta: &SystemMeta, world: &mut World) {
+        world.resource_scope(|world, mut storage: Mut<GizmoStorage>| {
+            if cfg!(debug_assertions) && storage.frame_sent && !self.warned_late && !self.is_empty()
+            {
+                warn!(
+                    "The system `{}` drew gizmos after they were sent to the render world in `Last`, they are rendered in the next frame instead. Draw them before the `ReadGizmos` set, such as in `PostUpdate`.",
+                    system_meta.name()
+                );
+                self.warned_late = true;
+            }
+            // Lines added outside of systems, such as by commands, are kept apart and first.
+            storage.end_chunk(0);
+            let (vertices, arcs, texts) = (
+                storage.list_positions.len() + storage.strip_positions.len(),
+                storage.pending_arcs.len(),
+                storage.pending_texts.len(),
+            );
+            self.append_to(&mut storage, world.resource::<GizmoConfig>());
+            if world.contains_resource::<GizmoUsage>() {
+                let usage = SystemUsage {
+                    system: system_meta.name().to_owned(),
+                    vertices: (storage.list_positions.len() + storage.strip_positions.len())
+                        .saturating_sub(vertices),
+                    arcs: arcs..storage.pending_arcs.len(),
+                    texts: texts..storage.pending_texts.len(),
+                };
+                storage.usage.push(usage);
+            }
+            let mut hasher = AHasher::default();
+            hasher.write(system_meta.name().as_bytes());
+            storage.end_chunk(hasher.finish().max(1));
+        });
+        if let Some(capture) = &mut self.capture {
+            if let Some(mut captured) = world.get_resource_mut::<CapturedGizmos>() {
+                captured.extend(system_meta.name(), capture.shapes.drain(..));
+            }
+        }
+    }
+}
+
+impl GizmoBuffer {
+    /// A buffer for a system of `world`, which captures the shapes the system draws
+    /// if the [`GizmoTestPlugin`](crate::testing::GizmoTestPlugin) is added.
+    fn for_world(world: &World) -> Self {
+        Self {
+            capture: world
+                .contains_resource::<CapturedGizmos>()
+                .then(ShapeCapture::default),
+            ..Default::default()
+        }
+    }
+
+    /// Whether no gizmos were drawn into the buffer.
+    fn is_empty(&self) -> bool {
+        self.list_positions.is_empty()
+            && self.strip_positions.is_empty()
+            && self.pending_arcs.is_empty()
+            && self.pending_texts.is_empty()
+            && self.tasks.is_empty()
+            && self.persistent.is_empty()
+            && self.screen_sized.is_empty()
+            && self
+                .gizmos_2d
+                .as_ref()
+                .map_or(true, |gizmos_2d| gizmos_2d.is_empty())
+    }
+
+    /// Drops the 2D or 3D gizmos of the buffer, including those of its persistent and
+    /// screen-sized gizmos, see [`GizmoConfig::enabled_2d`].
+    ///
+    /// Tasks are kept, as their gizmos are only known once they finish.
+    /// Their gizmos are dropped when they are stored instead.
+    fn drop_dimension(&mut self, is_2d: bool) {
+        if self.is_2d == is_2d {
+            self.list_positions.clear();
+            self.list_colors.clear();
+            self.strip_positions.clear();
+            self.strip_colors.clear();
+            self.pending_arcs.clear();
+            self.pending_texts.clear();
+            self.tags.clear();
+            self.default_colored = false;
+        }
+        for gizmos in &mut self.persistent {
+            gizmos.buffer.drop_dimension(is_2d);
+        }
+        self.persistent.retain(|gizmos| !gizmos.buffer.is_empty());
+        for gizmos in &mut self.screen_sized {
+            gizmos.buffer.drop_dimension(is_2d);
+        }
+        self.screen_sized.retain(|gizmos| !gizmos.buffer.is_empty());
+        if let Some(gizmos_2d) = &mut self.gizmos_2d {
+            gizmos_2d.drop_dimension(is_2d);
+        }
+    }
+
+    /// The buffer of the gizmos drawn with the 2D methods, see [`GizmoBuffer::gizmos_2d`].
+    fn buffer_2d(&mut self) -> &mut GizmoBuffer {
+        let retained = self.retained;
+        self.gizmos_2d.get_or_insert_with(|| {
+            Box::new(GizmoBuffer {
+                retained,
+                is_2d: true,
+                ..Default::default()
+            })
+        })
+    }
+
+    /// Moves the gizmos into the `storage`, dropping gizmos that exceed the
+    /// [vertex budget](GizmoConfig::vertex_budget) as set by [`GizmoConfig::budget_overflow`]
+    /// and the gizmos of disabled dimensions, see [`GizmoConfig::enabled_2d`].
+    fn append_to(&mut self, storage: &mut GizmoStorage, config: &GizmoConfig) {
+        let enabled = if self.is_2d {
+            config.enabled_2d
+        } else {
+            config.enabled_3d
+        };
+        if !enabled && !self.retained {
+            self.drop_dimension(self.is_2d);
+        }
+        if mem::take(&mut self.default_colored) {
+            let default_color = config.default_color.as_linear_rgba_f32();
+            let arc_colors = self.pending_arcs.iter_mut().map(|arc| &mut arc.color);
+            let text_colors = self.pending_texts.iter_mut().map(|text| &mut text.color);
+            for color in self
+                .list_colors
+                .iter_mut()
+                .chain(&mut self.strip_colors)
+                .chain(arc_colors)
+                .chain(text_colors)
+            {
+                if is_default_color_item(*color) {
+                    *color = default_color;
+                }
+            }
+        }
+        if let Some(max_vertices) = config.vertex_budget() {
+            match config.budget_overflow {
+                GizmoBudgetOverflow::DropNewest => {
+                    let budget = max_vertices.saturating_sub(storage.vertex_count());
+                    if self.truncate(budget) {
+                        storage.truncated = true;
+                    }
+                }
+                GizmoBudgetOverflow::DropOldest => {
+                    let (list_len, strip_len) =
+                        (self.list_positions.len(), self.strip_positions.len());
+                    let excess = (storage.vertex_count() + list_len + strip_len)
+                        .saturating_sub(max_vertices);
+                    if excess > 0 {
+                        // The gizmos of earlier systems are older than any of this buffer.
+                        let dropped = storage.drop_oldest(excess);
+                        drop_oldest_lines(
+                            [&mut self.list_positions, &mut self.strip_positions],
+                            [&mut self.list_colors, &mut self.strip_colors],
+                            excess.saturating_sub(dropped),
+                        );
+                        shift_removed_tags(
+                            &mut self.tags,
+                            list_len - self.list_positions.len(),
+                            strip_len - self.strip_positions.len(),
+                        );
+                        storage.truncated = true;
+                    }
+                }
+            }
+        }
+        let tags = offset_tags(
+            self.tags.drain(..),
+            storage.list_positions.len(),
+            storage.strip_positions.len(),
+        );
+        storage.tags.extend(tags);
+        storage.list_positions.append(&mut self.list_positions);
+        storage.list_colors.append(&mut self.list_colors);
+        storage.strip_positions.append(&mut self.strip_positions);
+        storage.strip_colors.append(&mut self.strip_colors);
+        storage.pending_arcs.append(&mut self.pending_arcs);
+        storage.pending_texts.append(&mut self.pending_texts);
+        storage.tasks.append(&mut self.tasks);
+        storage.persistent.append(&mut self.persistent);
+        storage.screen_sized.append(&mut self.screen_sized);
+        if let Some(gizmos_2d) = &mut self.gizmos_2d {
+            gizmos_2d.append_to(storage, config);
+        }
+    }
+
+    /// Moves all the gizmos of `other` into this buffer.
+    fn append(&mut self, other: &mut GizmoBuffer) {
+        let tags = offset_tags(
+            other.tags.drain(..),
+            self.list_positions.len(),
+            self.strip_positions.len(),
+        );
+        self.tags.extend(tags);
+        self.list_positions.append(&mut other.list_positions);
+        self.list_colors.append(&mut other.list_colors);
+        self.strip_positions.append(&mut other.strip_positions);
+        self.strip_colors.append(&mut other.strip_colors);
+        self.pending_arcs.append(&mut other.pending_arcs);
+        self.pending_texts.append(&mut other.pending_texts);
+        self.tasks.append(&mut other.tasks);
+        self.persistent.append(&mut other.persistent);
+        self.screen_sized.append(&mut other.screen_sized);
+        self.default_colored |= mem::take(&mut other.default_colored);
+        if let Some(other_2d) = &mut other.gizmos_2d {
+            self.buffer_2d().append(other_2d);
+        }
+    }
+
+    /// Copies the lines and arcs of this buffer into `target`.
+    fn copy_to(&self, target: &mut GizmoBuffer) {
+        let tags = offset_tags(
+            self.tags.iter().cloned(),
+            target.list_positions.len(),
+            target.strip_positions.len(),
+        );
+        target.tags.extend(tags);
+        target
+            .list_positions
+            .extend_from_slice(&self.list_positions);
+        target.list_colors.extend_from_slice(&self.list_colors);
+        target
+            .strip_positions
+            .extend_from_slice(&self.strip_positions);
+        target.strip_colors.extend_from_slice(&self.strip_colors);
+        target.pending_arcs.extend_from_slice(&self.pending_arcs);
+        target.pending_texts.extend_from_slice(&self.pending_texts);
+        target
+            .screen_sized
+            .extend(self.screen_sized.iter().map(ScreenSizedGizmos::copy));
+        target.default_colored |= self.default_colored;
+        if let Some(gizmos_2d) = &self.gizmos_2d {
+            gizmos_2d.copy_to(target.buffer_2d());
+        }
+    }
+
+    /// Scales the lines, arcs and texts of this buffer by `scale` and moves them to `anchor`,
+    /// see [`resolve_screen_sized_gizmos`].
+    fn scale_around(&mut self, anchor: Vec3, scale: f32) {
+        let to_world = |position: &mut PositionItem| {
+            *position = (anchor + Vec3::from(*position) * scale).to_array();
+        };
+        self.list_positions.iter_mut().for_each(to_world);
+        self.strip_positions.iter_mut().for_each(to_world);
+        for arc in &mut self.pending_arcs {
+            arc.center = anchor + arc.center * scale;
+            arc.radius *= scale;
+        }
+        for text in &mut self.pending_texts {
+            text.transform = Affine3A::from_translation(anchor)
+                * Affine3A::from_scale(Vec3::splat(scale))
+                * text.transform;
+        }
+        if let Some(gizmos_2d) = &mut self.gizmos_2d {
+            gizmos_2d.scale_around(anchor, scale);
+        }
+    }
+
+    /// Drops primitives until at most `budget` vertices remain.
+    ///
+    /// Returns `true` if anything was dropped.
+    fn truncate(&mut self, budget: usize) -> bool {
+        if self.list_positions.len() + self.strip_positions.len() <= budget {
+            return false;
+        }
+
+        // Lines are made of two vertices, so only keep complete pairs.
+        let list_len = self.list_positions.len().min(budget) & !1;
+        self.list_positions.truncate(list_len);
+        self.list_colors.truncate(list_len);
+
+        // Strips are terminated by a NaN separator, so only keep complete strips.
+        let strip_budget = (budget - list_len).min(self.strip_positions.len());
+        let strip_len = self.strip_positions[..strip_budget]
+            .iter()
+            .rposition(|position| position[0].is_nan())
+            .map_or(0, |index| index + 1);
+        self.strip_positions.truncate(strip_len);
+        self.strip_colors.truncate(strip_len);
+
+        for tagged in &mut self.tags {
+            tagged.list = tagged.list.start.min(list_len)..tagged.list.end.min(list_len);
+            tagged.strip = tagged.strip.start.min(strip_len)..tagged.strip.end.min(strip_len);
+        }
+        self.tags
+            .retain(|tagged| !tagged.list.is_empty() || !tagged.strip.is_empty());
+
+        true
+    }
+
+    /// Removes the line strip added from `start` on if it has a non-finite position,
+    /// which would otherwise split it or connect it to other strips.
+    ///
+    /// Returns `true` if it was removed.
+    fn truncate_invalid_strip(&mut self, start: usize) -> bool {
+        let end = self.strip_positions.len() - 1;
+        let invalid = self.strip_positions[start..end]
+            .iter()
+            .any(|position| !Vec3::from(*position).is_finite());
+        if invalid {
+            self.strip_positions.truncate(start);
+            self.strip_colors.truncate(start);
+        }
+        invalid
+    }
+
+    fn mark(&self) -> BufferMark {
+        BufferMark {
+            list: self.list_positions.len(),
+            strip: self.strip_positions.len(),
+            tags: self.tags.len(),
+            arcs: self.pending_arcs.len(),
+            texts: self.pending_texts.len(),
+            persistent: self.persistent.len(),
+            screen_sized: self.screen_sized.len(),
+            gizmos_2d: self
+                .gizmos_2d
+                .as_ref()
+                .map(|gizmos_2d| Box::new(gizmos_2d.mark())),
+        }
+    }
+
+    /// Tags the gizmos drawn since `mark` with `tag`, except those already tagged
+    /// by nested [`Gizmos::tagged`] scopes.
+    fn tag_since(&mut self, tag: GizmoTag, mark: &BufferMark) {
+        let nested = &self.tags[mark.tags..];
+        let list = untagged_ranges(
+            mark.list..self.list_positions.len(),
+            nested.iter().map(|tagged| tagged.list.clone()),
+        );
+        let strip = untagged_ranges(
+            mark.strip..self.strip_positions.len(),
+            nested.iter().map(|tagged| tagged.strip.clone()),
+        );
+        let list = list.into_iter().map(|list| TaggedLines {
+            tag,
+            list,
+            strip: 0..0,
+        });
+        let strip = strip.into_iter().map(|strip| TaggedLines {
+            tag,
+            list: 0..0,
+            strip,
+        });
+        self.tags.extend(list.chain(strip));
+
+        for arc in &mut self.pending_arcs[mark.arcs..] {
+            arc.tag.get_or_insert(tag);
+        }
+        for text in &mut self.pending_texts[mark.texts..] {
+            text.tag.get_or_insert(tag);
+        }
+        for persistent in &mut self.persistent[mark.persistent..] {
+            persistent.buffer.tag_since(tag, &BufferMark::default());
+        }
+        for screen_sized in &mut self.screen_sized[mark.screen_sized..] {
+            screen_sized.buffer.tag_since(tag, &BufferMark::default());
+        }
+        if let Some(gizmos_2d) = &mut self.gizmos_2d {
+            let mark_2d = mark.gizmos_2d.as_deref();
+            gizmos_2d.tag_since(tag, mark_2d.unwrap_or(&BufferMark::default()));
+        }
+    }
 }
 
-impl SystemBuffer for GizmoBuffer {
-    fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
-        let mut storage = world.resource_mut::<GizmoStorage>();
-        storage.list_positions.append(&mut self.list_positions);
-        storage.list_colors.append(&mut self.list_colors);
-        storage.strip_positions.append(&mut self.strip_positions);
-        storage.strip_colors.append(&mut self.strip_colors);
+/// The parts of `range` not covered by the `tagged` ranges.
+fn untagged_ranges(
+    range: Range<usize>,
+    tagged: impl Iterator<Item = Range<usize>>,
+) -> Vec<Range<usize>> {
+    let mut tagged: Vec<_> = tagged.filter(|range| !range.is_empty()).collect();
+    tagged.sort_by_key(|range| range.start);
+    let mut untagged = Vec::new();
+    let mut start = range.start;
+    for tagged in tagged {
+        if tagged.start > start {
+            untagged.push(start..tagged.start);
+        }
+        start = start.max(tagged.end);
+    }
+    if range.end > start {
+        untagged.push(start..range.end);
     }
+    untagged
 }
 
 impl<'s> Gizmos<'s> {
+    /// A placeholder for [`GizmoConfig::default_color`], for gizmos of a single color
+    /// to not repeat it at every call site.
+    ///
+    /// It is recognized by its negative infinite alpha. Modified default colors are not
+    /// supported: changing its red, green or blue still draws the default color, while
+    /// changing its alpha, such as with [`Color::with_a`], draws the modified color as is.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.line(Vec3::ZERO, Vec3::X, Gizmos::DEFAULT_COLOR);
+    ///     gizmos.circle(Vec3::ZERO, Vec3::Y, 1., Gizmos::DEFAULT_COLOR);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    ///
+    /// fn setup(mut config: ResMut<GizmoConfig>) {
+    ///     config.default_color = Color::RED;
+    /// }
+    /// # bevy_ecs::system::assert_is_system(setup);
+    /// ```
+    pub const DEFAULT_COLOR: Color = Color::RgbaLinear {
+        red: 0.,
+        green: 0.,
+        blue: 0.,
+        alpha: f32::NEG_INFINITY,
+    };
+
     /// Draw a line in 3D from `start` to `end`.
     ///
+    /// Positions can be given as anything convertible into a [`Vec3`],
+    /// such as arrays, tuples or [`Vec3A`](bevy_math::Vec3A), here and in the other drawing methods.
+    ///
     /// This should be called for each frame the line needs to be rendered.
     ///
     /// # Example
@@ -62,13 +1626,27 @@ impl<'s> Gizmos<'s> {
     /// # use bevy_math::prelude::*;
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.line(Vec3::ZERO, Vec3::X, Color::GREEN);
+    ///     gizmos.line([0., 1., 0.], (1., 1., 0.), Color::GREEN);
     /// }
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn line(&mut self, start: Vec3, end: Vec3, color: Color) {
-        self.extend_list_positions([start, end]);
-        self.add_list_color(color, 2);
+    pub fn line(&mut self, start: impl Into<Vec3>, end: impl Into<Vec3>, color: Color) {
+        if self.is_disabled() {
+            return;
+        }
+        let (start, end) = (start.into(), end.into());
+        skip_invalid!(
+            !start.is_finite() || !end.is_finite(),
+            "Skipped drawing a line with a non-finite position, got a line from {start} to {end}"
+        );
+        self.capture(
+            || CapturedShape::Line { start, end, color },
+            |gizmos| {
+                gizmos.extend_list_positions([start, end]);
+                gizmos.add_list_color(color, 2);
+            },
+        );
     }
 
     /// Draw a line in 3D with a color gradient from `start` to `end`.
@@ -86,9 +1664,31 @@ impl<'s> Gizmos<'s> {
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn line_gradient(&mut self, start: Vec3, end: Vec3, start_color: Color, end_color: Color) {
-        self.extend_list_positions([start, end]);
-        self.extend_list_colors([start_color, end_color]);
+    pub fn line_gradient(
+        &mut self,
+        start: impl Into<Vec3>,
+        end: impl Into<Vec3>,
+        start_color: Color,
+        end_color: Color,
+    ) {
+        if self.is_disabled() {
+            return;
+        }
+        let (start, end) = (start.into(), end.into());
+        skip_invalid!(
+            !start.is_finite() || !end.is_finite(),
+            "Skipped drawing a line with a non-finite position, got a line from {start} to {end}"
+        );
+        let shape = || CapturedShape::LineGradient {
+            start,
+            end,
+            start_color,
+            end_color,
+        };
+        self.capture(shape, |gizmos| {
+            gizmos.extend_list_positions([start, end]);
+            gizmos.extend_list_colors([start_color, end_color]);
+        });
     }
 
     /// Draw a line in 3D from `start` to `start + vector`.
@@ -106,8 +1706,9 @@ impl<'s> Gizmos<'s> {
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn ray(&mut self, start: Vec3, vector: Vec3, color: Color) {
-        self.line(start, start + vector, color);
+    pub fn ray(&mut self, start: impl Into<Vec3>, vector: impl Into<Vec3>, color: Color) {
+        let start = start.into();
+        self.line(start, start + vector.into(), color);
     }
 
     /// Draw a line in 3D with a color gradient from `start` to `start + vector`.
@@ -127,12 +1728,13 @@ impl<'s> Gizmos<'s> {
     #[inline]
     pub fn ray_gradient(
         &mut self,
-        start: Vec3,
-        vector: Vec3,
+        start: impl Into<Vec3>,
+        vector: impl Into<Vec3>,
         start_color: Color,
         end_color: Color,
     ) {
-        self.line_gradient(start, start + vector, start_color, end_color);
+        let start = start.into();
+        self.line_gradient(start, start + vector.into(), start_color, end_color);
     }
 
     /// Draw a line in 3D made of straight segments between the points.
@@ -146,16 +1748,58 @@ impl<'s> Gizmos<'s> {
     /// # use bevy_math::prelude::*;
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.linestrip([Vec3::ZERO, Vec3::X, Vec3::Y], Color::GREEN);
+    ///     gizmos.linestrip([[0., 0., 1.], [1., 0., 1.]], Color::GREEN);
+    ///
+    ///     // Smooth the corners of a path preview.
+    ///     gizmos
+    ///         .linestrip([Vec3::ZERO, Vec3::X * 4., Vec3::new(4., 0., 4.)], Color::YELLOW)
+    ///         .rounded_corners(0.5);
     /// }
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn linestrip(&mut self, positions: impl IntoIterator<Item = Vec3>, color: Color) {
+    pub fn linestrip(
+        &mut self,
+        positions: impl IntoIterator<Item = impl Into<Vec3>>,
+        color: Color,
+    ) -> LinestripBuilder<'_, 's, impl Iterator<Item = Vec3>> {
+        LinestripBuilder {
+            gizmos: self,
+            positions: Some(positions.into_iter().map(Into::into)),
+            color,
+            corners: None,
+            is_2d: false,
+        }
+    }
+
+    /// Draws the line strip of a [`LinestripBuilder`].
+    #[inline]
+    fn draw_linestrip(&mut self, positions: impl Iterator<Item = Vec3>, color: Color) {
+        if self.is_capturing() {
+            let positions: Vec<Vec3> = positions.collect();
+            skip_invalid!(
+                !positions.iter().all(|position| position.is_finite()),
+                "Skipped drawing a line strip with a non-finite position, got {positions:?}"
+            );
+            let shape = || CapturedShape::Linestrip {
+                positions: positions.clone(),
+                color,
+            };
+            self.capture(shape, |gizmos| {
+                gizmos.draw_linestrip(positions.iter().copied(), color);
+            });
+            return;
+        }
+        let start = self.buffer.strip_positions.len();
         self.extend_strip_positions(positions);
+        let invalid = self.buffer.truncate_invalid_strip(start);
+        skip_invalid!(
+            invalid,
+            "Skipped drawing a line strip with a non-finite position"
+        );
         let len = self.buffer.strip_positions.len();
-        self.buffer
-            .strip_colors
-            .resize(len - 1, color.as_linear_rgba_f32());
+        let color = self.color_item(color);
+        self.buffer.strip_colors.resize(len - 1, color);
         self.buffer.strip_colors.push([f32::NAN; 4]);
     }
 
@@ -178,8 +1822,30 @@ impl<'s> Gizmos<'s> {
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn linestrip_gradient(&mut self, points: impl IntoIterator<Item = (Vec3, Color)>) {
-        let points = points.into_iter();
+    pub fn linestrip_gradient(
+        &mut self,
+        points: impl IntoIterator<Item = (impl Into<Vec3>, Color)>,
+    ) {
+        if self.is_disabled() {
+            return;
+        }
+        let points = points
+            .into_iter()
+            .map(|(position, color)| (position.into(), color));
+        if self.is_capturing() {
+            let points: Vec<(Vec3, Color)> = points.collect();
+            skip_invalid!(
+                !points.iter().all(|(position, _)| position.is_finite()),
+                "Skipped drawing a line strip with a non-finite position, got {points:?}"
+            );
+            let shape = || CapturedShape::LinestripGradient {
+                points: points.clone(),
+            };
+            self.capture(shape, |gizmos| {
+                gizmos.linestrip_gradient(points.iter().copied());
+            });
+            return;
+        }
 
         let GizmoBuffer {
             strip_positions,
@@ -191,13 +1857,22 @@ impl<'s> Gizmos<'s> {
         strip_positions.reserve(min);
         strip_colors.reserve(min);
 
+        let start = strip_colors.len();
         for (position, color) in points {
-            strip_positions.push(position.to_array());
-            strip_colors.push(color.as_linear_rgba_f32());
+            strip_positions.push(transform_position(self.transform, position));
+            strip_colors.push(linear_color_item(color));
         }
-
         strip_positions.push([f32::NAN; 3]);
         strip_colors.push([f32::NAN; 4]);
+
+        let invalid = self.buffer.truncate_invalid_strip(start);
+        skip_invalid!(
+            invalid,
+            "Skipped drawing a line strip with a non-finite position"
+        );
+        self.buffer.default_colored |= self.buffer.strip_colors[start..]
+            .iter()
+            .any(|&color| is_default_color_item(color));
     }
 
     /// Draw a wireframe sphere in 3D made out of 3 circles around the axes.
@@ -212,7 +1887,8 @@ impl<'s> Gizmos<'s> {
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.sphere(Vec3::ZERO, Quat::IDENTITY, 1., Color::BLACK);
     ///
-    ///     // Each circle has 32 line-segments by default.
+    ///     // Each circle has 32 line-segments by default,
+    ///     // or as many as its size on screen requires if `GizmoConfig::adaptive_segments` is enabled.
     ///     // You may want to increase this for larger spheres.
     ///     gizmos
     ///         .sphere(Vec3::ZERO, Quat::IDENTITY, 5., Color::BLACK)
@@ -223,18 +1899,18 @@ impl<'s> Gizmos<'s> {
     #[inline]
     pub fn sphere(
         &mut self,
-        position: Vec3,
+        position: impl Into<Vec3>,
         rotation: Quat,
         radius: f32,
         color: Color,
     ) -> SphereBuilder<'_, 's> {
         SphereBuilder {
             gizmos: self,
-            position,
+            position: position.into(),
             rotation,
             radius,
             color,
-            circle_segments: DEFAULT_CIRCLE_SEGMENTS,
+            circle_segments: None,
         }
     }
 
@@ -253,9 +1929,26 @@ impl<'s> Gizmos<'s> {
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn rect(&mut self, position: Vec3, rotation: Quat, size: Vec2, color: Color) {
-        let [tl, tr, br, bl] = rect_inner(size).map(|vec2| position + rotation * vec2.extend(0.));
-        self.linestrip([tl, tr, br, bl, tl], color);
+    pub fn rect(&mut self, position: impl Into<Vec3>, rotation: Quat, size: Vec2, color: Color) {
+        let position = position.into();
+        skip_invalid!(
+            !size.is_finite() || !position.is_finite() || !rotation.is_finite(),
+            "Skipped drawing a rect with a non-finite size, position or rotation, got a size of {size} at {position} and the rotation {rotation}"
+        );
+        if size == Vec2::ZERO {
+            return;
+        }
+        let shape = || CapturedShape::Rect {
+            position,
+            rotation,
+            size,
+            color,
+        };
+        self.capture(shape, |gizmos| {
+            let [tl, tr, br, bl] =
+                rect_inner(size).map(|vec2| position + rotation * vec2.extend(0.));
+            gizmos.linestrip([tl, tr, br, bl, tl], color);
+        });
     }
 
     /// Draw a wireframe cube in 3D.
@@ -269,28 +1962,174 @@ impl<'s> Gizmos<'s> {
     /// # use bevy_transform::prelude::*;
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.cuboid(Transform::IDENTITY, Color::GREEN);
+    ///
+    ///     // Show which way an entity faces by marking its faces in distinct colors.
+    ///     gizmos
+    ///         .cuboid(Transform::from_xyz(2., 0., 0.), Color::GRAY)
+    ///         .face_colors([
+    ///             Color::RED,
+    ///             Color::MAROON,
+    ///             Color::GREEN,
+    ///             Color::DARK_GREEN,
+    ///             Color::BLUE,
+    ///             Color::MIDNIGHT_BLUE,
+    ///         ]);
     /// }
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn cuboid(&mut self, transform: impl TransformPoint, color: Color) {
-        let rect = rect_inner(Vec2::ONE);
-        // Front
-        let [tlf, trf, brf, blf] = rect.map(|vec2| transform.transform_point(vec2.extend(0.5)));
-        // Back
-        let [tlb, trb, brb, blb] = rect.map(|vec2| transform.transform_point(vec2.extend(-0.5)));
-
-        let strip_positions = [
-            tlf, trf, brf, blf, tlf, // Front
-            tlb, trb, brb, blb, tlb, // Back
-        ];
-        self.linestrip(strip_positions, color);
-
-        let list_positions = [
-            trf, trb, brf, brb, blf, blb, // Front to back
-        ];
-        self.extend_list_positions(list_positions);
-        self.add_list_color(color, 6);
+    pub fn cuboid<T: TransformPoint>(
+        &mut self,
+        transform: T,
+        color: Color,
+    ) -> CuboidBuilder<'_, 's, T> {
+        CuboidBuilder {
+            gizmos: self,
+            transform,
+            color,
+            edge_colors: None,
+            face_colors: None,
+        }
+    }
+
+    /// Draw L-shaped brackets at the corners of a cube in 3D instead of its whole outline,
+    /// such as to mark an entity as selected or targeted without cluttering it.
+    ///
+    /// The cube is placed by `transform` as with [`Gizmos::cuboid`]. The brackets reach `length`
+    /// along each edge, and at most halfway, so long enough brackets draw the whole cube.
+    ///
+    /// This should be called for each frame the brackets need to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.corner_brackets(Transform::from_scale(Vec3::splat(2.)), 0.25, Color::ORANGE);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn corner_brackets(&mut self, transform: impl TransformPoint, length: f32, color: Color) {
+        if self.is_disabled() {
+            return;
+        }
+        skip_invalid!(
+            length < 0. || length.is_nan() || !is_finite_transform(&transform),
+            "Skipped drawing corner brackets with a negative or NaN length or a non-finite transform, got a length of {}",
+            length
+        );
+        let shape = || CapturedShape::CornerBrackets {
+            transform: unit_cube_affine(&transform),
+            length,
+            color,
+        };
+        self.capture(shape, |gizmos| {
+            for x in [-0.5, 0.5] {
+                for y in [-0.5, 0.5] {
+                    for z in [-0.5, 0.5] {
+                        let corner = Vec3::new(x, y, z);
+                        let start = transform.transform_point(corner);
+                        for axis in Vec3::AXES {
+                            let neighbor = transform.transform_point(corner - axis * corner * 2.);
+                            let end = bracket_end(start, neighbor, length);
+                            // Flat cubes have edges without length along some axes.
+                            if end != start {
+                                gizmos.line(start, end, color);
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Draw a point at each of the `positions`, as a square [`GizmoConfig::line_width`] wide,
+    /// such as to visualize sampled data.
+    ///
+    /// The points are added in bulk, with little work per point,
+    /// so that hundreds of thousands of them can be drawn every frame.
+    /// Mind the [vertex budget](GizmoConfig::vertex_budget), each point takes two vertices.
+    ///
+    /// This should be called for each frame the points need to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     let samples: Vec<Vec3> = (0..100_000)
+    ///         .map(|i| Vec3::new((i % 1000) as f32, 0., (i / 1000) as f32) * 0.1)
+    ///         .collect();
+    ///     gizmos.point_cloud(&samples, Color::CYAN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn point_cloud(&mut self, positions: &[Vec3], color: Color) {
+        if self.is_disabled() {
+            return;
+        }
+        skip_invalid!(
+            !positions.iter().all(|position| position.is_finite()),
+            "Skipped drawing a point cloud with a non-finite position"
+        );
+        let shape = || CapturedShape::PointCloud {
+            positions: positions.to_vec(),
+            color,
+        };
+        self.capture(shape, |gizmos| {
+            gizmos.extend_point_positions(positions);
+            gizmos.add_list_color(color, positions.len() * 2);
+        });
+    }
+
+    /// Draw a point at each of the `positions` in the color at the same index of `colors`,
+    /// as a square [`GizmoConfig::line_width`] wide.
+    ///
+    /// See [`Gizmos::point_cloud`] for how the points are drawn.
+    ///
+    /// This should be called for each frame the points need to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     # let (samples, densities): (Vec<Vec3>, Vec<f32>) = Default::default();
+    ///     let colors: Vec<Color> = densities
+    ///         .iter()
+    ///         .map(|&density| GizmoColormap::Viridis.color_by(density, 0.0..=1.0))
+    ///         .collect();
+    ///     gizmos.point_cloud_colored(&samples, &colors);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn point_cloud_colored(&mut self, positions: &[Vec3], colors: &[Color]) {
+        if self.is_disabled() {
+            return;
+        }
+        debug_assert_eq!(
+            positions.len(),
+            colors.len(),
+            "each point of a point cloud must have a color"
+        );
+        let len = positions.len().min(colors.len());
+        let (positions, colors) = (&positions[..len], &colors[..len]);
+        skip_invalid!(
+            !positions.iter().all(|position| position.is_finite()),
+            "Skipped drawing a point cloud with a non-finite position"
+        );
+        let shape = || CapturedShape::PointCloudColored {
+            positions: positions.to_vec(),
+            colors: colors.to_vec(),
+        };
+        self.capture(shape, |gizmos| {
+            gizmos.extend_point_positions(positions);
+            gizmos.extend_list_colors(colors.iter().flat_map(|&color| [color, color]));
+        });
     }
 
     /// Draw a line in 2D from `start` to `end`.
@@ -308,8 +2147,11 @@ impl<'s> Gizmos<'s> {
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn line_2d(&mut self, start: Vec2, end: Vec2, color: Color) {
-        self.line(start.extend(0.), end.extend(0.), color);
+    pub fn line_2d(&mut self, start: impl Into<Vec2>, end: impl Into<Vec2>, color: Color) {
+        let (start, end) = (start.into(), end.into());
+        self.draw_2d(true, |gizmos| {
+            gizmos.line(start.extend(0.), end.extend(0.), color);
+        });
     }
 
     /// Draw a line in 2D with a color gradient from `start` to `end`.
@@ -329,12 +2171,15 @@ impl<'s> Gizmos<'s> {
     #[inline]
     pub fn line_gradient_2d(
         &mut self,
-        start: Vec2,
-        end: Vec2,
+        start: impl Into<Vec2>,
+        end: impl Into<Vec2>,
         start_color: Color,
         end_color: Color,
     ) {
-        self.line_gradient(start.extend(0.), end.extend(0.), start_color, end_color);
+        let (start, end) = (start.into(), end.into());
+        self.draw_2d(true, |gizmos| {
+            gizmos.line_gradient(start.extend(0.), end.extend(0.), start_color, end_color);
+        });
     }
 
     /// Draw a line in 2D made of straight segments between the points.
@@ -352,8 +2197,17 @@ impl<'s> Gizmos<'s> {
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn linestrip_2d(&mut self, positions: impl IntoIterator<Item = Vec2>, color: Color) {
-        self.linestrip(positions.into_iter().map(|vec2| vec2.extend(0.)), color);
+    pub fn linestrip_2d(
+        &mut self,
+        positions: impl IntoIterator<Item = impl Into<Vec2>>,
+        color: Color,
+    ) -> LinestripBuilder<'_, 's, impl Iterator<Item = Vec3>> {
+        let mut linestrip = self.linestrip(
+            positions.into_iter().map(|vec2| vec2.into().extend(0.)),
+            color,
+        );
+        linestrip.is_2d = true;
+        linestrip
     }
 
     /// Draw a line in 2D made of straight segments between the points, with a color gradient.
@@ -375,36 +2229,329 @@ impl<'s> Gizmos<'s> {
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
     #[inline]
-    pub fn linestrip_gradient_2d(&mut self, positions: impl IntoIterator<Item = (Vec2, Color)>) {
-        self.linestrip_gradient(
-            positions
-                .into_iter()
-                .map(|(vec2, color)| (vec2.extend(0.), color)),
-        );
+    pub fn linestrip_gradient_2d(
+        &mut self,
+        positions: impl IntoIterator<Item = (impl Into<Vec2>, Color)>,
+    ) {
+        self.draw_2d(true, |gizmos| {
+            gizmos.linestrip_gradient(
+                positions
+                    .into_iter()
+                    .map(|(vec2, color)| (vec2.into().extend(0.), color)),
+            );
+        });
     }
 
     /// Draw a line in 2D from `start` to `start + vector`.
     ///
-    /// This should be called for each frame the line needs to be rendered.
+    /// This should be called for each frame the line needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.ray_2d(Vec2::Y, Vec2::X, Color::GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn ray_2d(&mut self, start: impl Into<Vec2>, vector: impl Into<Vec2>, color: Color) {
+        let start = start.into();
+        self.line_2d(start, start + vector.into(), color);
+    }
+
+    /// Draw a line in 2D with a color gradient from `start` to `start + vector`.
+    ///
+    /// This should be called for each frame the line needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.line_gradient(Vec3::Y, Vec3::X, Color::GREEN, Color::RED);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn ray_gradient_2d(
+        &mut self,
+        start: impl Into<Vec2>,
+        vector: impl Into<Vec2>,
+        start_color: Color,
+        end_color: Color,
+    ) {
+        let start = start.into();
+        self.line_gradient_2d(start, start + vector.into(), start_color, end_color);
+    }
+
+    /// Draw a wireframe rectangle in 2D.
+    ///
+    /// This should be called for each frame the rectangle needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.rect_2d(Vec2::ZERO, 0., Vec2::ONE, Color::GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn rect_2d(&mut self, position: impl Into<Vec2>, rotation: f32, size: Vec2, color: Color) {
+        let position = position.into();
+        skip_invalid!(
+            !size.is_finite() || !position.is_finite() || !rotation.is_finite(),
+            "Skipped drawing a rect with a non-finite size, position or rotation, got a size of {size} at {position} and the rotation {rotation}"
+        );
+        if size == Vec2::ZERO {
+            return;
+        }
+        let shape = || CapturedShape::Rect {
+            position: position.extend(0.),
+            rotation: Quat::from_rotation_z(rotation),
+            size,
+            color,
+        };
+        self.draw_2d(true, |gizmos| {
+            gizmos.capture(shape, |gizmos| {
+                let rotation = Mat2::from_angle(rotation);
+                let [tl, tr, br, bl] = rect_inner(size).map(|vec2| position + rotation * vec2);
+                gizmos.linestrip_2d([tl, tr, br, bl, tl], color);
+            });
+        });
+    }
+
+    /// Draw L-shaped brackets at the corners of a rectangle in 2D instead of its whole outline,
+    /// such as to mark a sprite as selected or targeted without cluttering it.
+    ///
+    /// The rectangle is placed as with [`Gizmos::rect_2d`]. The brackets reach `length` along
+    /// each edge, and at most halfway, so long enough brackets draw the whole rectangle.
+    ///
+    /// This should be called for each frame the brackets need to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.corner_brackets_2d(Vec2::ZERO, 0., Vec2::new(64., 32.), 8., Color::ORANGE);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn corner_brackets_2d(
+        &mut self,
+        position: impl Into<Vec2>,
+        rotation: f32,
+        size: Vec2,
+        length: f32,
+        color: Color,
+    ) {
+        if self.is_disabled() {
+            return;
+        }
+        let position = position.into();
+        skip_invalid!(
+            length < 0. || length.is_nan() || !size.is_finite() || !position.is_finite(),
+            "Skipped drawing corner brackets with a negative or NaN length or a non-finite size or position, got a length of {} and a size of {} at {}",
+            length,
+            size,
+            position
+        );
+        if size == Vec2::ZERO {
+            return;
+        }
+        let shape = || CapturedShape::CornerBrackets {
+            transform: Affine3A::from_scale_rotation_translation(
+                size.extend(0.),
+                Quat::from_rotation_z(rotation),
+                position.extend(0.),
+            ),
+            length,
+            color,
+        };
+        let rotation = Mat2::from_angle(rotation);
+        let corners = rect_inner(size).map(|vec2| (position + rotation * vec2).extend(0.));
+        self.draw_2d(true, |gizmos| {
+            gizmos.capture(shape, |gizmos| {
+                for (index, corner) in corners.into_iter().enumerate() {
+                    let previous = corners[(index + 3) % 4];
+                    let next = corners[(index + 1) % 4];
+                    gizmos.linestrip(
+                        [
+                            bracket_end(corner, previous, length),
+                            corner,
+                            bracket_end(corner, next, length),
+                        ],
+                        color,
+                    );
+                }
+            });
+        });
+    }
+
+    /// Draw the gizmos of `draw` with all their positions transformed by `transform`,
+    /// such as the [`GlobalTransform`] of an entity to draw in its local space.
+    ///
+    /// Scopes can be nested, in which case the transforms are combined.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn system(mut gizmos: Gizmos, query: Query<&GlobalTransform>) {
+    ///     for &transform in &query {
+    ///         gizmos.with_transform(transform, |gizmos| {
+    ///             gizmos.line(Vec3::ZERO, Vec3::Y, Color::GREEN);
+    ///             gizmos.circle(Vec3::Y, Vec3::Y, 0.5, Color::GREEN);
+    ///         });
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn with_transform(
+        &mut self,
+        transform: impl Into<GlobalTransform>,
+        draw: impl FnOnce(&mut Gizmos),
+    ) {
+        let previous = self.push_transform(&transform.into());
+        draw(self);
+        self.transform = previous;
+    }
+
+    /// Draw in the local space of `transform`, such as the [`GlobalTransform`] of an entity,
+    /// through the returned [`RelativeGizmos`] until it is dropped.
+    ///
+    /// See [`Gizmos::with_transform`] to draw in a closure instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn system(mut gizmos: Gizmos, query: Query<&GlobalTransform>) {
+    ///     for transform in &query {
+    ///         let mut local = gizmos.relative_to(transform);
+    ///         local.ray(Vec3::ZERO, Vec3::NEG_Z, Color::BLUE);
+    ///         local.rect(Vec3::NEG_Z, Quat::IDENTITY, Vec2::ONE, Color::BLUE);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn relative_to<'a>(&'a mut self, transform: &GlobalTransform) -> RelativeGizmos<'a, 's> {
+        let previous = self.push_transform(transform);
+        RelativeGizmos {
+            gizmos: self,
+            previous,
+        }
+    }
+
+    /// Combines `transform` with the transform of the current scope,
+    /// returning the latter to be restored when the new scope ends.
+    fn push_transform(&mut self, transform: &GlobalTransform) -> Option<Affine3A> {
+        let previous = self.transform;
+        let transform = transform.affine();
+        self.transform = Some(previous.map_or(transform, |previous| previous * transform));
+        previous
+    }
+
+    /// Draw the gizmos of `draw` tagged with `tag`, such as the entity they belong to,
+    /// so that consumers of the lines of the frame can tell which gizmo is which,
+    /// see [`GizmoLines::tagged_segments`](crate::lines::GizmoLines::tagged_segments).
+    ///
+    /// Nested scopes override the tag of their gizmos.
+    /// Gizmos drawn by [`Gizmos::draw_async`] aren't tagged.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn system(mut gizmos: Gizmos, query: Query<(Entity, &GlobalTransform)>) {
+    ///     for (entity, transform) in &query {
+    ///         gizmos.tagged(entity, |gizmos| {
+    ///             gizmos.sphere(transform.translation(), Quat::IDENTITY, 1., Color::GREEN);
+    ///         });
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn tagged(&mut self, tag: impl Into<GizmoTag>, draw: impl FnOnce(&mut Gizmos)) {
+        if self.is_disabled() {
+            return;
+        }
+        let mark = self.buffer.mark();
+        draw(self);
+        self.buffer.tag_since(tag.into(), &mark);
+    }
+
+    /// Draw the gizmos of `draw` every frame for `duration`, starting with this one,
+    /// to keep gizmos for events that only happen during a single frame visible.
+    ///
+    /// The gizmos are drawn at least once, even if `duration` is zero.
     ///
     /// # Example
     /// ```
     /// # use bevy_gizmos::prelude::*;
     /// # use bevy_render::prelude::*;
     /// # use bevy_math::prelude::*;
+    /// # use bevy_utils::Duration;
     /// fn system(mut gizmos: Gizmos) {
-    ///     gizmos.ray_2d(Vec2::Y, Vec2::X, Color::GREEN);
+    ///     # let impact = Vec3::ZERO;
+    ///     gizmos.persist(Duration::from_secs(2), |gizmos| {
+    ///         gizmos.sphere(impact, Quat::IDENTITY, 0.2, Color::RED);
+    ///     });
     /// }
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
-    #[inline]
-    pub fn ray_2d(&mut self, start: Vec2, vector: Vec2, color: Color) {
-        self.line_2d(start, start + vector, color);
+    pub fn persist(&mut self, duration: Duration, draw: impl FnOnce(&mut Gizmos)) {
+        if self.is_disabled() {
+            return;
+        }
+        let mut buffer = GizmoBuffer {
+            capture: self
+                .buffer
+                .capture
+                .as_ref()
+                .map(|_| ShapeCapture::default()),
+            ..Default::default()
+        };
+        draw(&mut Gizmos {
+            buffer: &mut buffer,
+            transform: self.transform,
+        });
+        // The shapes are captured in the frame they are drawn in.
+        if let (Some(capture), Some(scope)) = (&mut self.buffer.capture, buffer.capture.take()) {
+            capture.shapes.extend(scope.shapes);
+        }
+        // Nested persistent gizmos expire on their own.
+        self.buffer.persistent.append(&mut buffer.persistent);
+        self.buffer.persistent.push(PersistentGizmos {
+            remaining: duration,
+            buffer,
+        });
     }
 
-    /// Draw a line in 2D with a color gradient from `start` to `start + vector`.
+    /// Draw the gizmos of `draw` around `position`, scaled so that a unit spans `size`
+    /// logical pixels on the screen, to keep markers such as pivots and waypoints
+    /// visible at any distance.
     ///
-    /// This should be called for each frame the line needs to be rendered.
+    /// The scale is resolved at the end of the frame, after cameras have moved.
+    /// When several cameras are active, each of them draws the gizmos at the right size for its own view.
+    /// Gizmos drawn by nested calls to `screen_sized` are ignored.
     ///
     /// # Example
     /// ```
@@ -412,24 +2559,53 @@ impl<'s> Gizmos<'s> {
     /// # use bevy_render::prelude::*;
     /// # use bevy_math::prelude::*;
     /// fn system(mut gizmos: Gizmos) {
-    ///     gizmos.line_gradient(Vec3::Y, Vec3::X, Color::GREEN, Color::RED);
+    ///     # let waypoint = Vec3::ZERO;
+    ///     // A diamond 20 pixels wide, however far the camera is.
+    ///     gizmos.screen_sized(waypoint, 10., |gizmos| {
+    ///         gizmos.linestrip([Vec3::X, Vec3::Y, Vec3::NEG_X, Vec3::NEG_Y, Vec3::X], Color::CYAN);
+    ///     });
     /// }
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
-    #[inline]
-    pub fn ray_gradient_2d(
+    pub fn screen_sized(
         &mut self,
-        start: Vec2,
-        vector: Vec2,
-        start_color: Color,
-        end_color: Color,
+        position: impl Into<Vec3>,
+        size: f32,
+        draw: impl FnOnce(&mut Gizmos),
     ) {
-        self.line_gradient_2d(start, start + vector, start_color, end_color);
+        if self.is_disabled() {
+            return;
+        }
+        let position = position.into();
+        // Scales are dropped along with the translation, as the scale comes from the screen.
+        let (anchor, rotation) = match self.transform {
+            Some(transform) => {
+                let (_, rotation, _) = transform.to_scale_rotation_translation();
+                (transform.transform_point3(position), Some(rotation))
+            }
+            None => (position, None),
+        };
+        let mut buffer = GizmoBuffer::default();
+        draw(&mut Gizmos {
+            buffer: &mut buffer,
+            transform: rotation.map(Affine3A::from_quat),
+        });
+        // Nested persistent gizmos expire on their own.
+        self.buffer.persistent.append(&mut buffer.persistent);
+        self.buffer.screen_sized.push(ScreenSizedGizmos {
+            anchor,
+            size,
+            buffer,
+        });
     }
 
-    /// Draw a wireframe rectangle in 2D.
+    /// Draw the gizmos of `draw` on the [`AsyncComputeTaskPool`],
+    /// so that expensive shapes such as high resolution curves don't stall the calling system.
     ///
-    /// This should be called for each frame the rectangle needs to be rendered.
+    /// The gizmos are added at the start of the [`Last`](bevy_app::Last) schedule,
+    /// which waits for the task to finish if it hasn't yet, so they are still drawn this frame.
+    ///
+    /// Without the `multi-threaded` feature, or on the web, `draw` is called immediately instead.
     ///
     /// # Example
     /// ```
@@ -437,49 +2613,243 @@ impl<'s> Gizmos<'s> {
     /// # use bevy_render::prelude::*;
     /// # use bevy_math::prelude::*;
     /// fn system(mut gizmos: Gizmos) {
-    ///     gizmos.rect_2d(Vec2::ZERO, 0., Vec2::ONE, Color::GREEN);
+    ///     gizmos.draw_async(|gizmos| {
+    ///         let points = (0..=10_000).map(|i| {
+    ///             let t = i as f32 / 100.;
+    ///             Vec3::new(t.cos(), t / 10., t.sin())
+    ///         });
+    ///         gizmos.linestrip(points, Color::GREEN);
+    ///     });
     /// }
     /// # bevy_ecs::system::assert_is_system(system);
     /// ```
+    pub fn draw_async(&mut self, draw: impl FnOnce(&mut Gizmos) + Send + 'static) {
+        if self.is_disabled() {
+            return;
+        }
+
+        #[cfg(all(feature = "multi-threaded", not(target_arch = "wasm32")))]
+        {
+            let transform = self.transform;
+            self.buffer
+                .tasks
+                .push(AsyncComputeTaskPool::get().spawn(async move {
+                    let mut buffer = GizmoBuffer::default();
+                    draw(&mut Gizmos {
+                        buffer: &mut buffer,
+                        transform,
+                    });
+                    buffer
+                }));
+        }
+
+        #[cfg(not(all(feature = "multi-threaded", not(target_arch = "wasm32"))))]
+        draw(self);
+    }
+
+    /// Draws the gizmos of `draw` as 2D gizmos if `is_2d`, so that they are dropped
+    /// along with the other 2D gizmos while [`GizmoConfig::enabled_2d`] is `false`.
+    ///
+    /// Builders shared by 2D and 3D methods pass whether they were returned by a 2D method.
     #[inline]
-    pub fn rect_2d(&mut self, position: Vec2, rotation: f32, size: Vec2, color: Color) {
-        let rotation = Mat2::from_angle(rotation);
-        let [tl, tr, br, bl] = rect_inner(size).map(|vec2| position + rotation * vec2);
-        self.linestrip_2d([tl, tr, br, bl, tl], color);
+    pub(crate) fn draw_2d(&mut self, is_2d: bool, draw: impl FnOnce(&mut Gizmos)) {
+        if !is_2d || self.buffer.is_2d {
+            draw(self);
+            return;
+        }
+        let transform = self.transform;
+        let capture = self.buffer.capture.take();
+        let buffer = self.buffer.buffer_2d();
+        buffer.capture = capture;
+        draw(&mut Gizmos { buffer, transform });
+        self.buffer.capture = self.buffer.buffer_2d().capture.take();
     }
 
+    /// Records `shape` for the [`GizmoTestPlugin`](crate::testing::GizmoTestPlugin)
+    /// unless it is drawn as part of another shape, then draws it with `draw`.
     #[inline]
-    fn extend_list_positions(&mut self, positions: impl IntoIterator<Item = Vec3>) {
+    pub(crate) fn capture(
+        &mut self,
+        shape: impl FnOnce() -> CapturedShape,
+        draw: impl FnOnce(&mut Self),
+    ) {
+        let Some(capture) = &mut self.buffer.capture else {
+            draw(self);
+            return;
+        };
+        if capture.depth == 0 {
+            let transform = self.transform.unwrap_or(Affine3A::IDENTITY);
+            capture.shapes.push((transform, shape()));
+        }
+        capture.depth += 1;
+        draw(self);
+        if let Some(capture) = &mut self.buffer.capture {
+            capture.depth -= 1;
+        }
+    }
+
+    /// Whether drawing methods return early, see [`DISABLED`].
+    #[inline]
+    fn is_disabled(&self) -> bool {
+        !self.buffer.retained && (STRIPPED || HEADLESS && self.buffer.capture.is_none())
+    }
+
+    /// Whether the next shape is recorded, see [`Gizmos::capture`].
+    #[inline]
+    fn is_capturing(&self) -> bool {
         self.buffer
-            .list_positions
-            .extend(positions.into_iter().map(|vec3| vec3.to_array()));
+            .capture
+            .as_ref()
+            .is_some_and(|capture| capture.depth == 0)
+    }
+
+    #[inline]
+    fn extend_list_positions(&mut self, positions: impl IntoIterator<Item = Vec3>) {
+        let transform = self.transform;
+        self.buffer.list_positions.extend(
+            positions
+                .into_iter()
+                .map(|vec3| transform_position(transform, vec3)),
+        );
+    }
+
+    /// Adds each point as a line of zero length, which the shader draws as a square.
+    #[inline]
+    fn extend_point_positions(&mut self, positions: &[Vec3]) {
+        let transform = self.transform;
+        let list_positions = &mut self.buffer.list_positions;
+        list_positions.reserve(positions.len() * 2);
+        for &position in positions {
+            let position = transform_position(transform, position);
+            list_positions.push(position);
+            list_positions.push(position);
+        }
     }
 
     #[inline]
     fn extend_list_colors(&mut self, colors: impl IntoIterator<Item = Color>) {
+        let start = self.buffer.list_colors.len();
         self.buffer
             .list_colors
-            .extend(colors.into_iter().map(|color| color.as_linear_rgba_f32()));
+            .extend(colors.into_iter().map(linear_color_item));
+        self.buffer.default_colored |= self.buffer.list_colors[start..]
+            .iter()
+            .any(|&color| is_default_color_item(color));
     }
 
     #[inline]
     fn add_list_color(&mut self, color: Color, count: usize) {
+        let color = self.color_item(color);
         self.buffer
             .list_colors
-            .extend(iter::repeat(color.as_linear_rgba_f32()).take(count));
+            .extend(iter::repeat(color).take(count));
+    }
+
+    /// Converts `color` for the buffers, noting whether it is [`Gizmos::DEFAULT_COLOR`].
+    #[inline]
+    fn color_item(&mut self, color: Color) -> ColorItem {
+        let color = linear_color_item(color);
+        self.buffer.default_colored |= is_default_color_item(color);
+        color
+    }
+
+    /// Draws a circle or arc whose number of segments is resolved at the end of the frame.
+    #[inline]
+    pub(crate) fn pending_arc(&mut self, mut arc: PendingArc) {
+        if self.is_disabled() {
+            return;
+        }
+        debug_assert!(
+            arc.center.is_finite() && is_valid_color_item(arc.color),
+            "gizmo positions must be finite and colors must not be NaN, got a circle at {} with the color {:?}",
+            arc.center,
+            arc.color
+        );
+        if let Some(transform) = self.transform {
+            let (scale, rotation, _) = transform.to_scale_rotation_translation();
+            let uniform = scale.abs_diff_eq(Vec3::splat(scale.x), scale.x.abs() * 1e-4);
+            if arc.billboard && !uniform {
+                // Billboards always face the camera, so they can't be skewed either.
+                arc.center = transform.transform_point3(arc.center);
+                arc.radius *= scale.abs().max_element();
+            } else if !uniform {
+                // A pending arc can't be skewed into an ellipse, so draw it right away instead.
+                let fraction = arc.arc_angle.abs() / TAU;
+                let segments = ((DEFAULT_CIRCLE_SEGMENTS as f32 * fraction).ceil() as usize).max(1);
+                let positions = arc_positions(arc.start_angle, arc.arc_angle, arc.radius, segments)
+                    .map(|vec2| arc.center + arc.rotation * vec2.extend(0.));
+                self.extend_strip_positions(positions);
+                let len = self.buffer.strip_positions.len();
+                self.buffer.default_colored |= is_default_color_item(arc.color);
+                self.buffer.strip_colors.resize(len - 1, arc.color);
+                self.buffer.strip_colors.push([f32::NAN; 4]);
+                return;
+            } else {
+                arc.center = transform.transform_point3(arc.center);
+                arc.rotation = rotation * arc.rotation;
+                arc.radius *= scale.x;
+            }
+        }
+        self.buffer.default_colored |= is_default_color_item(arc.color);
+        self.buffer.pending_arcs.push(arc);
+    }
+
+    /// Draws a text which is laid out at the end of the frame, see [`GizmoConfig::text`].
+    ///
+    /// `transform` places the text from the XY plane, with glyphs 1 unit high.
+    pub(crate) fn pending_text(&mut self, transform: Affine3A, text: &str, color: Color) {
+        if self.is_disabled() {
+            return;
+        }
+        let color = self.color_item(color);
+        self.buffer.pending_texts.push(PendingText {
+            transform: self.transform.map_or(transform, |scope| scope * transform),
+            text: text.to_owned(),
+            color,
+            tag: None,
+            view: None,
+        });
     }
 
     #[inline]
     fn extend_strip_positions(&mut self, positions: impl IntoIterator<Item = Vec3>) {
+        let transform = self.transform;
         self.buffer.strip_positions.extend(
             positions
                 .into_iter()
-                .map(|vec3| vec3.to_array())
+                .map(|vec3| transform_position(transform, vec3))
                 .chain(iter::once([f32::NAN; 3])),
         );
     }
 }
 
+/// A [`Gizmos`] wrapper returned by [`Gizmos::relative_to`] drawing in the local space
+/// of a transform.
+pub struct RelativeGizmos<'a, 's> {
+    gizmos: &'a mut Gizmos<'s>,
+    previous: Option<Affine3A>,
+}
+
+impl<'s> Deref for RelativeGizmos<'_, 's> {
+    type Target = Gizmos<'s>;
+
+    fn deref(&self) -> &Self::Target {
+        self.gizmos
+    }
+}
+
+impl DerefMut for RelativeGizmos<'_, '_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.gizmos
+    }
+}
+
+impl Drop for RelativeGizmos<'_, '_> {
+    fn drop(&mut self) {
+        self.gizmos.transform = self.previous;
+    }
+}
+
 /// A builder returned by [`Gizmos::sphere`].
 pub struct SphereBuilder<'a, 's> {
     gizmos: &'a mut Gizmos<'s>,
@@ -487,25 +2857,362 @@ pub struct SphereBuilder<'a, 's> {
     rotation: Quat,
     radius: f32,
     color: Color,
-    circle_segments: usize,
+    circle_segments: Option<usize>,
 }
 
 impl SphereBuilder<'_, '_> {
     /// Set the number of line-segments per circle for this sphere.
     pub fn circle_segments(mut self, segments: usize) -> Self {
-        self.circle_segments = segments;
+        self.circle_segments = Some(segments);
+        self
+    }
+
+    /// Set the color to that of `value` within `range` in `colormap`.
+    pub fn color_by(
+        mut self,
+        value: f32,
+        range: RangeInclusive<f32>,
+        colormap: &GizmoColormap,
+    ) -> Self {
+        self.color = colormap.color_by(value, range);
         self
     }
 }
 
 impl Drop for SphereBuilder<'_, '_> {
     fn drop(&mut self) {
-        for axis in Vec3::AXES {
-            self.gizmos
-                .circle(self.position, self.rotation * axis, self.radius, self.color)
-                .segments(self.circle_segments);
+        skip_invalid!(
+            self.radius < 0.
+                || !self.radius.is_finite()
+                || !self.position.is_finite()
+                || !self.rotation.is_finite()
+                || self.circle_segments == Some(0),
+            "Skipped drawing a sphere with a negative or non-finite radius, a non-finite position or rotation or zero segments, got a radius of {} at {}, the rotation {} and {:?} segments",
+            self.radius,
+            self.position,
+            self.rotation,
+            self.circle_segments
+        );
+        if self.radius == 0. {
+            return;
+        }
+        let shape = || CapturedShape::Sphere {
+            position: self.position,
+            rotation: self.rotation,
+            radius: self.radius,
+            color: self.color,
+        };
+        self.gizmos.capture(shape, |gizmos| {
+            for axis in Vec3::AXES {
+                let circle =
+                    gizmos.circle(self.position, self.rotation * axis, self.radius, self.color);
+                if let Some(segments) = self.circle_segments {
+                    circle.segments(segments);
+                }
+            }
+        });
+    }
+}
+
+/// A builder returned by [`Gizmos::linestrip`] and [`Gizmos::linestrip_2d`].
+pub struct LinestripBuilder<'a, 's, I: Iterator<Item = Vec3>> {
+    gizmos: &'a mut Gizmos<'s>,
+    positions: Option<I>,
+    color: Color,
+    /// The radius of the corners and whether they are rounded rather than chamfered.
+    corners: Option<(f32, bool)>,
+    /// Whether the line strip was drawn with [`Gizmos::linestrip_2d`].
+    is_2d: bool,
+}
+
+impl<I: Iterator<Item = Vec3>> LinestripBuilder<'_, '_, I> {
+    /// Replace the corners of the line strip with arcs of `radius`, such as to preview
+    /// a smoothed path.
+    ///
+    /// The arcs are shrunk where a segment is shorter than twice the distance from its corners
+    /// to where their arcs start. A strip ending at its first point is rounded there too.
+    pub fn rounded_corners(mut self, radius: f32) -> Self {
+        self.corners = Some((radius, true));
+        self
+    }
+
+    /// Cut the corners of the line strip off with straight segments, where arcs of `radius`
+    /// drawn with [`LinestripBuilder::rounded_corners`] would start and end.
+    pub fn chamfered_corners(mut self, radius: f32) -> Self {
+        self.corners = Some((radius, false));
+        self
+    }
+
+    /// Set the color to that of `value` within `range` in `colormap`.
+    pub fn color_by(
+        mut self,
+        value: f32,
+        range: RangeInclusive<f32>,
+        colormap: &GizmoColormap,
+    ) -> Self {
+        self.color = colormap.color_by(value, range);
+        self
+    }
+}
+
+impl<I: Iterator<Item = Vec3>> Drop for LinestripBuilder<'_, '_, I> {
+    fn drop(&mut self) {
+        if self.gizmos.is_disabled() {
+            return;
+        }
+        let Some(positions) = self.positions.take() else {
+            return;
+        };
+        let color = self.color;
+        let corners = self.corners;
+        self.gizmos.draw_2d(self.is_2d, |gizmos| {
+            let Some((radius, rounded)) = corners else {
+                gizmos.draw_linestrip(positions, color);
+                return;
+            };
+            skip_invalid!(
+                radius < 0. || radius.is_nan(),
+                "Skipped drawing a line strip with a negative or NaN corner radius, got {}",
+                radius
+            );
+            let positions = cut_corners(positions, radius, rounded);
+            gizmos.draw_linestrip(positions.into_iter(), color);
+        });
+    }
+}
+
+/// The points of a line strip through `positions` with corners replaced by arcs of `radius`
+/// if `rounded`, or cut off where those arcs start and end otherwise.
+fn cut_corners(positions: impl Iterator<Item = Vec3>, radius: f32, rounded: bool) -> Vec<Vec3> {
+    let mut points: Vec<Vec3> = Vec::new();
+    for position in positions {
+        // Repeated points would leave their corners without a direction.
+        if points.last() != Some(&position) {
+            points.push(position);
+        }
+    }
+    let closed = points.len() > 3 && points.first() == points.last();
+    if points.len() < 3 || radius == 0. {
+        return points;
+    }
+    if closed {
+        points.pop();
+    }
+
+    let len = points.len();
+    let mut cut = Vec::with_capacity(len * 2);
+    let corners = if closed {
+        0..len
+    } else {
+        cut.push(points[0]);
+        1..len - 1
+    };
+    for index in corners {
+        let corner = points[index];
+        let previous = points[(index + len - 1) % len];
+        let next = points[(index + 1) % len];
+        cut_corner(&mut cut, previous, corner, next, radius, rounded);
+    }
+    cut.push(if closed { cut[0] } else { points[len - 1] });
+    cut
+}
+
+/// Pushes the points replacing `corner` between the segments from `previous` and to `next`.
+fn cut_corner(
+    cut: &mut Vec<Vec3>,
+    previous: Vec3,
+    corner: Vec3,
+    next: Vec3,
+    radius: f32,
+    rounded: bool,
+) {
+    let (to_previous, to_next) = (previous - corner, next - corner);
+    let angle = to_previous.angle_between(to_next);
+    // Straight corners have nothing to cut, and reversing ones have no room for an arc.
+    if !(1e-4..PI - 1e-4).contains(&angle) {
+        cut.push(corner);
+        return;
+    }
+    let half_angle = angle / 2.;
+    // Neighboring corners don't cut more than half of the segment they share.
+    let distance = (radius / half_angle.tan())
+        .min(to_previous.length() / 2.)
+        .min(to_next.length() / 2.);
+    let start = corner + to_previous.normalize() * distance;
+    let end = corner + to_next.normalize() * distance;
+    if !rounded {
+        cut.extend([start, end]);
+        return;
+    }
+
+    let bisector = (to_previous.normalize() + to_next.normalize()).normalize();
+    let center = corner + bisector * (distance / half_angle.cos());
+    let (from_center, axis) = (
+        start - center,
+        (start - center).cross(end - center).normalize(),
+    );
+    let sweep = PI - angle;
+    let segments = ((DEFAULT_CIRCLE_SEGMENTS as f32 * sweep / TAU).round() as usize).max(1);
+    cut.extend((0..=segments).map(|segment| {
+        let rotation = Quat::from_axis_angle(axis, sweep * segment as f32 / segments as f32);
+        center + rotation * from_center
+    }));
+}
+
+/// A builder returned by [`Gizmos::cuboid`].
+pub struct CuboidBuilder<'a, 's, T: TransformPoint> {
+    gizmos: &'a mut Gizmos<'s>,
+    transform: T,
+    color: Color,
+    edge_colors: Option<[Color; 3]>,
+    face_colors: Option<[Color; 6]>,
+}
+
+impl<T: TransformPoint> CuboidBuilder<'_, '_, T> {
+    /// Draw the edges along the local X, Y and Z axes of the cube in the respective `colors`,
+    /// instead of the color of the cube.
+    pub fn edge_colors(mut self, colors: [Color; 3]) -> Self {
+        self.edge_colors = Some(colors);
+        self
+    }
+
+    /// Mark the faces of the cube facing the local +X, -X, +Y, -Y, +Z and -Z axes with outlines
+    /// in the respective `colors`, inset to 80% of the size of the faces.
+    pub fn face_colors(mut self, colors: [Color; 6]) -> Self {
+        self.face_colors = Some(colors);
+        self
+    }
+
+    /// Set the color to that of `value` within `range` in `colormap`.
+    pub fn color_by(
+        mut self,
+        value: f32,
+        range: RangeInclusive<f32>,
+        colormap: &GizmoColormap,
+    ) -> Self {
+        self.color = colormap.color_by(value, range);
+        self
+    }
+}
+
+impl<T: TransformPoint> Drop for CuboidBuilder<'_, '_, T> {
+    fn drop(&mut self) {
+        if self.gizmos.is_disabled() {
+            return;
         }
+        skip_invalid!(
+            !is_finite_transform(&self.transform),
+            "Skipped drawing a cuboid with a non-finite transform"
+        );
+        let (transform, color) = (&self.transform, self.color);
+        let shape = || CapturedShape::Cuboid {
+            transform: unit_cube_affine(transform),
+            color,
+        };
+        let (edge_colors, face_colors) = (self.edge_colors, self.face_colors);
+        self.gizmos.capture(shape, |gizmos| {
+            if let Some(edge_colors) = edge_colors {
+                for (axis, color) in Vec3::AXES.into_iter().zip(edge_colors) {
+                    for offset in rect_inner(Vec2::ONE) {
+                        let offset = axis.yzx() * offset.x + axis.zxy() * offset.y;
+                        gizmos.line(
+                            transform.transform_point(offset - axis / 2.),
+                            transform.transform_point(offset + axis / 2.),
+                            color,
+                        );
+                    }
+                }
+            } else {
+                let rect = rect_inner(Vec2::ONE);
+                // Front
+                let [tlf, trf, brf, blf] =
+                    rect.map(|vec2| transform.transform_point(vec2.extend(0.5)));
+                // Back
+                let [tlb, trb, brb, blb] =
+                    rect.map(|vec2| transform.transform_point(vec2.extend(-0.5)));
+
+                let strip_positions = [
+                    tlf, trf, brf, blf, tlf, // Front
+                    tlb, trb, brb, blb, tlb, // Back
+                ];
+                gizmos.linestrip(strip_positions, color);
+
+                let list_positions = [
+                    trf, trb, brf, brb, blf, blb, // Front to back
+                ];
+                gizmos.extend_list_positions(list_positions);
+                gizmos.add_list_color(color, 6);
+            }
+
+            let Some(face_colors) = face_colors else {
+                return;
+            };
+            let normals = [
+                Vec3::X,
+                Vec3::NEG_X,
+                Vec3::Y,
+                Vec3::NEG_Y,
+                Vec3::Z,
+                Vec3::NEG_Z,
+            ];
+            for (normal, color) in normals.into_iter().zip(face_colors) {
+                let [tl, tr, br, bl] = rect_inner(Vec2::splat(0.8)).map(|offset| {
+                    let offset = normal.yzx() * offset.x + normal.zxy() * offset.y;
+                    transform.transform_point(normal / 2. + offset)
+                });
+                gizmos.linestrip([tl, tr, br, bl, tl], color);
+            }
+        });
+    }
+}
+
+/// Applies the transform of a [`Gizmos::with_transform`] scope to `position`.
+///
+/// The shapes skip non-finite positions before getting here, see [`skip_invalid`].
+#[inline]
+fn transform_position(transform: Option<Affine3A>, position: Vec3) -> PositionItem {
+    match transform {
+        Some(transform) => transform.transform_point3(position),
+        None => position,
     }
+    .to_array()
+}
+
+/// The affine transform of the unit cube placed by `transform`, for [`CapturedShape`]s.
+fn unit_cube_affine(transform: &impl TransformPoint) -> Affine3A {
+    let center = transform.transform_point(Vec3::ZERO);
+    let [x, y, z] = Vec3::AXES.map(|axis| transform.transform_point(axis) - center);
+    Affine3A::from_cols(x.into(), y.into(), z.into(), center.into())
+}
+
+/// Whether `transform` maps the unit cube to finite positions.
+fn is_finite_transform(transform: &impl TransformPoint) -> bool {
+    [Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z]
+        .into_iter()
+        .all(|point| transform.transform_point(point).is_finite())
+}
+
+/// Converts `color` for the buffers, rejecting NaN colors in debug builds.
+fn linear_color_item(color: Color) -> ColorItem {
+    let item = color.as_linear_rgba_f32();
+    debug_assert!(
+        is_valid_color_item(item),
+        "gizmo colors must not be NaN, got {color:?}"
+    );
+    item
+}
+
+/// Whether `color` has no NaN channel, which infinite channels such as the alpha
+/// of [`Gizmos::DEFAULT_COLOR`] don't count as.
+fn is_valid_color_item(color: ColorItem) -> bool {
+    !color.iter().any(|channel| channel.is_nan())
+}
+
+/// The end of a bracket from the `corner` of a shape, `length` along the edge to `neighbor`
+/// and at most halfway.
+fn bracket_end(corner: Vec3, neighbor: Vec3, length: f32) -> Vec3 {
+    let edge = neighbor - corner;
+    corner + edge.clamp_length_max(length.min(edge.length() / 2.))
 }
 
 fn rect_inner(size: Vec2) -> [Vec2; 4] {
@@ -516,3 +3223,556 @@ fn rect_inner(size: Vec2) -> [Vec2; 4] {
     let br = Vec2::new(half_size.x, -half_size.y);
     [tl, tr, br, bl]
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::TAU;
+
+    use bevy_app::{App, Last, Update};
+    use bevy_ecs::{
+        entity::Entity,
+        schedule::IntoSystemConfigs,
+        system::{Res, ResMut, Resource},
+    };
+    use bevy_math::{Affine3A, Quat, UVec2, Vec2, Vec3};
+    use bevy_render::{camera::Camera, color::Color};
+    use bevy_transform::components::{GlobalTransform, Transform};
+    use bevy_utils::{Duration, HashMap};
+
+    use super::{cut_corners, GizmoStorage, Gizmos, ParallelGizmos, PendingArc, DISABLED};
+    use crate::{
+        circles::{resolve_pending_arcs, DEFAULT_CIRCLE_SEGMENTS},
+        testing::{CapturedGizmos, CapturedShape, GizmoTestPlugin},
+        GizmoBudgetOverflow, GizmoConfig,
+    };
+
+    /// The number of shapes drawn by `system` in one app update.
+    fn count_drawn(system: fn(Gizmos)) -> usize {
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin).add_systems(Update, system);
+        app.update();
+        app.world.resource::<CapturedGizmos>().len()
+    }
+
+    #[test]
+    fn stores_gizmos_of_enabled_dimensions() {
+        fn draw(mut gizmos: Gizmos) {
+            gizmos.line(Vec3::ZERO, Vec3::Z, Color::RED);
+            gizmos.line_2d(Vec2::ZERO, Vec2::X, Color::RED);
+            gizmos.rect_2d(Vec2::ZERO, 0., Vec2::ONE, Color::RED);
+        }
+
+        for (enabled_2d, enabled_3d) in [(true, true), (false, true), (true, false)] {
+            let mut app = App::new();
+            app.add_plugins(GizmoTestPlugin)
+                .insert_resource(GizmoConfig {
+                    enabled_2d,
+                    enabled_3d,
+                    ..Default::default()
+                })
+                .add_systems(Update, draw);
+            app.update();
+
+            let storage = app.world.resource::<GizmoStorage>();
+            let mut expected_lines = Vec::new();
+            if enabled_3d {
+                expected_lines.extend([[0., 0., 0.], [0., 0., 1.]]);
+            }
+            if enabled_2d {
+                expected_lines.extend([[0., 0., 0.], [1., 0., 0.]]);
+            }
+            assert_eq!(storage.list_positions, expected_lines);
+            assert_eq!(storage.strip_positions.is_empty(), !enabled_2d);
+        }
+    }
+
+    #[test]
+    fn replaces_default_colors_with_other_channels_changed() {
+        fn draw(mut gizmos: Gizmos) {
+            gizmos.line(Vec3::ZERO, Vec3::X, Gizmos::DEFAULT_COLOR);
+            gizmos.line(Vec3::ZERO, Vec3::Y, Gizmos::DEFAULT_COLOR.with_g(1.));
+            gizmos.line(Vec3::ZERO, Vec3::Z, Gizmos::DEFAULT_COLOR.as_rgba());
+            gizmos.linestrip([Vec3::ZERO, Vec3::X], Gizmos::DEFAULT_COLOR.with_b(1.));
+        }
+
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .insert_resource(GizmoConfig {
+                default_color: Color::RED,
+                ..Default::default()
+            })
+            .add_systems(Update, draw);
+        app.update();
+
+        let storage = app.world.resource::<GizmoStorage>();
+        let red = Color::RED.as_linear_rgba_f32();
+        assert_eq!(storage.list_colors, [red; 6]);
+        assert_eq!(storage.strip_colors[..2], [red; 2]);
+    }
+
+    #[test]
+    fn drops_persistent_gizmos_of_disabled_dimensions() {
+        fn draw(mut gizmos: Gizmos) {
+            gizmos.persist(Duration::from_secs(1), |gizmos| {
+                gizmos.line(Vec3::ZERO, Vec3::Z, Color::RED);
+            });
+            gizmos.persist(Duration::from_secs(1), |gizmos| {
+                gizmos.line(Vec3::ZERO, Vec3::Z, Color::RED);
+                gizmos.line_2d(Vec2::ZERO, Vec2::X, Color::RED);
+            });
+        }
+
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .insert_resource(GizmoConfig {
+                enabled_3d: false,
+                ..Default::default()
+            })
+            .add_systems(Update, draw);
+        app.update();
+
+        let storage = app.world.resource::<GizmoStorage>();
+        let [persistent] = &storage.persistent[..] else {
+            panic!("expected only the persistent 2D gizmos to be kept");
+        };
+        assert!(persistent.buffer.list_positions.is_empty());
+        assert!(persistent
+            .buffer
+            .gizmos_2d
+            .as_ref()
+            .is_some_and(|gizmos_2d| !gizmos_2d.list_positions.is_empty()));
+    }
+
+    #[test]
+    fn stores_billboards_in_the_view_they_face() {
+        fn draw(mut gizmos: Gizmos) {
+            gizmos.billboard_circle(Vec3::ZERO, 1., Color::RED);
+        }
+
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .add_systems(Update, draw)
+            .add_systems(Last, resolve_pending_arcs);
+        let directions = [Vec3::Z, Vec3::X];
+        let cameras = directions.map(|direction| {
+            let transform = GlobalTransform::from_translation(direction * 10.);
+            app.world.spawn((Camera::default(), transform)).id()
+        });
+        app.update();
+
+        let storage = app.world.resource::<GizmoStorage>();
+        assert!(storage.strip_positions.is_empty());
+        for (camera, direction) in cameras.into_iter().zip(directions) {
+            let (_, view) = storage
+                .views
+                .iter()
+                .find(|(view, _)| *view == camera)
+                .unwrap();
+            let mut positions = view.strip_positions.iter().filter(|p| !p[0].is_nan());
+            assert_eq!(positions.clone().count(), 33);
+            assert!(positions.all(|&p| Vec3::from(p).dot(direction).abs() < 1e-5));
+        }
+    }
+
+    #[test]
+    fn resolves_arcs_of_a_view_only_in_that_view() {
+        #[derive(Resource)]
+        struct SizedFor(Entity);
+
+        fn draw(mut storage: ResMut<GizmoStorage>, sized_for: Res<SizedFor>) {
+            storage.pending_arcs.push(PendingArc {
+                center: Vec3::ZERO,
+                rotation: Quat::IDENTITY,
+                radius: 1.,
+                start_angle: 0.,
+                arc_angle: TAU,
+                color: Color::RED.as_linear_rgba_f32(),
+                billboard: true,
+                view: Some(sized_for.0),
+                tag: None,
+            });
+        }
+
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .add_systems(Update, draw)
+            .add_systems(Last, resolve_pending_arcs);
+        let [sized_for, other] = [Vec3::Z, Vec3::X].map(|direction| {
+            let transform = GlobalTransform::from_translation(direction * 10.);
+            app.world.spawn((Camera::default(), transform)).id()
+        });
+        app.insert_resource(SizedFor(sized_for));
+        app.update();
+
+        let storage = app.world.resource::<GizmoStorage>();
+        assert!(storage.strip_positions.is_empty());
+        let strips = |camera| {
+            storage
+                .views
+                .iter()
+                .find(|(view, _)| *view == camera)
+                .map_or(0, |(_, view)| view.strip_positions.len())
+        };
+        assert!(strips(sized_for) > 0);
+        assert_eq!(strips(other), 0);
+    }
+
+    #[test]
+    fn drops_the_oldest_lines_over_budget() {
+        fn draw_first(mut gizmos: Gizmos) {
+            gizmos.tagged(1, |gizmos| {
+                gizmos.line(Vec3::ZERO, Vec3::X, Color::RED);
+                gizmos.linestrip([Vec3::ZERO, Vec3::X], Color::RED);
+                gizmos.linestrip([Vec3::Y, Vec3::Z], Color::RED);
+            });
+        }
+
+        fn draw_second(mut gizmos: Gizmos) {
+            gizmos.tagged(2, |gizmos| {
+                gizmos.line(Vec3::Y, Vec3::Z, Color::BLUE);
+                gizmos.linestrip([Vec3::Z, Vec3::X], Color::BLUE);
+            });
+        }
+
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .insert_resource(GizmoConfig {
+                max_vertices: Some(10),
+                budget_overflow: GizmoBudgetOverflow::DropOldest,
+                ..Default::default()
+            })
+            .add_systems(Update, (draw_first, draw_second).chain());
+        app.update();
+
+        // The 5 vertices of the second system only fit by dropping the first line
+        // and the first strip of the first system, whose last strip survives.
+        let storage = app.world.resource::<GizmoStorage>();
+        assert!(storage.truncated);
+        assert_eq!(storage.list_positions, [[0., 1., 0.], [0., 0., 1.]]);
+        let strips: Vec<_> = storage
+            .strip_positions
+            .split(|position| position[0].is_nan())
+            .collect();
+        assert_eq!(
+            strips,
+            [
+                &[[0., 1., 0.], [0., 0., 1.]][..],
+                &[[0., 0., 1.], [1., 0., 0.]],
+                &[]
+            ]
+        );
+        assert_eq!(storage.list_colors.len(), storage.list_positions.len());
+        assert_eq!(storage.strip_colors.len(), storage.strip_positions.len());
+
+        let tags: Vec<_> = storage
+            .tags
+            .iter()
+            .map(|tagged| (tagged.tag.0, tagged.list.clone(), tagged.strip.clone()))
+            .collect();
+        assert_eq!(tags, [(1, 0..0, 0..3), (2, 0..2, 3..3), (2, 0..0, 3..6)]);
+        for tagged in storage
+            .tags
+            .iter()
+            .filter(|tagged| !tagged.strip.is_empty())
+        {
+            // Tagged strips still end with their separator.
+            assert!(storage.strip_positions[tagged.strip.end - 1][0].is_nan());
+        }
+    }
+
+    #[test]
+    fn deduplicates_identical_lines() {
+        fn draw_first(mut gizmos: Gizmos) {
+            for _ in 0..2 {
+                gizmos.line(Vec3::ZERO, Vec3::X, Color::RED);
+                gizmos.linestrip([Vec3::ZERO, Vec3::Y, Vec3::Z], Color::RED);
+            }
+            // Lines differing only in color aren't duplicates.
+            gizmos.line(Vec3::ZERO, Vec3::X, Color::BLUE);
+            gizmos.linestrip([Vec3::ZERO, Vec3::Y, Vec3::Z], Color::BLUE);
+        }
+
+        fn draw_second(mut gizmos: Gizmos) {
+            gizmos.line(Vec3::ZERO, Vec3::X, Color::RED);
+            gizmos.linestrip([Vec3::ZERO, Vec3::Y, Vec3::Z], Color::RED);
+            gizmos.line(Vec3::X, Vec3::Y, Color::RED);
+        }
+
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .add_systems(Update, (draw_first, draw_second).chain());
+        app.update();
+
+        let mut storage = app.world.resource_mut::<GizmoStorage>();
+        storage.deduplicate(&mut HashMap::default());
+
+        let [red, blue] = [Color::RED, Color::BLUE].map(|color| color.as_linear_rgba_f32());
+        assert_eq!(
+            storage.list_positions,
+            [
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [1., 0., 0.],
+                [0., 1., 0.]
+            ]
+        );
+        assert_eq!(storage.list_colors, [red, red, blue, blue, red, red]);
+        let strips: Vec<_> = storage
+            .strip_positions
+            .split(|position| position[0].is_nan())
+            .collect();
+        let strip = &[[0., 0., 0.], [0., 1., 0.], [0., 0., 1.]][..];
+        assert_eq!(strips, [strip, strip, &[]]);
+        assert_eq!(storage.strip_colors[..2], [red, red]);
+        assert_eq!(storage.strip_colors[4..6], [blue, blue]);
+    }
+
+    /// Asserts that `points` are about the `expected` points.
+    fn assert_points_eq(points: &[Vec3], expected: &[Vec3]) {
+        assert_eq!(points.len(), expected.len(), "{points:?} != {expected:?}");
+        for (point, expected) in points.iter().zip(expected) {
+            assert!(
+                point.abs_diff_eq(*expected, 1e-5),
+                "{points:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn cuts_corners_of_line_strips() {
+        let corner = [Vec3::ZERO, Vec3::X * 2., Vec3::new(2., 2., 0.)];
+
+        let chamfered = cut_corners(corner.into_iter(), 0.5, false);
+        assert_points_eq(
+            &chamfered,
+            &[
+                Vec3::ZERO,
+                Vec3::new(1.5, 0., 0.),
+                Vec3::new(2., 0.5, 0.),
+                Vec3::new(2., 2., 0.),
+            ],
+        );
+
+        // A right angle sweeps a quarter of a circle's segments.
+        let rounded = cut_corners(corner.into_iter(), 0.5, true);
+        assert_eq!(rounded.len(), 2 + DEFAULT_CIRCLE_SEGMENTS / 4 + 1);
+        let arc = &rounded[1..rounded.len() - 1];
+        assert_points_eq(
+            &[
+                rounded[0],
+                arc[0],
+                arc[arc.len() - 1],
+                rounded[rounded.len() - 1],
+            ],
+            &chamfered,
+        );
+        let center = Vec3::new(1.5, 0.5, 0.);
+        assert!(arc
+            .iter()
+            .all(|point| (point.distance(center) - 0.5).abs() < 1e-5));
+    }
+
+    #[test]
+    fn cuts_at_most_half_of_each_segment() {
+        let corner = [Vec3::ZERO, Vec3::X, Vec3::new(1., 1., 0.)];
+        assert_points_eq(
+            &cut_corners(corner.into_iter(), 5., false),
+            &[
+                Vec3::ZERO,
+                Vec3::new(0.5, 0., 0.),
+                Vec3::new(1., 0.5, 0.),
+                Vec3::new(1., 1., 0.),
+            ],
+        );
+    }
+
+    #[test]
+    fn keeps_strips_without_corners_to_cut() {
+        for rounded in [false, true] {
+            let collinear = [Vec3::ZERO, Vec3::X, Vec3::X * 2.];
+            assert_eq!(cut_corners(collinear.into_iter(), 0.5, rounded), collinear);
+
+            let two_points = [Vec3::ZERO, Vec3::X];
+            assert_eq!(
+                cut_corners(two_points.into_iter(), 0.5, rounded),
+                two_points
+            );
+        }
+    }
+
+    #[test]
+    fn skips_non_finite_positions() {
+        fn draw(mut gizmos: Gizmos) {
+            for position in [Vec3::NAN, Vec3::new(0., f32::INFINITY, 0.)] {
+                gizmos.line(Vec3::ZERO, position, Color::RED);
+                gizmos.line_gradient(position, Vec3::ZERO, Color::RED, Color::BLUE);
+                gizmos.ray(position, Vec3::X, Color::RED);
+                gizmos.linestrip([Vec3::ZERO, position, Vec3::X], Color::RED);
+                gizmos.linestrip_gradient([(Vec3::ZERO, Color::RED), (position, Color::BLUE)]);
+                gizmos.arrow(position, Vec3::X, Color::RED);
+                gizmos.arrow(Vec3::ZERO, position, Color::RED);
+                gizmos.point_cloud(&[Vec3::ZERO, position], Color::RED);
+                gizmos.cuboid(Transform::from_translation(position), Color::RED);
+                gizmos.corner_brackets(Transform::from_translation(position), 0.1, Color::RED);
+                gizmos.circle(position, Vec3::Z, 1., Color::RED);
+                gizmos.circle_2d(position.truncate(), 1., Color::RED);
+                gizmos.billboard_circle(position, 1., Color::RED);
+                gizmos.arc_2d(position.truncate(), 0., 1., 1., Color::RED);
+                gizmos.sphere(position, Quat::IDENTITY, 1., Color::RED);
+                gizmos.rect(position, Quat::IDENTITY, Vec2::ONE, Color::RED);
+                gizmos.rect_2d(position.truncate(), 0., Vec2::ONE, Color::RED);
+                gizmos.corner_brackets_2d(position.truncate(), 0., Vec2::ONE, 0.1, Color::RED);
+                gizmos.grid(position, Quat::IDENTITY, UVec2::ONE, Vec2::ONE, Color::RED);
+            }
+        }
+        assert_eq!(count_drawn(draw), 0);
+
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin).add_systems(Update, draw);
+        app.update();
+        let storage = app.world.resource::<GizmoStorage>();
+        assert!(storage.list_positions.is_empty());
+        assert!(storage.strip_positions.is_empty());
+    }
+
+    #[test]
+    fn keeps_strips_apart_from_skipped_strips() {
+        fn draw(mut gizmos: Gizmos) {
+            gizmos.linestrip([Vec3::ZERO, Vec3::X], Color::RED);
+            gizmos.linestrip([Vec3::Y, Vec3::NAN, Vec3::Z], Color::RED);
+            gizmos.linestrip_gradient([(Vec3::Y, Color::RED), (Vec3::INFINITY, Color::BLUE)]);
+            gizmos.linestrip([Vec3::Y, Vec3::Z], Color::BLUE);
+        }
+
+        if DISABLED {
+            return;
+        }
+        // Without capturing the shapes, strips are only checked once they are added to the buffer.
+        let mut app = App::new();
+        app.init_resource::<GizmoConfig>()
+            .init_resource::<GizmoStorage>()
+            .add_systems(Update, draw);
+        app.update();
+
+        let storage = app.world.resource::<GizmoStorage>();
+        let strips: Vec<_> = storage
+            .strip_positions
+            .split(|position| position[0].is_nan())
+            .collect();
+        assert_eq!(
+            strips,
+            [
+                &[[0., 0., 0.], [1., 0., 0.]][..],
+                &[[0., 1., 0.], [0., 0., 1.]],
+                &[]
+            ]
+        );
+        assert_eq!(storage.strip_colors.len(), storage.strip_positions.len());
+    }
+
+    #[test]
+    fn skips_non_finite_radii() {
+        fn draw(mut gizmos: Gizmos) {
+            for radius in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+                gizmos.circle(Vec3::ZERO, Vec3::Z, radius, Color::RED);
+                gizmos
+                    .circle(Vec3::ZERO, Vec3::Z, radius, Color::RED)
+                    .segments(8);
+                gizmos.circle_2d(Vec2::ZERO, radius, Color::RED);
+                gizmos.billboard_circle(Vec3::ZERO, radius, Color::RED);
+                gizmos.arc_2d(Vec2::ZERO, 0., 1., radius, Color::RED);
+                gizmos.sphere(Vec3::ZERO, Quat::IDENTITY, radius, Color::RED);
+            }
+        }
+        assert_eq!(count_drawn(draw), 0);
+    }
+
+    #[test]
+    fn skips_non_finite_sizes() {
+        fn draw(mut gizmos: Gizmos) {
+            for size in [Vec2::NAN, Vec2::new(1., f32::INFINITY)] {
+                gizmos.rect(Vec3::ZERO, Quat::IDENTITY, size, Color::RED);
+                gizmos.rect_2d(Vec2::ZERO, 0., size, Color::RED);
+                gizmos.corner_brackets_2d(Vec2::ZERO, 0., size, 0.1, Color::RED);
+                gizmos.grid_2d(Vec2::ZERO, 0., UVec2::ONE, size, Color::RED);
+            }
+        }
+        assert_eq!(count_drawn(draw), 0);
+    }
+
+    #[test]
+    fn draws_finite_shapes() {
+        fn draw(mut gizmos: Gizmos) {
+            gizmos.circle(Vec3::ZERO, Vec3::Z, 1., Color::RED);
+            gizmos.rect_2d(Vec2::ZERO, 0., Vec2::ONE, Color::RED);
+        }
+        assert_eq!(count_drawn(draw), 2);
+    }
+
+    #[test]
+    fn captures_corner_brackets_as_one_shape() {
+        fn draw_brackets(mut gizmos: Gizmos) {
+            gizmos.corner_brackets(Transform::from_scale(Vec3::splat(2.)), 0.25, Color::RED);
+            gizmos.corner_brackets_2d(Vec2::X, 0., Vec2::new(4., 2.), 0.5, Color::RED);
+        }
+
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .add_systems(Update, draw_brackets);
+        app.update();
+
+        let captured = app.world.resource::<CapturedGizmos>();
+        let shapes: Vec<_> = captured.of_system("draw_brackets").collect();
+        assert_eq!(
+            shapes,
+            [
+                &CapturedShape::CornerBrackets {
+                    transform: Affine3A::from_scale(Vec3::splat(2.)),
+                    length: 0.25,
+                    color: Color::RED,
+                },
+                &CapturedShape::CornerBrackets {
+                    transform: Affine3A::from_scale_rotation_translation(
+                        Vec3::new(4., 2., 0.),
+                        Quat::IDENTITY,
+                        Vec3::X,
+                    ),
+                    length: 0.5,
+                    color: Color::RED,
+                },
+            ]
+        );
+    }
+
+    /// The shapes drawn by one app update, with [`ParallelGizmos::draw`] called for each key in order.
+    fn draw_parallel(keys: Vec<u32>) -> Vec<CapturedShape> {
+        #[derive(Resource)]
+        struct Keys(Vec<u32>);
+
+        fn draw(keys: Res<Keys>, gizmos: ParallelGizmos) {
+            for &key in &keys.0 {
+                gizmos.draw(key, |gizmos| {
+                    gizmos.line(Vec3::ZERO, Vec3::splat(key as f32), Color::RED);
+                });
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .insert_resource(Keys(keys))
+            .add_systems(Update, draw);
+        app.update();
+
+        let captured = app.world.resource::<CapturedGizmos>();
+        captured.iter().map(|gizmo| gizmo.shape.clone()).collect()
+    }
+
+    #[test]
+    fn parallel_gizmos_are_merged_by_key() {
+        let shapes = draw_parallel((0..16).collect());
+        assert_eq!(shapes.len(), 16);
+        assert_eq!(shapes, draw_parallel((0..16).rev().collect()));
+    }
+}
@@ -0,0 +1,282 @@
+//! Interpolating the colors of gradients in perceptual color spaces.
+//!
+//! Includes the [`GradientColorSpace`] set in [`GizmoConfig::gradient_color_space`](crate::GizmoConfig::gradient_color_space).
+
+use crate::gizmos::GizmoStorage;
+use bevy_math::{Mat3, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use std::{f32::consts::TAU, mem};
+
+/// The color space the colors of gradients are interpolated in,
+/// such as the lines drawn by [`Gizmos::line_gradient`](crate::gizmos::Gizmos::line_gradient).
+///
+/// The GPU interpolates colors in linear RGB, which can produce muddy or dark midpoints
+/// between some hues, such as red to green going through brown.
+/// The other color spaces are perceptually uniform: segments whose ends have different colors
+/// are split into shorter segments colored in that space, which costs more vertices.
+///
+/// Retained [`LineGizmo`](crate::LineGizmo)s are always interpolated in linear RGB.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// fn setup(mut config: ResMut<GizmoConfig>) {
+///     config.gradient_color_space = GradientColorSpace::Oklch;
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Default)]
+pub enum GradientColorSpace {
+    /// Interpolate the red, green and blue channels linearly, as the GPU does.
+    #[default]
+    LinearRgb,
+    /// Interpolate in the Oklab color space, keeping the lightness even along the gradient.
+    Oklab,
+    /// Interpolate in the cylindrical form of Oklab, going around the hue circle the short way,
+    /// which also keeps the colors saturated along the gradient.
+    Oklch,
+}
+
+/// The distance in Oklab between the colors at the ends of a split segment.
+const MAX_STEP: f32 = 0.05;
+/// The maximum number of segments a gradient segment is split into.
+const MAX_SPLITS: usize = 16;
+
+/// Buffers reused every frame by [`interpolate_gradients`].
+#[derive(Default)]
+pub(crate) struct GradientScratch {
+    positions: Vec<[f32; 3]>,
+    colors: Vec<[f32; 4]>,
+}
+
+/// Splits the segments of the `storage` whose ends have different colors into segments
+/// colored in the `color_space`.
+pub(crate) fn interpolate_gradients(
+    storage: &mut GizmoStorage,
+    color_space: GradientColorSpace,
+    scratch: &mut GradientScratch,
+) {
+    if color_space == GradientColorSpace::LinearRgb {
+        return;
+    }
+    let GradientScratch { positions, colors } = scratch;
+
+    for (segment, segment_colors) in storage
+        .list_positions
+        .chunks_exact(2)
+        .zip(storage.list_colors.chunks_exact(2))
+    {
+        let splits = split_count(segment_colors[0], segment_colors[1]);
+        if splits == 1 {
+            positions.extend_from_slice(segment);
+            colors.extend_from_slice(segment_colors);
+            continue;
+        }
+        let point = |i: usize| {
+            gradient_point(
+                segment,
+                segment_colors,
+                i as f32 / splits as f32,
+                color_space,
+            )
+        };
+        for i in 0..splits {
+            let [(start, start_color), (end, end_color)] = [point(i), point(i + 1)];
+            positions.extend([start, end]);
+            colors.extend([start_color, end_color]);
+        }
+    }
+    mem::swap(&mut storage.list_positions, positions);
+    mem::swap(&mut storage.list_colors, colors);
+    positions.clear();
+    colors.clear();
+
+    let strip_positions = &storage.strip_positions;
+    let strip_colors = &storage.strip_colors;
+    for i in 0..strip_positions.len() {
+        positions.push(strip_positions[i]);
+        colors.push(strip_colors[i]);
+        // Strips are separated by a NaN position.
+        let Some(&next) = strip_positions.get(i + 1) else {
+            continue;
+        };
+        if strip_positions[i][0].is_nan() || next[0].is_nan() {
+            continue;
+        }
+        let segment = [strip_positions[i], next];
+        let segment_colors = [strip_colors[i], strip_colors[i + 1]];
+        let splits = split_count(segment_colors[0], segment_colors[1]);
+        for split in 1..splits {
+            let t = split as f32 / splits as f32;
+            let (position, color) = gradient_point(&segment, &segment_colors, t, color_space);
+            positions.push(position);
+            colors.push(color);
+        }
+    }
+    mem::swap(&mut storage.strip_positions, positions);
+    mem::swap(&mut storage.strip_colors, colors);
+    positions.clear();
+    colors.clear();
+}
+
+/// The number of segments a segment from `start` to `end` colors is split into.
+fn split_count(start: [f32; 4], end: [f32; 4]) -> usize {
+    if start == end {
+        return 1;
+    }
+    let distance = linear_to_oklab(start).distance(linear_to_oklab(end));
+    ((distance / MAX_STEP).ceil() as usize).clamp(1, MAX_SPLITS)
+}
+
+/// The position and color at `t` along the `segment`.
+fn gradient_point(
+    segment: &[[f32; 3]],
+    colors: &[[f32; 4]],
+    t: f32,
+    color_space: GradientColorSpace,
+) -> ([f32; 3], [f32; 4]) {
+    let position = Vec3::from(segment[0]).lerp(Vec3::from(segment[1]), t);
+    let [start, end] = [colors[0], colors[1]];
+    let alpha = start[3] + (end[3] - start[3]) * t;
+    let [start, end] = [linear_to_oklab(start), linear_to_oklab(end)];
+    let lab = match color_space {
+        GradientColorSpace::LinearRgb | GradientColorSpace::Oklab => start.lerp(end, t),
+        GradientColorSpace::Oklch => {
+            let [start, end] = [start, end].map(oklab_to_oklch);
+            let mut hue_delta = (end.z - start.z).rem_euclid(TAU);
+            if hue_delta > TAU / 2. {
+                hue_delta -= TAU;
+            }
+            // Grays have no hue, so the hue of the other end is kept along the way.
+            let start_hue = if start.y < f32::EPSILON {
+                end.z
+            } else {
+                start.z
+            };
+            let hue_delta = if start.y < f32::EPSILON || end.y < f32::EPSILON {
+                0.
+            } else {
+                hue_delta
+            };
+            let lightness = start.x + (end.x - start.x) * t;
+            let chroma = start.y + (end.y - start.y) * t;
+            let hue = start_hue + hue_delta * t;
+            Vec3::new(lightness, chroma * hue.cos(), chroma * hue.sin())
+        }
+    };
+    // Hues between the ends may be out of the sRGB gamut, such as with Oklch from red to green.
+    let color = oklab_to_linear(lab).max(Vec3::ZERO);
+    (position.to_array(), color.extend(alpha).to_array())
+}
+
+/// Linear sRGB to the LMS cone responses of Oklab, by Björn Ottosson.
+///
+/// The matrices are given by columns.
+const LINEAR_TO_LMS: Mat3 = Mat3::from_cols(
+    Vec3::new(0.412_221_46, 0.211_903_5, 0.088_302_46),
+    Vec3::new(0.536_332_55, 0.680_699_5, 0.281_718_85),
+    Vec3::new(0.051_445_995, 0.107_396_96, 0.629_978_7),
+);
+
+/// Nonlinear LMS to Oklab.
+const LMS_TO_OKLAB: Mat3 = Mat3::from_cols(
+    Vec3::new(0.210_454_26, 1.977_998_5, 0.025_904_037),
+    Vec3::new(0.793_617_8, -2.428_592_2, 0.782_771_77),
+    Vec3::new(-0.004_072_047, 0.450_593_7, -0.808_675_77),
+);
+
+/// Oklab to nonlinear LMS.
+const OKLAB_TO_LMS: Mat3 = Mat3::from_cols(
+    Vec3::new(1., 1., 1.),
+    Vec3::new(0.396_337_78, -0.105_561_346, -0.089_484_18),
+    Vec3::new(0.215_803_76, -0.063_854_17, -1.291_485_5),
+);
+
+/// LMS to linear sRGB.
+const LMS_TO_LINEAR: Mat3 = Mat3::from_cols(
+    Vec3::new(4.076_741_7, -1.268_438, -0.004_196_086_3),
+    Vec3::new(-3.307_711_6, 2.609_757_4, -0.703_418_6),
+    Vec3::new(0.230_969_94, -0.341_319_4, 1.707_614_7),
+);
+
+fn linear_to_oklab([red, green, blue, _]: [f32; 4]) -> Vec3 {
+    let lms = LINEAR_TO_LMS * Vec3::new(red, green, blue);
+    LMS_TO_OKLAB * Vec3::new(lms.x.cbrt(), lms.y.cbrt(), lms.z.cbrt())
+}
+
+fn oklab_to_linear(lab: Vec3) -> Vec3 {
+    let lms = OKLAB_TO_LMS * lab;
+    LMS_TO_LINEAR * (lms * lms * lms)
+}
+
+/// The lightness, chroma and hue in radians of an Oklab color.
+fn oklab_to_oklch(lab: Vec3) -> Vec3 {
+    Vec3::new(lab.x, lab.y.hypot(lab.z), lab.z.atan2(lab.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{Vec2, Vec3};
+
+    use super::{
+        gradient_point, linear_to_oklab, oklab_to_linear, oklab_to_oklch, GradientColorSpace,
+    };
+
+    const RED: [f32; 4] = [1., 0., 0., 1.];
+    const BLUE: [f32; 4] = [0., 0., 1., 1.];
+
+    /// The colors at the ends and middle of a gradient from `start` to `end`.
+    fn gradient(start: [f32; 4], end: [f32; 4], color_space: GradientColorSpace) -> [Vec3; 3] {
+        let segment = [[0.; 3], [1., 0., 0.]];
+        [0., 0.5, 1.].map(|t| {
+            let (_, color) = gradient_point(&segment, &[start, end], t, color_space);
+            Vec3::from_slice(&color)
+        })
+    }
+
+    #[test]
+    fn converts_to_oklab_and_back() {
+        // The well-known Oklab coordinates of the sRGB primaries and white.
+        for (linear, oklab) in [
+            (RED, Vec3::new(0.627_955, 0.224_863, 0.125_846)),
+            (BLUE, Vec3::new(0.452_014, -0.032_457, -0.311_528)),
+            ([1.; 4], Vec3::new(1., 0., 0.)),
+        ] {
+            assert!(linear_to_oklab(linear).abs_diff_eq(oklab, 1e-4));
+        }
+
+        for linear in [RED, BLUE, [0.2, 0.5, 0.8, 1.], [0.; 4], [1.; 4]] {
+            let round_trip = oklab_to_linear(linear_to_oklab(linear));
+            assert!(round_trip.abs_diff_eq(Vec3::from_slice(&linear), 1e-4));
+        }
+    }
+
+    #[test]
+    fn interpolates_in_oklab() {
+        let [start, middle, end] = gradient(RED, BLUE, GradientColorSpace::Oklab);
+        assert!(start.abs_diff_eq(Vec3::X, 1e-4));
+        assert!(end.abs_diff_eq(Vec3::Z, 1e-4));
+
+        let expected = Vec3::new(0.539_985, 0.096_203, -0.092_841);
+        assert!(linear_to_oklab(middle.extend(1.).to_array()).abs_diff_eq(expected, 1e-4));
+    }
+
+    #[test]
+    fn interpolates_oklch_hues_the_short_way() {
+        // Hues at 170° and 190°, with a gradient crossing 180° rather than going through 0°.
+        let [start, end] = [170_f32, -170.].map(|hue| {
+            let (sin, cos) = hue.to_radians().sin_cos();
+            let lab = Vec3::new(0.6, 0.05 * cos, 0.05 * sin);
+            oklab_to_linear(lab).extend(1.).to_array()
+        });
+        let [_, middle, _] = gradient(start, end, GradientColorSpace::Oklch);
+
+        let lch = oklab_to_oklch(linear_to_oklab(middle.extend(1.).to_array()));
+        assert!((lch.x - 0.6).abs() < 1e-3);
+        assert!((lch.y - 0.05).abs() < 1e-3);
+        let hue = Vec2::from_angle(lch.z);
+        assert!(hue.abs_diff_eq(Vec2::NEG_X, 1e-2));
+    }
+}
@@ -0,0 +1,162 @@
+//! A frame time graph overlay drawn with gizmos.
+//!
+//! Includes the `GizmoGraphOverlayPlugin` drawing it, behind the `graph_overlay` feature.
+
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+
+#[cfg(feature = "graph_overlay")]
+use crate::{gizmos::Gizmos, GizmoConfig};
+#[cfg(feature = "graph_overlay")]
+use bevy_app::{App, Plugin, PostUpdate};
+#[cfg(feature = "graph_overlay")]
+use bevy_diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+#[cfg(feature = "graph_overlay")]
+use bevy_ecs::{
+    schedule::IntoSystemConfigs,
+    system::{Local, Query, Res},
+};
+#[cfg(feature = "graph_overlay")]
+use bevy_render::camera::Camera;
+#[cfg(feature = "graph_overlay")]
+use bevy_transform::{components::GlobalTransform, TransformSystem};
+#[cfg(feature = "graph_overlay")]
+use std::collections::VecDeque;
+
+/// Configuration for the frame time graph drawn by the `GizmoGraphOverlayPlugin`.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct GraphOverlayConfig {
+    /// The position of the top left corner of the graph,
+    /// in logical pixels from the top left corner of the viewport.
+    ///
+    /// Defaults to `Vec2::new(10.0, 10.0)`.
+    pub position: Vec2,
+    /// The size of the graph in logical pixels.
+    ///
+    /// Defaults to `Vec2::new(240.0, 80.0)`.
+    pub size: Vec2,
+    /// The number of frames the graph spans.
+    ///
+    /// Defaults to `240`.
+    pub history_length: usize,
+    /// The frame time in milliseconds at the top of the graph.
+    ///
+    /// Longer frames are clamped to the top.
+    ///
+    /// Defaults to `50.0`.
+    pub max_frame_time: f32,
+    /// The frame times in milliseconds marked with horizontal lines.
+    ///
+    /// Defaults to the frame times at 60 and 30 frames per second.
+    pub reference_frame_times: Vec<f32>,
+    /// The color of the frame times.
+    ///
+    /// Defaults to `Color::GREEN`.
+    pub color: Color,
+    /// The color of the frame, the reference lines and the label.
+    ///
+    /// Defaults to `Color::GRAY`.
+    pub frame_color: Color,
+}
+
+impl Default for GraphOverlayConfig {
+    fn default() -> Self {
+        Self {
+            position: Vec2::new(10., 10.),
+            size: Vec2::new(240., 80.),
+            history_length: 240,
+            max_frame_time: 50.,
+            reference_frame_times: vec![1000. / 60., 1000. / 30.],
+            color: Color::GREEN,
+            frame_color: Color::GRAY,
+        }
+    }
+}
+
+/// A [`Plugin`] that draws a scrolling graph of the frame times measured by the
+/// [`FrameTimeDiagnosticsPlugin`] in the viewport of the active camera with the highest
+/// [`Camera::order`], configured by [`GizmoConfig::graph_overlay`].
+///
+/// It adds the [`FrameTimeDiagnosticsPlugin`] if it isn't added yet,
+/// so it must be added after it if you add it too.
+///
+/// Unlike most other gizmo plugins, it isn't added by the [`GizmoPlugin`](crate::GizmoPlugin).
+#[cfg(feature = "graph_overlay")]
+pub struct GizmoGraphOverlayPlugin;
+
+#[cfg(feature = "graph_overlay")]
+impl Plugin for GizmoGraphOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        app.add_systems(
+            PostUpdate,
+            draw_graph_overlay.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// The height of the label of the graph in logical pixels.
+#[cfg(feature = "graph_overlay")]
+const LABEL_SIZE: f32 = 10.;
+
+#[cfg(feature = "graph_overlay")]
+pub(crate) fn draw_graph_overlay(
+    diagnostics: Res<DiagnosticsStore>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    config: Res<GizmoConfig>,
+    mut frame_times: Local<VecDeque<f32>>,
+    mut gizmos: Gizmos,
+) {
+    let graph_config = &config.graph_overlay;
+    if let Some(frame_time) = diagnostics
+        .get(FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|diagnostic| diagnostic.value())
+    {
+        frame_times.push_back(frame_time as f32);
+    }
+    while frame_times.len() > graph_config.history_length {
+        frame_times.pop_front();
+    }
+
+    let Some((camera, camera_transform)) = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+    else {
+        return;
+    };
+    let mut screen = gizmos.screen(camera, camera_transform);
+
+    let (min, size) = (graph_config.position, graph_config.size);
+    let bottom = min.y + size.y;
+    let height = |frame_time: f32| {
+        bottom - (frame_time / graph_config.max_frame_time).clamp(0., 1.) * size.y
+    };
+    screen.rect(min + size / 2., size, graph_config.frame_color);
+    for &frame_time in &graph_config.reference_frame_times {
+        if frame_time < graph_config.max_frame_time {
+            let y = height(frame_time);
+            let (start, end) = (Vec2::new(min.x, y), Vec2::new(min.x + size.x, y));
+            screen.line(start, end, graph_config.frame_color);
+        }
+    }
+
+    screen.graph(
+        min,
+        size,
+        frame_times.iter().copied(),
+        graph_config.history_length,
+        0.0..=graph_config.max_frame_time,
+        graph_config.color,
+    );
+
+    if let Some(&frame_time) = frame_times.back() {
+        let label = format!("{frame_time:.1} ms {:.0} FPS", 1000. / frame_time);
+        let position = Vec2::new(min.x + size.x / 2., min.y + LABEL_SIZE);
+        screen.text(position, &label, LABEL_SIZE, graph_config.frame_color);
+    }
+}
@@ -0,0 +1,292 @@
+//! Additional [`Gizmos`] Functions -- Grids
+//!
+//! Includes the implementation of [`Gizmos::grid`], [`Gizmos::grid_on_plane`] and [`Gizmos::grid_2d`],
+//! and assorted support items.
+
+use crate::{gizmos::skip_invalid, prelude::Gizmos, testing::CapturedShape, text::text_size};
+use bevy_math::{Quat, UVec2, Vec2, Vec3};
+use bevy_render::color::Color;
+
+/// The coordinates labeled on a grid, see [`GridBuilder::labels`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridLabels {
+    /// Label the lines with their distance from the center of the grid along each axis,
+    /// only the major lines if they are set.
+    Lines,
+    /// Label the cells with their column and row, from `0,0` at the bottom left cell.
+    Cells,
+}
+
+impl<'s> Gizmos<'s> {
+    /// Draw a grid in 3D of `cell_count` cells of `spacing` size, centered on `position`
+    /// and lying in the local XY plane of `rotation`.
+    ///
+    /// This should be called for each frame the grid needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.grid(
+    ///         Vec3::ZERO,
+    ///         Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+    ///         UVec2::splat(10),
+    ///         Vec2::ONE,
+    ///         Color::GRAY,
+    ///     );
+    ///
+    ///     // Every fifth line can be drawn in a different color.
+    ///     gizmos
+    ///         .grid(Vec3::ZERO, Quat::IDENTITY, UVec2::splat(20), Vec2::ONE, Color::GRAY)
+    ///         .major_lines(5, Color::WHITE);
+    ///
+    ///     // Cells can be labeled with their coordinates.
+    ///     gizmos
+    ///         .grid(Vec3::ZERO, Quat::IDENTITY, UVec2::splat(8), Vec2::ONE, Color::GRAY)
+    ///         .labels(GridLabels::Cells);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn grid(
+        &mut self,
+        position: impl Into<Vec3>,
+        rotation: Quat,
+        cell_count: UVec2,
+        spacing: Vec2,
+        color: Color,
+    ) -> GridBuilder<'_, 's> {
+        GridBuilder {
+            gizmos: self,
+            position: position.into(),
+            rotation,
+            cell_count,
+            spacing,
+            color,
+            major_lines: None,
+            labels: None,
+            label_size: None,
+            is_2d: false,
+        }
+    }
+
+    /// Draw a grid in 3D lying in the plane through `point` facing `normal`, with cells of
+    /// `spacing` size covering an `extent` centered on `point`, rounded up to whole cells,
+    /// such as to show the work plane objects are placed on.
+    ///
+    /// `normal` doesn't need to be normalized, and nothing is drawn if it is zero. The lines of the
+    /// grid are rotated from the XY plane onto the plane the shortest way, so they run along the X
+    /// and Z axes on the ground. See [`Gizmos::grid`] to set their rotation instead.
+    ///
+    /// This should be called for each frame the grid needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     // A slanted work plane 10 by 10 units wide with a line every unit.
+    ///     let normal = Vec3::new(1., 1., 0.);
+    ///     gizmos
+    ///         .grid_on_plane(Vec3::Y, normal, Vec2::splat(10.), Vec2::ONE, Color::GRAY)
+    ///         .major_lines(5, Color::WHITE);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn grid_on_plane(
+        &mut self,
+        point: impl Into<Vec3>,
+        normal: impl Into<Vec3>,
+        extent: Vec2,
+        spacing: Vec2,
+        color: Color,
+    ) -> GridBuilder<'_, 's> {
+        let (rotation, cell_count) = match normal.into().try_normalize() {
+            Some(normal) => (
+                Quat::from_rotation_arc(Vec3::Z, normal),
+                (extent / spacing).abs().ceil().as_uvec2(),
+            ),
+            None => (Quat::IDENTITY, UVec2::ZERO),
+        };
+        self.grid(point, rotation, cell_count, spacing, color)
+    }
+
+    /// Draw a grid in 2D of `cell_count` cells of `spacing` size, centered on `position`
+    /// and rotated counter-clockwise by `rotation` radians.
+    ///
+    /// This should be called for each frame the grid needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.grid_2d(Vec2::ZERO, 0., UVec2::new(16, 9), Vec2::splat(32.), Color::GRAY);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn grid_2d(
+        &mut self,
+        position: impl Into<Vec2>,
+        rotation: f32,
+        cell_count: UVec2,
+        spacing: Vec2,
+        color: Color,
+    ) -> GridBuilder<'_, 's> {
+        let mut grid = self.grid(
+            position.into().extend(0.),
+            Quat::from_rotation_z(rotation),
+            cell_count,
+            spacing,
+            color,
+        );
+        grid.is_2d = true;
+        grid
+    }
+}
+
+/// A builder returned by [`Gizmos::grid`], [`Gizmos::grid_on_plane`] and [`Gizmos::grid_2d`].
+pub struct GridBuilder<'a, 's> {
+    gizmos: &'a mut Gizmos<'s>,
+    position: Vec3,
+    rotation: Quat,
+    cell_count: UVec2,
+    spacing: Vec2,
+    color: Color,
+    major_lines: Option<(u32, Color)>,
+    labels: Option<GridLabels>,
+    label_size: Option<f32>,
+    /// Whether the grid was drawn with [`Gizmos::grid_2d`].
+    is_2d: bool,
+}
+
+impl GridBuilder<'_, '_> {
+    /// Draw every `interval`th line in `color`, counting from the first line in each direction.
+    pub fn major_lines(mut self, interval: u32, color: Color) -> Self {
+        self.major_lines = Some((interval, color));
+        self
+    }
+
+    /// Label the lines or cells of the grid with their coordinates,
+    /// in the color of the major lines if they are set.
+    ///
+    /// Labels are skipped at regular intervals where they would overlap.
+    pub fn labels(mut self, labels: GridLabels) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Set the height of the labels' capital letters.
+    ///
+    /// Defaults to a third of the smallest side of the cells.
+    pub fn label_size(mut self, size: f32) -> Self {
+        self.label_size = Some(size);
+        self
+    }
+}
+
+impl Drop for GridBuilder<'_, '_> {
+    fn drop(&mut self) {
+        skip_invalid!(
+            !self.spacing.is_finite() || !self.position.is_finite() || !self.rotation.is_finite(),
+            "Skipped drawing a grid with a non-finite spacing, position or rotation, got a spacing of {} at {} and the rotation {}",
+            self.spacing,
+            self.position,
+            self.rotation
+        );
+        if self.cell_count.cmpeq(UVec2::ZERO).any() || self.spacing.cmpeq(Vec2::ZERO).any() {
+            return;
+        }
+        let shape = || CapturedShape::Grid {
+            position: self.position,
+            rotation: self.rotation,
+            cell_count: self.cell_count,
+            spacing: self.spacing,
+            color: self.color,
+        };
+        self.gizmos.draw_2d(self.is_2d, |gizmos| {
+            gizmos.capture(shape, |gizmos| {
+                let size = self.cell_count.as_vec2() * self.spacing;
+                let min = -size / 2.;
+                let to_world = |vec2: Vec2| self.position + self.rotation * vec2.extend(0.);
+                let line_color = |i: u32| match self.major_lines {
+                    Some((interval, color)) if interval > 0 && i % interval == 0 => color,
+                    _ => self.color,
+                };
+
+                for i in 0..=self.cell_count.x {
+                    let x = min.x + i as f32 * self.spacing.x;
+                    let (start, end) = (Vec2::new(x, min.y), Vec2::new(x, min.y + size.y));
+                    gizmos.line(to_world(start), to_world(end), line_color(i));
+                }
+                for i in 0..=self.cell_count.y {
+                    let y = min.y + i as f32 * self.spacing.y;
+                    let (start, end) = (Vec2::new(min.x, y), Vec2::new(min.x + size.x, y));
+                    gizmos.line(to_world(start), to_world(end), line_color(i));
+                }
+
+                let Some(labels) = self.labels else {
+                    return;
+                };
+                let label_size = self.label_size.unwrap_or(self.spacing.min_element() / 3.);
+                let label_color = self.major_lines.map_or(self.color, |(_, color)| color);
+                let mut label = |position: Vec2, text: &str| {
+                    let position = to_world(position);
+                    gizmos.text(position, self.rotation, text, label_size, label_color);
+                };
+                // The number of lines or cells from one label to the next, a multiple of `interval`,
+                // for labels `extent` glyphs high or wide to fit in between.
+                let label_step = |extent: f32, spacing: f32, interval: u32| {
+                    let fit = ((extent + 0.5) * label_size / spacing.abs()).ceil().max(1.) as u32;
+                    fit.div_ceil(interval) * interval
+                };
+
+                match labels {
+                    GridLabels::Lines => {
+                        let interval = self.major_lines.map_or(1, |(interval, _)| interval.max(1));
+                        let widest =
+                            text_size(&format_coordinate(-size.abs().max_element() / 2.)).x;
+                        let step_x = label_step(widest, self.spacing.x, interval);
+                        // Labels of rows are stacked, so they only need to fit their height.
+                        let step_y = label_step(1., self.spacing.y, interval);
+                        for i in (0..=self.cell_count.x).step_by(step_x as usize) {
+                            let x = min.x + i as f32 * self.spacing.x;
+                            let below = Vec2::new(x, min.y - label_size * 1.5);
+                            label(below, &format_coordinate(x));
+                        }
+                        for i in (0..=self.cell_count.y).step_by(step_y as usize) {
+                            let y = min.y + i as f32 * self.spacing.y;
+                            let text = format_coordinate(y);
+                            let width = text_size(&text).x * label_size;
+                            label(Vec2::new(min.x - label_size - width / 2., y), &text);
+                        }
+                    }
+                    GridLabels::Cells => {
+                        let widest =
+                            text_size(&format!("{},{}", self.cell_count.x, self.cell_count.y)).x;
+                        let step_x = label_step(widest, self.spacing.x, 1);
+                        let step_y = label_step(1., self.spacing.y, 1);
+                        for y in (0..self.cell_count.y).step_by(step_y as usize) {
+                            for x in (0..self.cell_count.x).step_by(step_x as usize) {
+                                let center =
+                                    min + (Vec2::new(x as f32, y as f32) + 0.5) * self.spacing;
+                                label(center, &format!("{x},{y}"));
+                            }
+                        }
+                    }
+                }
+            });
+        });
+    }
+}
+
+/// Formats a coordinate with up to two decimals.
+fn format_coordinate(value: f32) -> String {
+    // Avoid labeling the center `-0`.
+    let value = if value.abs() < 0.005 { 0. } else { value };
+    let text = format!("{value:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_owned()
+}
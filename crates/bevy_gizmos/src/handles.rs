@@ -0,0 +1,724 @@
+//! Interactive handles to manipulate the [`Transform`] of entities with the mouse.
+//!
+//! Includes the [`TransformHandle`] component, the [`TransformHandles`] resource describing the
+//! current interaction and the [`TransformHandlePlugin`] hit testing, dragging and drawing them.
+
+use crate::{gizmos::Gizmos, GizmoConfig};
+use bevy_app::{App, Plugin, PostUpdate, Update};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::With,
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res, ResMut, Resource},
+};
+use bevy_hierarchy::Parent;
+use bevy_input::{mouse::MouseButton, ButtonInput};
+use bevy_math::{primitives::Plane3d, Quat, Ray3d, Vec2, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{camera::Camera, color::Color};
+use bevy_transform::{
+    components::{GlobalTransform, Transform},
+    TransformSystem,
+};
+use bevy_window::{PrimaryWindow, Window};
+
+/// A [`Plugin`] that lets the [`TransformHandle`]s be dragged with the left mouse button
+/// and draws them, configured by [`GizmoConfig::transform_handles`].
+///
+/// Unlike most other gizmo plugins, it isn't added by the [`GizmoPlugin`](crate::GizmoPlugin),
+/// as it reacts to mouse input.
+///
+/// Handles are hit tested and drawn for the active camera with the highest [`Camera::order`],
+/// using the cursor of the primary window.
+pub struct TransformHandlePlugin;
+
+impl Plugin for TransformHandlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TransformHandles>()
+            .add_systems(Update, update_transform_handles)
+            .add_systems(
+                PostUpdate,
+                draw_transform_handles.after(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+/// Configuration for the [`TransformHandle`]s.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct TransformHandleConfig {
+    /// The length of the handles on screen, in logical pixels.
+    ///
+    /// Defaults to `100.0`.
+    pub size: f32,
+    /// The distance from the cursor to a handle on screen within which it is hovered,
+    /// in logical pixels.
+    ///
+    /// Defaults to `8.0`.
+    pub hit_distance: f32,
+    /// The color of the handles along or around the X axis.
+    ///
+    /// Defaults to `Color::RED`.
+    pub x_color: Color,
+    /// The color of the handles along or around the Y axis.
+    ///
+    /// Defaults to `Color::GREEN`.
+    pub y_color: Color,
+    /// The color of the handles along or around the Z axis.
+    ///
+    /// Defaults to `Color::BLUE`.
+    pub z_color: Color,
+    /// The color of the outer rotation ring facing the camera and of the uniform scale cube.
+    ///
+    /// Defaults to `Color::WHITE`.
+    pub screen_color: Color,
+    /// The color of the hovered or dragged handle.
+    ///
+    /// Defaults to `Color::YELLOW`.
+    pub hover_color: Color,
+    /// The angle in radians that rotations made with the rotation rings snap to.
+    ///
+    /// Rotations aren't snapped if `None`.
+    ///
+    /// Defaults to `None`.
+    pub rotation_snap: Option<f32>,
+}
+
+impl Default for TransformHandleConfig {
+    fn default() -> Self {
+        Self {
+            size: 100.,
+            hit_distance: 8.,
+            x_color: Color::RED,
+            y_color: Color::GREEN,
+            z_color: Color::BLUE,
+            screen_color: Color::WHITE,
+            hover_color: Color::YELLOW,
+            rotation_snap: None,
+        }
+    }
+}
+
+impl TransformHandleConfig {
+    fn part_color(&self, part: HandlePart) -> Color {
+        let axis = match part {
+            HandlePart::TranslateAxis(axis)
+            | HandlePart::TranslatePlane(axis)
+            | HandlePart::RotateAxis(axis)
+            | HandlePart::ScaleAxis(axis) => axis,
+            HandlePart::RotateScreen | HandlePart::ScaleUniform => return self.screen_color,
+        };
+        match axis {
+            HandleAxis::X => self.x_color,
+            HandleAxis::Y => self.y_color,
+            HandleAxis::Z => self.z_color,
+        }
+    }
+}
+
+/// Add this [`Component`] to an entity to manipulate its [`Transform`] with handles,
+/// when the [`TransformHandlePlugin`] is added.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct TransformHandle {
+    /// Which part of the transform the handles manipulate.
+    pub mode: TransformHandleMode,
+    /// Aligns the handles to the entity's rotation instead of the world axes when set to `true`.
+    ///
+    /// Scale handles are always aligned to the entity's rotation.
+    pub local: bool,
+}
+
+/// Which part of the [`Transform`] a [`TransformHandle`] manipulates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Default)]
+pub enum TransformHandleMode {
+    /// Move the entity along an axis with arrows, or along a plane with squares.
+    #[default]
+    Translate,
+    /// Rotate the entity around an axis with rings, or around the view direction
+    /// with an outer ring facing the camera.
+    Rotate,
+    /// Scale the entity along an axis with lines ending in cubes, or uniformly with a cube
+    /// at its center dragged sideways.
+    Scale,
+}
+
+/// An axis of the handles of a [`TransformHandle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum HandleAxis {
+    /// The X axis.
+    X,
+    /// The Y axis.
+    Y,
+    /// The Z axis.
+    Z,
+}
+
+impl HandleAxis {
+    const ALL: [HandleAxis; 3] = [HandleAxis::X, HandleAxis::Y, HandleAxis::Z];
+
+    /// The unit vector of the axis.
+    pub fn direction(self) -> Vec3 {
+        match self {
+            HandleAxis::X => Vec3::X,
+            HandleAxis::Y => Vec3::Y,
+            HandleAxis::Z => Vec3::Z,
+        }
+    }
+
+    /// The two other axes, in order.
+    fn others(self) -> (HandleAxis, HandleAxis) {
+        match self {
+            HandleAxis::X => (HandleAxis::Y, HandleAxis::Z),
+            HandleAxis::Y => (HandleAxis::Z, HandleAxis::X),
+            HandleAxis::Z => (HandleAxis::X, HandleAxis::Y),
+        }
+    }
+}
+
+/// A part of the handles of a [`TransformHandle`] that can be dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum HandlePart {
+    /// The arrow moving the entity along an axis.
+    TranslateAxis(HandleAxis),
+    /// The square moving the entity along the plane normal to an axis.
+    TranslatePlane(HandleAxis),
+    /// The ring rotating the entity around an axis.
+    RotateAxis(HandleAxis),
+    /// The outer ring facing the camera, rotating the entity around the view direction.
+    RotateScreen,
+    /// The line ending in a cube scaling the entity along an axis.
+    ScaleAxis(HandleAxis),
+    /// The cube at the center scaling the entity uniformly.
+    ScaleUniform,
+}
+
+/// A [`Resource`] describing the interaction with the [`TransformHandle`]s,
+/// for example to stop a camera controller from reacting to the mouse while a handle is dragged.
+#[derive(Resource, Default, Debug)]
+pub struct TransformHandles {
+    hovered: Option<(Entity, HandlePart)>,
+    drag: Option<Drag>,
+}
+
+impl TransformHandles {
+    /// The entity and the part of its handles under the cursor, if any.
+    pub fn hovered(&self) -> Option<(Entity, HandlePart)> {
+        self.hovered
+    }
+
+    /// The entity and the part of its handles being dragged, if any.
+    pub fn dragged(&self) -> Option<(Entity, HandlePart)> {
+        self.drag.as_ref().map(|drag| (drag.entity, drag.part))
+    }
+
+    /// The part of the handles of `entity` that is dragged, or else hovered.
+    fn highlighted(&self, entity: Entity) -> Option<HandlePart> {
+        self.dragged()
+            .or(self.hovered)
+            .filter(|&(highlighted, _)| highlighted == entity)
+            .map(|(_, part)| part)
+    }
+}
+
+/// The state of a handle from when it started being dragged.
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    entity: Entity,
+    part: HandlePart,
+    frame: HandleFrame,
+    start_point: Vec3,
+    start_transform: Transform,
+}
+
+/// The distance of the squares of the plane handles from the center, relative to the handle size.
+const PLANE_OFFSET: f32 = 0.25;
+/// The size of the squares of the plane handles, relative to the handle size.
+const PLANE_SIZE: f32 = 0.2;
+/// The radius of the outer rotation ring, relative to the handle size.
+const SCREEN_RING_RADIUS: f32 = 1.2;
+/// The size of the cubes of the scale handles, relative to the handle size.
+const SCALE_CUBE_SIZE: f32 = 0.1;
+/// The smallest factor a drag can scale an entity by, to keep its transform invertible.
+const MIN_SCALE_FACTOR: f32 = 0.01;
+
+/// The position, orientation and world space size of the handles of an entity.
+#[derive(Clone, Copy, Debug)]
+struct HandleFrame {
+    center: Vec3,
+    rotation: Quat,
+    /// The direction the camera is looking in.
+    view_direction: Vec3,
+    /// The direction to the right of the camera.
+    view_right: Vec3,
+    /// The world space length of handles [`TransformHandleConfig::size`] pixels long on screen.
+    size: f32,
+}
+
+impl HandleFrame {
+    fn new(
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        transform: &GlobalTransform,
+        handle: &TransformHandle,
+        config: &TransformHandleConfig,
+    ) -> Option<Self> {
+        let center = transform.translation();
+        let rotation = if handle.local || handle.mode == TransformHandleMode::Scale {
+            transform.to_scale_rotation_translation().1
+        } else {
+            Quat::IDENTITY
+        };
+        // Measure the world space distance `size` pixels away from the center on screen.
+        let viewport_center = camera.world_to_viewport(camera_transform, center)?;
+        let ray =
+            camera.viewport_to_world(camera_transform, viewport_center + Vec2::X * config.size)?;
+        let view_direction = camera_transform.forward();
+        let distance = ray.intersect_plane(center, Plane3d::new(view_direction))?;
+        let size = ray.get_point(distance).distance(center);
+        Some(Self {
+            center,
+            rotation,
+            view_direction,
+            view_right: camera_transform.right(),
+            size,
+        })
+    }
+
+    fn axis(&self, axis: HandleAxis) -> Vec3 {
+        self.rotation * axis.direction()
+    }
+
+    /// The transform of a scale handle cube centered on `position`.
+    fn scale_cube(&self, position: Vec3) -> Transform {
+        Transform::from_translation(position)
+            .with_rotation(self.rotation)
+            .with_scale(Vec3::splat(self.size * SCALE_CUBE_SIZE))
+    }
+
+    /// The normal and radius of the rotation ring `part`, if it is one.
+    fn ring(&self, part: HandlePart) -> Option<(Vec3, f32)> {
+        match part {
+            HandlePart::RotateAxis(axis) => Some((self.axis(axis), self.size)),
+            HandlePart::RotateScreen => Some((self.view_direction, self.size * SCREEN_RING_RADIUS)),
+            _ => None,
+        }
+    }
+
+    /// The corners of the square of the plane handle normal to `axis`.
+    fn plane_corners(&self, axis: HandleAxis) -> [Vec3; 4] {
+        let (u, v) = axis.others();
+        let (u, v) = (self.axis(u) * self.size, self.axis(v) * self.size);
+        let (near, far) = (PLANE_OFFSET, PLANE_OFFSET + PLANE_SIZE);
+        [
+            self.center + u * near + v * near,
+            self.center + u * far + v * near,
+            self.center + u * far + v * far,
+            self.center + u * near + v * far,
+        ]
+    }
+
+    /// The distance along `ray` at which it hits `part`, if it does.
+    ///
+    /// `hit_size` is the world space distance within which the ray hits thin parts.
+    fn hit(&self, ray: Ray3d, part: HandlePart, hit_size: f32) -> Option<f32> {
+        match part {
+            HandlePart::TranslateAxis(axis) | HandlePart::ScaleAxis(axis) => {
+                let direction = self.axis(axis);
+                let (along, _) = ray_line_closest(ray, self.center, direction)?;
+                let point = self.center + direction * along.clamp(0., self.size);
+                let distance = (point - ray.origin).dot(*ray.direction);
+                (distance >= 0. && ray.get_point(distance).distance(point) <= hit_size)
+                    .then_some(distance)
+            }
+            HandlePart::TranslatePlane(axis) => {
+                let distance = ray.intersect_plane(self.center, Plane3d::new(self.axis(axis)))?;
+                let offset = ray.get_point(distance) - self.center;
+                let (u, v) = axis.others();
+                let (u, v) = (
+                    offset.dot(self.axis(u)) / self.size,
+                    offset.dot(self.axis(v)) / self.size,
+                );
+                let range = PLANE_OFFSET..=PLANE_OFFSET + PLANE_SIZE;
+                (range.contains(&u) && range.contains(&v)).then_some(distance)
+            }
+            HandlePart::RotateAxis(_) | HandlePart::RotateScreen => {
+                let (normal, radius) = self.ring(part)?;
+                let distance = ray.intersect_plane(self.center, Plane3d::new(normal))?;
+                let offset = ray.get_point(distance).distance(self.center);
+                ((offset - radius).abs() <= hit_size).then_some(distance)
+            }
+            HandlePart::ScaleUniform => {
+                let distance = (self.center - ray.origin).dot(*ray.direction);
+                let offset = ray.get_point(distance).distance(self.center);
+                let radius = (self.size * SCALE_CUBE_SIZE).max(hit_size);
+                (distance >= 0. && offset <= radius).then_some(distance)
+            }
+        }
+    }
+
+    /// The point `part` is dragged to by `ray`, constrained to its axis or plane.
+    fn drag_point(&self, ray: Ray3d, part: HandlePart) -> Option<Vec3> {
+        match part {
+            HandlePart::TranslateAxis(axis) | HandlePart::ScaleAxis(axis) => {
+                let direction = self.axis(axis);
+                let (along, _) = ray_line_closest(ray, self.center, direction)?;
+                Some(self.center + direction * along)
+            }
+            HandlePart::TranslatePlane(axis) => {
+                let distance = ray.intersect_plane(self.center, Plane3d::new(self.axis(axis)))?;
+                Some(ray.get_point(distance))
+            }
+            HandlePart::RotateAxis(_) | HandlePart::RotateScreen => {
+                let (normal, _) = self.ring(part)?;
+                let distance = ray.intersect_plane(self.center, Plane3d::new(normal))?;
+                Some(ray.get_point(distance))
+            }
+            HandlePart::ScaleUniform => {
+                let plane = Plane3d::new(self.view_direction);
+                let distance = ray.intersect_plane(self.center, plane)?;
+                Some(ray.get_point(distance))
+            }
+        }
+    }
+
+    /// The rotation dragging `part` from `start_point` to `point` applies, in world space.
+    fn drag_rotation(
+        &self,
+        part: HandlePart,
+        start_point: Vec3,
+        point: Vec3,
+        snap: Option<f32>,
+    ) -> Quat {
+        let Some((normal, _)) = self.ring(part) else {
+            return Quat::IDENTITY;
+        };
+        let (from, to) = (start_point - self.center, point - self.center);
+        let mut angle = normal.dot(from.cross(to)).atan2(from.dot(to));
+        if let Some(snap) = snap.filter(|&snap| snap > 0.) {
+            angle = (angle / snap).round() * snap;
+        }
+        Quat::from_axis_angle(normal, angle)
+    }
+
+    /// The factors dragging `part` from `start_point` to `point` scales the local axes by.
+    fn drag_scale(&self, part: HandlePart, start_point: Vec3, point: Vec3) -> Vec3 {
+        match part {
+            HandlePart::ScaleAxis(axis) => {
+                let direction = self.axis(axis);
+                let start = (start_point - self.center).dot(direction);
+                let factor = if start.abs() > f32::EPSILON {
+                    (point - self.center).dot(direction) / start
+                } else {
+                    1.
+                };
+                let mut scale = Vec3::ONE;
+                scale[axis as usize] = factor.max(MIN_SCALE_FACTOR);
+                scale
+            }
+            HandlePart::ScaleUniform => {
+                let factor = 1. + (point - start_point).dot(self.view_right) / self.size;
+                Vec3::splat(factor.max(MIN_SCALE_FACTOR))
+            }
+            _ => Vec3::ONE,
+        }
+    }
+}
+
+/// The parameters of the closest points between `ray` and the line through `origin`
+/// along the normalized `direction`: the distance along the line and the distance along the ray.
+///
+/// Returns `None` if they are parallel.
+fn ray_line_closest(ray: Ray3d, origin: Vec3, direction: Vec3) -> Option<(f32, f32)> {
+    let offset = origin - ray.origin;
+    let cos = direction.dot(*ray.direction);
+    let denominator = 1. - cos * cos;
+    if denominator < 1e-6 {
+        return None;
+    }
+    let along_line = (cos * ray.direction.dot(offset) - direction.dot(offset)) / denominator;
+    let along_ray = ray.direction.dot(offset) + along_line * cos;
+    Some((along_line, along_ray))
+}
+
+/// The parts of the handles drawn in `mode`.
+fn handle_parts(mode: TransformHandleMode) -> Vec<HandlePart> {
+    let axes = HandleAxis::ALL.into_iter();
+    match mode {
+        TransformHandleMode::Translate => axes
+            .flat_map(|axis| {
+                [
+                    HandlePart::TranslateAxis(axis),
+                    HandlePart::TranslatePlane(axis),
+                ]
+            })
+            .collect(),
+        TransformHandleMode::Rotate => axes
+            .map(HandlePart::RotateAxis)
+            .chain([HandlePart::RotateScreen])
+            .collect(),
+        TransformHandleMode::Scale => axes
+            .map(HandlePart::ScaleAxis)
+            .chain([HandlePart::ScaleUniform])
+            .collect(),
+    }
+}
+
+/// The active camera with the highest [`Camera::order`].
+fn handle_camera<'a>(
+    cameras: &'a Query<(&Camera, &GlobalTransform)>,
+) -> Option<(&'a Camera, &'a GlobalTransform)> {
+    cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_transform_handles(
+    mut handles: ResMut<TransformHandles>,
+    mut query: Query<(Entity, &TransformHandle, &GlobalTransform, &mut Transform)>,
+    parents: Query<&Parent>,
+    global_transforms: Query<&GlobalTransform>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    config: Res<GizmoConfig>,
+) {
+    let handle_config = &config.transform_handles;
+    handles.hovered = None;
+    if !mouse.pressed(MouseButton::Left) {
+        handles.drag = None;
+    }
+
+    let Some((camera, camera_transform)) = handle_camera(&cameras) else {
+        return;
+    };
+    let Some(ray) = windows
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .and_then(|cursor| {
+            let viewport_min = camera
+                .logical_viewport_rect()
+                .map_or(Vec2::ZERO, |rect| rect.min);
+            camera.viewport_to_world(camera_transform, cursor - viewport_min)
+        })
+    else {
+        return;
+    };
+
+    if let Some(drag) = handles.drag {
+        let Ok((_, _, _, mut transform)) = query.get_mut(drag.entity) else {
+            handles.drag = None;
+            return;
+        };
+        let Some(point) = drag.frame.drag_point(ray, drag.part) else {
+            return;
+        };
+        let parent = parents
+            .get(drag.entity)
+            .and_then(|parent| global_transforms.get(parent.get()))
+            .ok();
+        match drag.part {
+            HandlePart::TranslateAxis(_) | HandlePart::TranslatePlane(_) => {
+                let translation = drag.frame.center + point - drag.start_point;
+                transform.translation = match parent {
+                    Some(parent) => parent.affine().inverse().transform_point3(translation),
+                    None => translation,
+                };
+            }
+            HandlePart::RotateAxis(_) | HandlePart::RotateScreen => {
+                let snap = handle_config.rotation_snap;
+                let mut rotation =
+                    drag.frame
+                        .drag_rotation(drag.part, drag.start_point, point, snap);
+                // Express the world space rotation in the parent's space.
+                if let Some(parent) = parent {
+                    let parent_rotation = parent.to_scale_rotation_translation().1;
+                    rotation = parent_rotation.inverse() * rotation * parent_rotation;
+                }
+                transform.rotation = rotation * drag.start_transform.rotation;
+            }
+            HandlePart::ScaleAxis(_) | HandlePart::ScaleUniform => {
+                let scale = drag.frame.drag_scale(drag.part, drag.start_point, point);
+                transform.scale = drag.start_transform.scale * scale;
+            }
+        }
+        return;
+    }
+
+    let mut nearest: Option<(f32, Entity, HandlePart, HandleFrame)> = None;
+    for (entity, handle, transform, _) in &query {
+        let Some(frame) =
+            HandleFrame::new(camera, camera_transform, transform, handle, handle_config)
+        else {
+            continue;
+        };
+        let hit_size = frame.size * handle_config.hit_distance / handle_config.size;
+        for part in handle_parts(handle.mode) {
+            if let Some(distance) = frame.hit(ray, part, hit_size) {
+                if nearest.map_or(true, |(nearest, ..)| distance < nearest) {
+                    nearest = Some((distance, entity, part, frame));
+                }
+            }
+        }
+    }
+    let Some((_, entity, part, frame)) = nearest else {
+        return;
+    };
+    handles.hovered = Some((entity, part));
+
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    if let (Some(start_point), Ok((.., start_transform))) =
+        (frame.drag_point(ray, part), query.get(entity))
+    {
+        handles.drag = Some(Drag {
+            entity,
+            part,
+            frame,
+            start_point,
+            start_transform: *start_transform,
+        });
+    }
+}
+
+pub(crate) fn draw_transform_handles(
+    query: Query<(Entity, &TransformHandle, &GlobalTransform)>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    handles: Res<TransformHandles>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let handle_config = &config.transform_handles;
+    let Some((camera, camera_transform)) = handle_camera(&cameras) else {
+        return;
+    };
+    for (entity, handle, transform) in &query {
+        let Some(frame) =
+            HandleFrame::new(camera, camera_transform, transform, handle, handle_config)
+        else {
+            continue;
+        };
+        let highlighted = handles.highlighted(entity);
+        for part in handle_parts(handle.mode) {
+            let color = if highlighted == Some(part) {
+                handle_config.hover_color
+            } else {
+                handle_config.part_color(part)
+            };
+            match part {
+                HandlePart::TranslateAxis(axis) => {
+                    let end = frame.center + frame.axis(axis) * frame.size;
+                    gizmos.arrow(frame.center, end, color);
+                }
+                HandlePart::TranslatePlane(axis) => {
+                    let [a, b, c, d] = frame.plane_corners(axis);
+                    gizmos.linestrip([a, b, c, d, a], color);
+                }
+                HandlePart::RotateAxis(_) | HandlePart::RotateScreen => {
+                    if let Some((normal, radius)) = frame.ring(part) {
+                        gizmos.circle(frame.center, normal, radius, color);
+                    }
+                }
+                HandlePart::ScaleAxis(axis) => {
+                    let end = frame.center + frame.axis(axis) * frame.size;
+                    gizmos.line(frame.center, end, color);
+                    gizmos.cuboid(frame.scale_cube(end), color);
+                }
+                HandlePart::ScaleUniform => {
+                    gizmos.cuboid(frame.scale_cube(frame.center), color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{Quat, Ray3d, Vec3};
+
+    use super::{HandleAxis, HandleFrame, HandlePart};
+
+    /// Where the camera looks at the handles from, off every axis.
+    const EYE: Vec3 = Vec3::new(4., 5., 6.);
+    const HIT_SIZE: f32 = 0.05;
+
+    /// The handles of an entity at the origin aligned to the world axes, 1 unit long.
+    fn frame() -> HandleFrame {
+        HandleFrame {
+            center: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            view_direction: -EYE.normalize(),
+            view_right: Vec3::X,
+            size: 1.,
+        }
+    }
+
+    fn ray_to(target: Vec3) -> Ray3d {
+        Ray3d::new(EYE, target - EYE)
+    }
+
+    #[test]
+    fn picks_the_axis_handle_under_the_ray() {
+        let frame = frame();
+        for axis in HandleAxis::ALL {
+            let target = axis.direction() * 0.5;
+            for part in [HandlePart::TranslateAxis, HandlePart::ScaleAxis] {
+                let distance = frame.hit(ray_to(target), part(axis), HIT_SIZE);
+                assert!((distance.unwrap() - EYE.distance(target)).abs() < 1e-4);
+
+                for other in HandleAxis::ALL.into_iter().filter(|&other| other != axis) {
+                    assert_eq!(frame.hit(ray_to(target), part(other), HIT_SIZE), None);
+                }
+                // The handles end at their size.
+                let beyond = axis.direction() * 1.5;
+                assert_eq!(frame.hit(ray_to(beyond), part(axis), HIT_SIZE), None);
+            }
+        }
+    }
+
+    #[test]
+    fn picks_the_plane_and_ring_handles_under_the_ray() {
+        let frame = frame();
+        for axis in HandleAxis::ALL {
+            let (u, v) = axis.others();
+            let in_square = (u.direction() + v.direction()) * 0.35;
+            let plane = HandlePart::TranslatePlane(axis);
+            assert!(frame.hit(ray_to(in_square), plane, HIT_SIZE).is_some());
+            assert_eq!(frame.hit(ray_to(in_square * 2.), plane, HIT_SIZE), None);
+
+            let on_ring = u.direction();
+            let ring = HandlePart::RotateAxis(axis);
+            assert!(frame.hit(ray_to(on_ring), ring, HIT_SIZE).is_some());
+            assert_eq!(frame.hit(ray_to(on_ring * 0.5), ring, HIT_SIZE), None);
+        }
+    }
+
+    #[test]
+    fn drags_handles_along_their_axis() {
+        let frame = frame();
+        for axis in HandleAxis::ALL {
+            let direction = axis.direction();
+            let point = frame.drag_point(ray_to(direction * 2.), HandlePart::TranslateAxis(axis));
+            assert!(point.unwrap().abs_diff_eq(direction * 2., 1e-4));
+
+            let scale = frame.drag_scale(HandlePart::ScaleAxis(axis), direction, direction * 2.);
+            assert_eq!(scale, Vec3::ONE + direction);
+
+            let (u, v) = axis.others();
+            let rotation = frame.drag_rotation(
+                HandlePart::RotateAxis(axis),
+                u.direction(),
+                v.direction(),
+                None,
+            );
+            assert!((rotation * u.direction()).abs_diff_eq(v.direction(), 1e-5));
+        }
+    }
+}
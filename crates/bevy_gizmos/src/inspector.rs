@@ -0,0 +1,280 @@
+//! An overlay listing how many vertices each kind of gizmo draws.
+//!
+//! Includes the [`GizmoInspectorPlugin`] drawing it and the [`GizmoUsage`] it reads.
+
+use crate::{
+    gizmos::{GizmoStorage, Gizmos},
+    orientation::ScreenCorner,
+    text::text_size,
+    GizmoConfig, ReadGizmos,
+};
+use bevy_app::{App, Last, Plugin, PostUpdate};
+use bevy_ecs::{
+    schedule::IntoSystemConfigs,
+    system::{IntoSystem, Local, Query, Res, ResMut, Resource, System},
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, ReflectRef, Struct};
+use bevy_render::{camera::Camera, color::Color};
+use bevy_transform::{components::GlobalTransform, TransformSystem};
+use bevy_utils::{get_short_name, HashMap};
+use std::{borrow::Cow, fmt::Write};
+
+/// A [`Plugin`] that lists how many vertices each [configuration group](GizmoConfig::sub_configs)
+/// drew in the last frame, sorted by cost, to find which debug visualization blows the frame
+/// budget without attaching a profiler.
+///
+/// Each row shows the number of vertices, the name of the group and its boolean settings
+/// that are enabled, such as `draw_all`. The gizmos of other systems are listed by system,
+/// and those drawn outside of systems, such as [persistent gizmos](Gizmos::persist), as `other`.
+///
+/// The list is drawn in the viewport of the active camera with the highest [`Camera::order`],
+/// configured by [`GizmoConfig::inspector`].
+///
+/// Unlike most other gizmo plugins, it isn't added by the [`GizmoPlugin`](crate::GizmoPlugin).
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_app::prelude::*;
+/// # let mut app = App::new();
+/// app.add_plugins(GizmoInspectorPlugin);
+/// ```
+pub struct GizmoInspectorPlugin;
+
+impl Plugin for GizmoInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GizmoUsage>()
+            .add_systems(Last, measure_gizmo_usage.in_set(ReadGizmos))
+            .add_systems(
+                PostUpdate,
+                draw_inspector.after(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+/// The number of vertices drawn by each system during the last frame,
+/// measured while the [`GizmoInspectorPlugin`] is added.
+///
+/// Circles, arcs and texts count towards the system that drew them once their vertices are
+/// resolved at the end of the frame. Retained [`LineGizmo`](crate::LineGizmo)s aren't counted.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct GizmoUsage {
+    /// The number of vertices drawn by each system, by the name of the system.
+    pub systems: HashMap<String, usize>,
+    /// The number of vertices drawn outside of systems, such as by [persistent gizmos](Gizmos::persist),
+    /// [async gizmos](Gizmos::draw_async) and the [`GizmoSender`](crate::gizmos::GizmoSender).
+    pub other: usize,
+}
+
+impl GizmoUsage {
+    /// The number of vertices drawn during the last frame.
+    pub fn total(&self) -> usize {
+        self.systems.values().sum::<usize>() + self.other
+    }
+}
+
+/// Configuration for the list drawn by the [`GizmoInspectorPlugin`].
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct InspectorConfig {
+    /// The corner of the viewport the list is drawn in.
+    ///
+    /// Defaults to [`ScreenCorner::TopRight`].
+    pub corner: ScreenCorner,
+    /// The distance from the corner of the viewport to the list in logical pixels,
+    /// both horizontally and vertically.
+    ///
+    /// Defaults to `10.0`.
+    pub margin: f32,
+    /// The height of the text in logical pixels.
+    ///
+    /// Defaults to `8.0`.
+    pub text_size: f32,
+    /// The maximum number of rows, the cheapest rows are left out beyond it.
+    ///
+    /// Defaults to `16`.
+    pub max_rows: usize,
+    /// The color of the text.
+    ///
+    /// Defaults to `Color::WHITE`.
+    pub color: Color,
+}
+
+impl Default for InspectorConfig {
+    fn default() -> Self {
+        Self {
+            corner: ScreenCorner::TopRight,
+            margin: 10.,
+            text_size: 8.,
+            max_rows: 16,
+            color: Color::WHITE,
+        }
+    }
+}
+
+fn measure_gizmo_usage(storage: Res<GizmoStorage>, mut usage: ResMut<GizmoUsage>) {
+    let usage = &mut *usage;
+    for vertices in usage.systems.values_mut() {
+        *vertices = 0;
+    }
+    let mut counted = 0;
+    for system_usage in &storage.usage {
+        counted += system_usage.vertices;
+        match usage.systems.get_mut(system_usage.system.as_str()) {
+            Some(vertices) => *vertices += system_usage.vertices,
+            None => {
+                usage
+                    .systems
+                    .insert(system_usage.system.clone(), system_usage.vertices);
+            }
+        }
+    }
+    usage.systems.retain(|_, vertices| *vertices > 0);
+    let total = storage.list_positions.len() + storage.strip_positions.len();
+    usage.other = total.saturating_sub(counted);
+}
+
+/// The name of a system, as in [`GizmoUsage::systems`].
+fn system_name<M>(system: impl IntoSystem<(), (), M>) -> Cow<'static, str> {
+    IntoSystem::into_system(system).name()
+}
+
+/// The names of the systems drawing the gizmos of each configuration group.
+fn group_systems() -> Vec<(Cow<'static, str>, &'static str)> {
+    vec![
+        (system_name(crate::draw_aabbs), "aabb"),
+        (system_name(crate::draw_all_aabbs), "aabb"),
+        (system_name(crate::draw_bounding_spheres), "bounding_sphere"),
+        (
+            system_name(crate::draw_all_bounding_spheres),
+            "bounding_sphere",
+        ),
+        (system_name(crate::draw_axes), "axes"),
+        (system_name(crate::draw_all_axes), "axes"),
+        (system_name(crate::frustum::draw_frustums), "frustum"),
+        (system_name(crate::motion::draw_motion), "motion"),
+        (system_name(crate::name::draw_names), "name"),
+        (system_name(crate::origin::draw_origin), "origin"),
+        (
+            system_name(crate::orientation::draw_orientation),
+            "orientation",
+        ),
+        (system_name(crate::rays::draw_debug_rays), "debug_rays"),
+        (system_name(crate::selection::draw_selections), "selection"),
+        (system_name(crate::plots::draw_plots), "plots"),
+        (
+            system_name(crate::handles::draw_transform_handles),
+            "transform_handles",
+        ),
+        (system_name(crate::skeleton::draw_skeletons), "skeleton"),
+        (system_name(crate::viewport::draw_viewports), "viewport"),
+        (system_name(draw_inspector), "inspector"),
+        #[cfg(feature = "bevy_audio")]
+        (system_name(crate::audio::draw_audio), "audio"),
+        #[cfg(feature = "bevy_animation")]
+        (
+            system_name(crate::animation::draw_animation_paths),
+            "animation_path",
+        ),
+        #[cfg(feature = "bevy_pbr")]
+        (system_name(crate::light::draw_lights), "light"),
+        #[cfg(feature = "bevy_pbr")]
+        (system_name(crate::cluster::draw_clusters), "cluster"),
+        #[cfg(feature = "bevy_sprite")]
+        (system_name(crate::sprite::draw_sprites), "sprite"),
+        #[cfg(feature = "graph_overlay")]
+        (
+            system_name(crate::graph_overlay::draw_graph_overlay),
+            "graph_overlay",
+        ),
+    ]
+}
+
+/// The boolean settings of the configuration group `name` that are enabled, separated by spaces.
+fn enabled_settings(config: &GizmoConfig, name: &str) -> String {
+    let mut settings = String::new();
+    let Some(ReflectRef::Struct(group)) = config.field(name).map(Reflect::reflect_ref) else {
+        return settings;
+    };
+    for index in 0..group.field_len() {
+        let enabled = group.field_at(index).and_then(|field| field.downcast_ref());
+        if let (Some(field), Some(true)) = (group.name_at(index), enabled) {
+            settings.push(' ');
+            settings.push_str(field);
+        }
+    }
+    settings
+}
+
+fn draw_inspector(
+    usage: Res<GizmoUsage>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    config: Res<GizmoConfig>,
+    mut group_systems: Local<Option<Vec<(Cow<'static, str>, &'static str)>>>,
+    mut gizmos: Gizmos,
+) {
+    let group_systems = group_systems.get_or_insert_with(self::group_systems);
+
+    let mut rows = HashMap::<Cow<str>, usize>::new();
+    for (system, &vertices) in &usage.systems {
+        let name = match group_systems.iter().find(|(name, _)| name == system) {
+            Some((_, group)) => Cow::Borrowed(*group),
+            None => Cow::Owned(get_short_name(system)),
+        };
+        *rows.entry(name).or_default() += vertices;
+    }
+    // List the groups that drew nothing too, to show whether they are enabled.
+    for (_, group) in group_systems.iter() {
+        rows.entry(Cow::Borrowed(*group)).or_default();
+    }
+    let mut rows: Vec<_> = rows.into_iter().collect();
+    if usage.other > 0 {
+        rows.push((Cow::Borrowed("other"), usage.other));
+    }
+    rows.sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then_with(|| a_name.cmp(b_name)));
+
+    let inspector_config = &config.inspector;
+    let mut text = format!("{:>7} gizmo vertices", usage.total());
+    if !config.enabled {
+        text.push_str(" (disabled)");
+    }
+    for (name, vertices) in rows.iter().take(inspector_config.max_rows) {
+        let settings = enabled_settings(&config, name);
+        let _ = write!(text, "\n{vertices:>7} {name}{settings}");
+    }
+    // Pad the rows to the same width, as each row is centered on the text.
+    let width = text
+        .lines()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    let text = text
+        .lines()
+        .map(|row| format!("{row:<width$}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let Some((camera, camera_transform)) = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+    else {
+        return;
+    };
+    let mut screen = gizmos.screen(camera, camera_transform);
+    let Some(viewport_size) = screen.viewport_size() else {
+        return;
+    };
+    let size = text_size(&text) * inspector_config.text_size;
+    // Move the center of the text away from the corner by half of its size.
+    let corner = inspector_config
+        .corner
+        .position(viewport_size, inspector_config.margin);
+    let center = corner + (viewport_size / 2. - corner).signum() * size / 2.;
+    screen.text(
+        center,
+        &text,
+        inspector_config.text_size,
+        inspector_config.color,
+    );
+}
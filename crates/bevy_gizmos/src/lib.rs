@@ -14,6 +14,30 @@
 //! ```
 //!
 //! See the documentation on [`Gizmos`] for more examples.
+//!
+//! # Stripping gizmos from release builds
+//!
+//! Enable the `strip_in_release` feature to turn all [`Gizmos`] methods into no-ops and skip
+//! the gizmo systems entirely when `debug_assertions` are disabled.
+//!
+//! To only strip some gizmos, draw them with the [`debug_gizmo!`] macro instead.
+//!
+//! # Headless builds
+//!
+//! Without the `bevy_sprite` and `bevy_pbr` features nothing can render gizmos, so the crate
+//! still builds but [`Gizmos`] methods are no-ops and the gizmo systems are skipped,
+//! as with `strip_in_release`. Code drawing gizmos can then be shared with dedicated servers
+//! without `cfg` attributes. Shapes are still captured by the [`GizmoTestPlugin`](testing::GizmoTestPlugin).
+//! Retained [`LineGizmo`] assets can still be added in apps with an `AssetPlugin`,
+//! they are just never drawn.
+//!
+//! # Scenes
+//!
+//! The [`GizmoPlugin`] registers the gizmo components, such as [`AabbGizmo`] and
+//! [`Gizmo`](shape::Gizmo), so they are saved and loaded with scenes like any other component
+//! and level annotations made of gizmos survive a round trip through a `DynamicScene`.
+//! Loading them needs the types of their fields to be registered as well, such as
+//! `Option<Color>`.
 
 /// Label for the the render systems handling the
 #[derive(SystemSet, Clone, Debug, Hash, PartialEq, Eq)]
@@ -26,10 +50,51 @@ pub enum GizmoRenderSystem {
     QueueLineGizmos3d,
 }
 
+/// Label for the systems of the [`Last`] schedule that read the gizmos of the frame
+/// with [`GizmoLines`](lines::GizmoLines), once all of them are resolved into lines.
+#[derive(SystemSet, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ReadGizmos;
+
+pub mod animation;
 pub mod arcs;
 pub mod arrows;
+pub mod audio;
 pub mod circles;
+pub mod cluster;
+pub mod colormap;
+pub mod diagnostics;
+pub mod env;
+pub mod export;
+pub mod frustum;
 pub mod gizmos;
+pub mod gradients;
+pub mod graph_overlay;
+pub mod grid;
+pub mod handles;
+pub mod inspector;
+pub mod light;
+pub mod lines;
+pub mod measure;
+pub mod mesh;
+pub mod motion;
+pub mod name;
+pub mod orientation;
+pub mod origin;
+pub mod plots;
+pub mod rays;
+pub mod recorder;
+pub mod screen;
+pub mod selection;
+#[cfg(feature = "settings_panel")]
+pub mod settings_panel;
+pub mod shape;
+pub mod skeleton;
+pub mod sprite;
+pub mod testing;
+pub mod text;
+#[cfg(feature = "bevy_ui")]
+pub mod ui;
+pub mod viewport;
 
 #[cfg(feature = "bevy_sprite")]
 mod pipeline_2d;
@@ -39,90 +104,303 @@ mod pipeline_3d;
 /// The `bevy_gizmos` prelude.
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{gizmos::Gizmos, AabbGizmo, AabbGizmoConfig, GizmoConfig};
+    pub use crate::{
+        animation::{AnimationPathGizmo, AnimationPathGizmoConfig},
+        audio::{AudioGizmo, AudioGizmoConfig},
+        cluster::ClusterGizmoConfig,
+        colormap::GizmoColormap,
+        debug_gizmo,
+        export::{GizmoExportFormat, GizmoExporter},
+        frustum::{FrustumGizmo, FrustumGizmoConfig},
+        gizmos::{GizmoCommandsExt, GizmoSender, GizmoTag, GizmoWorldExt, Gizmos, ParallelGizmos},
+        gradients::GradientColorSpace,
+        graph_overlay::GraphOverlayConfig,
+        grid::GridLabels,
+        handles::{
+            TransformHandle, TransformHandleConfig, TransformHandleMode, TransformHandlePlugin,
+            TransformHandles,
+        },
+        inspector::{GizmoInspectorPlugin, GizmoUsage, InspectorConfig},
+        light::{LightGizmo, LightGizmoConfig},
+        lines::{GizmoLines, GizmoStrip, GizmoVertex},
+        mesh::GizmoShapeMeshBuilder,
+        motion::{MotionGizmo, MotionGizmoConfig},
+        name::{NameGizmo, NameGizmoConfig},
+        orientation::{OrientationGizmoConfig, OrientationGizmoPlugin, ScreenCorner},
+        origin::{OriginGizmoConfig, OriginGizmoPlugin},
+        plots::{GizmoPlots, PlotConfig},
+        rays::{DebugRay, DebugRayGizmoConfig, DebugRays},
+        recorder::GizmoRecorder,
+        selection::{SelectionGizmo, SelectionGizmoConfig},
+        shape::{Gizmo, GizmoShape},
+        skeleton::{SkeletonGizmo, SkeletonGizmoConfig},
+        sprite::{SpriteGizmo, SpriteGizmoConfig},
+        testing::{CapturedGizmo, CapturedGizmos, CapturedShape, GizmoTestPlugin},
+        text::{TextGizmoAnchor, TextGizmoConfig},
+        viewport::{ViewportGizmo, ViewportGizmoConfig},
+        AabbGizmo, AabbGizmoConfig, AabbGizmoHierarchy, AxesGizmo, AxesGizmoConfig,
+        BoundingSphereGizmo, BoundingSphereGizmoConfig, GizmoCamera, GizmoCameraFilter,
+        GizmoConfig, GizmoConfigSnapshot, GizmoInScreenshots, LineGizmo,
+    };
+
+    #[cfg(feature = "bevy_ui")]
+    #[doc(hidden)]
+    pub use crate::ui::UiGizmos;
 }
 
-use bevy_app::{Last, Plugin, PostUpdate};
-use bevy_asset::{load_internal_asset, Asset, AssetApp, Assets, Handle};
+use animation::{AnimationPathGizmo, AnimationPathGizmoConfig};
+use audio::{AudioGizmo, AudioGizmoConfig};
+use bevy_app::{First, FixedFirst, FixedLast, Last, Plugin, PostUpdate};
+use bevy_asset::{
+    load_internal_asset, Asset, AssetApp, AssetEvent, AssetId, AssetServer, Assets, Handle,
+};
 use bevy_core::cast_slice;
 use bevy_ecs::{
     change_detection::DetectChanges,
     component::Component,
     entity::Entity,
-    query::{ROQueryItem, Without},
+    event::EventReader,
+    query::{ROQueryItem, With, Without},
     reflect::{ReflectComponent, ReflectResource},
     schedule::{IntoSystemConfigs, SystemSet},
     system::{
         lifetimeless::{Read, SRes},
-        Commands, Query, Res, ResMut, Resource, SystemParamItem,
+        Commands, Local, Query, Res, ResMut, Resource, SystemParamItem,
     },
+    world::{FromWorld, World},
 };
-use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypePath};
+use bevy_hierarchy::{Children, HierarchyQueryExt};
+use bevy_log::{warn, warn_once};
+use bevy_math::{Affine3A, Quat, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, ReflectRef, Struct, TypePath};
 use bevy_render::{
+    camera::{Camera, ExtractedCamera, NormalizedRenderTarget},
     color::Color,
-    extract_component::{ComponentUniforms, DynamicUniformIndex, UniformComponentPlugin},
-    primitives::Aabb,
-    render_asset::{
-        PrepareAssetError, RenderAsset, RenderAssetPersistencePolicy, RenderAssetPlugin,
-        RenderAssets,
+    extract_component::{
+        ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+        UniformComponentPlugin,
     },
+    primitives::{Aabb, Frustum},
     render_phase::{PhaseItem, RenderCommand, RenderCommandResult, TrackedRenderPass},
     render_resource::{
-        binding_types::uniform_buffer, BindGroup, BindGroupEntries, BindGroupLayout,
-        BindGroupLayoutEntries, Buffer, BufferInitDescriptor, BufferUsages, Shader, ShaderStages,
-        ShaderType, VertexAttribute, VertexBufferLayout, VertexFormat, VertexStepMode,
+        binding_types::{storage_buffer_read_only_sized, uniform_buffer},
+        BindGroup, BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, Buffer,
+        BufferBinding, BufferDescriptor, BufferUsages, IndexFormat, Shader, ShaderDefVal,
+        ShaderStages, ShaderType, VertexAttribute, VertexBufferLayout, VertexFormat, VertexState,
+        VertexStepMode,
     },
-    renderer::RenderDevice,
-    view::RenderLayers,
-    Extract, ExtractSchedule, Render, RenderApp, RenderSet,
+    renderer::{RenderDevice, RenderQueue},
+    view::{ExtractedView, ExtractedWindows, InheritedVisibility, RenderLayers},
+    Extract, ExtractSchedule, MainWorld, Render, RenderApp, RenderSet,
 };
+use bevy_tasks::ComputeTaskPool;
 use bevy_transform::{
     components::{GlobalTransform, Transform},
     TransformSystem,
 };
-use gizmos::{GizmoStorage, Gizmos};
-use std::mem;
+use bevy_utils::{tracing::info_span, Duration, HashMap, HashSet, Instant};
+use cluster::ClusterGizmoConfig;
+use diagnostics::GizmoRenderDiagnostics;
+use export::GizmoExporter;
+use frustum::{FrustumGizmo, FrustumGizmoConfig};
+use gizmos::{FixedGizmoStorage, GizmoBuffer, GizmoStorage, Gizmos};
+use gradients::{GradientColorSpace, GradientScratch};
+use graph_overlay::GraphOverlayConfig;
+use handles::{
+    HandleAxis, HandlePart, TransformHandle, TransformHandleConfig, TransformHandleMode,
+};
+use inspector::InspectorConfig;
+use light::{LightGizmo, LightGizmoConfig};
+use motion::{MotionGizmo, MotionGizmoConfig};
+use name::{NameGizmo, NameGizmoConfig};
+use orientation::{OrientationGizmoConfig, ScreenCorner};
+use origin::OriginGizmoConfig;
+use plots::{GizmoPlots, PlotConfig};
+use rays::{DebugRayGizmoConfig, DebugRays};
+use recorder::GizmoRecorder;
+use selection::{SelectionGizmo, SelectionGizmoConfig};
+use shape::{Gizmo, GizmoShape};
+use skeleton::{SkeletonGizmo, SkeletonGizmoConfig};
+use sprite::{SpriteGizmo, SpriteGizmoConfig};
+use std::{collections::VecDeque, mem, num::NonZeroU64, ops::Range};
+use text::{TextGizmoAnchor, TextGizmoConfig};
+use viewport::{ViewportGizmo, ViewportGizmoConfig};
 
 const LINE_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(7414812689238026784);
+const LIST_LINE_GIZMO_HANDLE: Handle<LineGizmo> =
+    Handle::weak_from_u128(254089218547301931265404186931475693837);
+const STRIP_LINE_GIZMO_HANDLE: Handle<LineGizmo> =
+    Handle::weak_from_u128(193047619347026437261843851120357129402);
 
 /// A [`Plugin`] that provides an immediate mode drawing api for visual debugging.
 pub struct GizmoPlugin;
 
 impl Plugin for GizmoPlugin {
     fn build(&self, app: &mut bevy_app::App) {
-        // Gizmos cannot work without either a 3D or 2D renderer.
-        #[cfg(all(not(feature = "bevy_pbr"), not(feature = "bevy_sprite")))]
-        bevy_log::error!("bevy_gizmos requires either bevy_pbr or bevy_sprite. Please enable one.");
-
-        load_internal_asset!(app, LINE_SHADER_HANDLE, "lines.wgsl", Shader::from_wgsl);
-
         app.register_type::<GizmoConfig>()
             .register_type::<AabbGizmoConfig>()
-            .add_plugins(UniformComponentPlugin::<LineGizmoUniform>::default())
-            .init_asset::<LineGizmo>()
-            .add_plugins(RenderAssetPlugin::<LineGizmo>::default())
-            .init_resource::<LineGizmoHandles>()
+            .register_type::<AdaptiveSegmentsConfig>()
+            .register_type::<LodConfig>()
+            .register_type::<GizmoBudgetOverflow>()
+            .register_type::<GizmoCameraFilter>()
+            .register_type::<GradientColorSpace>()
+            .register_type::<GizmoCamera>()
+            .register_type::<GizmoInScreenshots>()
+            .register_type::<AabbGizmo>()
+            .register_type::<AabbGizmoHierarchy>()
+            .register_type::<BoundingSphereGizmoConfig>()
+            .register_type::<BoundingSphereGizmo>()
+            .register_type::<AxesGizmoConfig>()
+            .register_type::<AxesGizmo>()
+            .register_type::<AudioGizmoConfig>()
+            .register_type::<AudioGizmo>()
+            .register_type::<AnimationPathGizmoConfig>()
+            .register_type::<AnimationPathGizmo>()
+            .register_type::<FrustumGizmoConfig>()
+            .register_type::<FrustumGizmo>()
+            .register_type::<LightGizmoConfig>()
+            .register_type::<LightGizmo>()
+            .register_type::<MotionGizmoConfig>()
+            .register_type::<MotionGizmo>()
+            .register_type::<NameGizmoConfig>()
+            .register_type::<NameGizmo>()
+            .register_type::<OriginGizmoConfig>()
+            .register_type::<OrientationGizmoConfig>()
+            .register_type::<ScreenCorner>()
+            .register_type::<ClusterGizmoConfig>()
+            .register_type::<DebugRayGizmoConfig>()
+            .register_type::<SelectionGizmoConfig>()
+            .register_type::<SelectionGizmo>()
+            .register_type::<Gizmo>()
+            .register_type::<GizmoShape>()
+            .register_type::<GraphOverlayConfig>()
+            .register_type::<InspectorConfig>()
+            .register_type::<PlotConfig>()
+            .register_type::<TransformHandleConfig>()
+            .register_type::<TransformHandle>()
+            .register_type::<TransformHandleMode>()
+            .register_type::<HandleAxis>()
+            .register_type::<HandlePart>()
+            .register_type::<SkeletonGizmoConfig>()
+            .register_type::<SkeletonGizmo>()
+            .register_type::<SpriteGizmoConfig>()
+            .register_type::<SpriteGizmo>()
+            .register_type::<ViewportGizmoConfig>()
+            .register_type::<ViewportGizmo>()
+            .register_type::<TextGizmoConfig>()
+            .register_type::<TextGizmoAnchor>()
             .init_resource::<GizmoConfig>()
             .init_resource::<GizmoStorage>()
-            .add_systems(Last, update_gizmo_meshes)
+            .init_resource::<DebugRays>()
+            .init_resource::<GizmoPlots>()
+            .init_resource::<GizmoRecorder>()
+            .init_resource::<GizmoExporter>();
+        let (gizmo_sender, gizmo_receiver) = gizmos::gizmo_channel();
+        app.insert_resource(gizmo_sender)
+            .insert_resource(gizmo_receiver);
+
+        // Retained line gizmos are added by users, so their asset is registered even in builds
+        // that never draw them, as long as there is an asset server to register it with.
+        if !gizmos::DISABLED || app.world.contains_resource::<AssetServer>() {
+            app.init_asset::<LineGizmo>();
+        }
+
+        // Stripped gizmos and gizmos of headless builds are never drawn, so skip all of their systems.
+        if gizmos::DISABLED {
+            return;
+        }
+
+        load_internal_asset!(app, LINE_SHADER_HANDLE, "lines.wgsl", Shader::from_wgsl);
+
+        app.add_plugins(UniformComponentPlugin::<LineGizmoUniform>::default())
+            .add_plugins(UniformComponentPlugin::<LineGizmoViewUniform>::default())
+            .add_plugins(ExtractComponentPlugin::<GizmoCamera>::default())
+            .init_resource::<LineGizmoFrame>()
+            .init_resource::<LineGizmoEntities>()
+            .init_resource::<FixedGizmoStorage>()
+            .add_systems(First, gizmos::start_gizmo_frame)
+            .add_systems(FixedFirst, gizmos::start_fixed_gizmos)
+            .add_systems(FixedLast, gizmos::end_fixed_gizmos)
+            .add_systems(
+                Last,
+                (
+                    gizmos::draw_fixed_gizmos,
+                    gizmos::receive_sent_gizmos,
+                    gizmos::draw_persistent_gizmos,
+                    gizmos::finish_gizmo_tasks,
+                    gizmos::resolve_screen_sized_gizmos,
+                    text::resolve_pending_texts,
+                    circles::resolve_pending_arcs,
+                    recorder::record_gizmos,
+                )
+                    .chain()
+                    .before(ReadGizmos),
+            )
+            .add_systems(
+                Last,
+                (
+                    export::export_gizmos.in_set(ReadGizmos),
+                    update_gizmo_meshes.after(ReadGizmos),
+                ),
+            )
             .add_systems(
                 PostUpdate,
                 (
                     draw_aabbs,
                     draw_all_aabbs.run_if(|config: Res<GizmoConfig>| config.aabb.draw_all),
+                    draw_bounding_spheres,
+                    draw_all_bounding_spheres
+                        .run_if(|config: Res<GizmoConfig>| config.bounding_sphere.draw_all),
+                    draw_axes,
+                    draw_all_axes.run_if(|config: Res<GizmoConfig>| config.axes.draw_all),
+                    frustum::draw_frustums,
+                    motion::draw_motion,
+                    name::draw_names,
+                    plots::draw_plots,
+                    rays::draw_debug_rays,
+                    selection::draw_selections,
+                    shape::draw_gizmo_shapes,
+                    skeleton::draw_skeletons,
+                    viewport::draw_viewports,
                 )
                     .after(TransformSystem::TransformPropagate),
             );
 
+        #[cfg(feature = "bevy_pbr")]
+        app.add_plugins(light::LightGizmoPlugin);
+        #[cfg(feature = "bevy_sprite")]
+        app.add_plugins(sprite::SpriteGizmoPlugin);
+        #[cfg(feature = "bevy_audio")]
+        app.add_plugins(audio::AudioGizmoPlugin);
+        #[cfg(feature = "bevy_animation")]
+        app.add_plugins(animation::AnimationPathGizmoPlugin);
+
         let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
         };
 
         render_app
-            .add_systems(ExtractSchedule, extract_gizmo_data)
+            .init_resource::<ExtractedLineGizmos>()
+            .init_resource::<GpuLineGizmos>()
+            .init_resource::<ExtractedRetainedLineGizmos>()
+            .init_resource::<VisibleLineGizmoChunks>()
+            .add_systems(
+                ExtractSchedule,
+                (
+                    (extract_gizmo_data, extract_line_gizmos).chain(),
+                    extract_retained_line_gizmos,
+                    extract_line_gizmo_views,
+                ),
+            )
             .add_systems(
                 Render,
-                prepare_line_gizmo_bind_group.in_set(RenderSet::PrepareBindGroups),
+                (
+                    prepare_line_gizmos.in_set(RenderSet::PrepareAssets),
+                    cull_line_gizmo_chunks
+                        .in_set(RenderSet::Queue)
+                        .after(prepare_line_gizmos),
+                    prepare_line_gizmo_bind_group.in_set(RenderSet::PrepareBindGroups),
+                ),
             );
 
         #[cfg(feature = "bevy_sprite")]
@@ -132,6 +410,10 @@ impl Plugin for GizmoPlugin {
     }
 
     fn finish(&self, app: &mut bevy_app::App) {
+        if gizmos::DISABLED {
+            return;
+        }
+
         let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
         };
@@ -139,33 +421,229 @@ impl Plugin for GizmoPlugin {
         let render_device = render_app.world.resource::<RenderDevice>();
         let layout = render_device.create_bind_group_layout(
             "LineGizmoUniform layout",
-            &BindGroupLayoutEntries::single(
+            &BindGroupLayoutEntries::sequential(
                 ShaderStages::VERTEX,
-                uniform_buffer::<LineGizmoUniform>(true),
+                (
+                    uniform_buffer::<LineGizmoUniform>(true),
+                    uniform_buffer::<LineGizmoViewUniform>(true),
+                ),
             ),
         );
 
+        let storage_layout = supports_vertex_pulling(render_device).then(|| {
+            render_device.create_bind_group_layout(
+                "LineGizmo storage layout",
+                &BindGroupLayoutEntries::sequential(
+                    ShaderStages::VERTEX,
+                    (
+                        storage_buffer_read_only_sized(false, None),
+                        storage_buffer_read_only_sized(false, None),
+                    ),
+                ),
+            )
+        });
+
         render_app.insert_resource(LineGizmoUniformBindgroupLayout { layout });
+        if let Some(layout) = storage_layout {
+            render_app.insert_resource(LineGizmoStorageBindGroupLayout { layout });
+        }
+    }
+}
+
+/// The memory in bytes counted per vertex by [`GizmoConfig::max_memory`].
+///
+/// This is the position and the color of the vertex on the CPU and on the GPU,
+/// with [`GizmoConfig::hdr_colors`] and the indices of an [indexed strip](GizmoConfig::indexed_strips).
+pub const GIZMO_VERTEX_MEMORY: usize = 2 * (12 + 16) + 6 * 4;
+
+/// The maximum [`GizmoConfig::line_width`] in logical pixels, wider lines are clamped to it.
+///
+/// Wider lines overlap at the joints of strips and mostly cost fill rate.
+pub const MAX_LINE_WIDTH: f32 = 64.;
+
+/// The line width passed to the shaders of all pipelines, see [`GizmoConfig::line_width`].
+fn validated_line_width(line_width: f32) -> f32 {
+    if line_width > MAX_LINE_WIDTH {
+        warn_once!(
+            "`GizmoConfig::line_width` is {line_width}, gizmo lines are clamped to {MAX_LINE_WIDTH} pixels"
+        );
+        MAX_LINE_WIDTH
+    } else if line_width > 0. {
+        line_width
+    } else {
+        warn_once!(
+            "`GizmoConfig::line_width` is {line_width}, gizmo lines are drawn 1 pixel wide instead"
+        );
+        1.
+    }
+}
+
+/// Which gizmos are dropped once the budget set in the [`GizmoConfig`] is exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Default)]
+pub enum GizmoBudgetOverflow {
+    /// Drop the gizmos drawn after the budget was exceeded.
+    #[default]
+    DropNewest,
+    /// Drop the gizmos that were drawn first this frame to make room for newer ones, like a ring buffer.
+    DropOldest,
+}
+
+/// Which cameras gizmos are rendered to, in addition to the [`GizmoConfig::render_layers`].
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_core_pipeline::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_render::camera::RenderTarget;
+/// # use bevy_window::{Window, WindowRef};
+/// // Render gizmos to the tools window only, not to the game window.
+/// fn setup(mut commands: Commands, mut config: ResMut<GizmoConfig>) {
+///     let tools = commands.spawn(Window::default()).id();
+///     commands.spawn(Camera3dBundle {
+///         camera: Camera {
+///             target: RenderTarget::Window(WindowRef::Entity(tools)),
+///             ..Default::default()
+///         },
+///         ..Default::default()
+///     });
+///     config.cameras = GizmoCameraFilter::Window(tools);
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Default)]
+pub enum GizmoCameraFilter {
+    /// Render gizmos to all cameras.
+    #[default]
+    All,
+    /// Only render gizmos to cameras with the [`GizmoCamera`] component.
+    Marked,
+    /// Only render gizmos to cameras rendering to the primary window.
+    PrimaryWindow,
+    /// Only render gizmos to cameras rendering to this window entity.
+    Window(Entity),
+}
+
+/// Add this [`Component`] to a camera to render gizmos to it
+/// when the [`GizmoConfig::cameras`] filter is [`GizmoCameraFilter::Marked`].
+#[derive(Component, ExtractComponent, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct GizmoCamera;
+
+/// Add this [`Component`] to an entity with a [`Handle<LineGizmo>`] to override
+/// [`GizmoConfig::in_screenshots`] for it.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_asset::Handle;
+/// // Keep the level annotations in screenshots even when other gizmos are left out.
+/// fn setup(mut commands: Commands, mut config: ResMut<GizmoConfig>) {
+///     config.in_screenshots = false;
+///     commands.spawn((Handle::<LineGizmo>::default(), GizmoInScreenshots(true)));
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// ```
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct GizmoInScreenshots(pub bool);
+
+impl Default for GizmoInScreenshots {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// The window the view of `camera` renders to, if any.
+fn target_window(camera: Option<&ExtractedCamera>) -> Option<Entity> {
+    match camera.and_then(|camera| camera.target.as_ref()) {
+        Some(NormalizedRenderTarget::Window(window)) => Some(window.entity()),
+        _ => None,
+    }
+}
+
+/// Whether gizmos are rendered to the view of `camera`, which has the [`GizmoCamera`] component if `marked`.
+fn is_gizmo_camera(
+    filter: GizmoCameraFilter,
+    camera: Option<&ExtractedCamera>,
+    marked: bool,
+    windows: &ExtractedWindows,
+) -> bool {
+    match filter {
+        GizmoCameraFilter::All => true,
+        GizmoCameraFilter::Marked => marked,
+        GizmoCameraFilter::PrimaryWindow => {
+            target_window(camera).is_some_and(|window| Some(window) == windows.primary)
+        }
+        GizmoCameraFilter::Window(entity) => target_window(camera) == Some(entity),
     }
 }
 
+/// Whether the view of `camera` renders to a window a screenshot is taken of this frame,
+/// see [`GizmoConfig::in_screenshots`].
+fn is_screenshot_view(camera: Option<&ExtractedCamera>, windows: &ExtractedWindows) -> bool {
+    target_window(camera)
+        .and_then(|window| windows.windows.get(&window))
+        .is_some_and(|window| window.screenshot_func.is_some())
+}
+
 /// A [`Resource`] that stores configuration for gizmos.
 #[derive(Resource, Clone, Reflect)]
-#[reflect(Resource)]
+#[reflect(Resource, Default)]
 pub struct GizmoConfig {
     /// Set to `false` to stop drawing gizmos.
     ///
+    /// This is the master switch, [`enabled_2d`](Self::enabled_2d) and
+    /// [`enabled_3d`](Self::enabled_3d) have no effect while it is `false`.
+    ///
     /// Defaults to `true`.
     pub enabled: bool,
-    /// Line width specified in pixels.
+    /// Set to `false` to stop drawing 2D gizmos, those drawn with the 2D methods
+    /// such as [`Gizmos::line_2d`] and [`Gizmos::circle_2d`], on any camera.
+    ///
+    /// Gizmos are dropped when their system's gizmos are stored, so this applies to
+    /// the gizmos of systems that run after it is changed. Persistent and screen-sized
+    /// gizmos are dropped along with the others, while the gizmos drawn by
+    /// [`Gizmos::draw_async`] are dropped once their task finishes.
+    ///
+    /// The 2D gizmos of a system are stored after its 3D gizmos, so they are rendered
+    /// after them rather than in the order they were drawn in.
+    ///
+    /// Defaults to `true`.
+    pub enabled_2d: bool,
+    /// Set to `false` to stop drawing 3D gizmos, those drawn with all other methods,
+    /// on any camera.
+    ///
+    /// Gizmos are dropped when their system's gizmos are stored, so this applies to
+    /// the gizmos of systems that run after it is changed, as described for
+    /// [`enabled_2d`](Self::enabled_2d).
+    ///
+    /// Defaults to `true`.
+    pub enabled_3d: bool,
+    /// Line width specified in logical pixels.
     ///
-    /// If `line_perspective` is `true` then this is the size in pixels at the camera's near plane.
+    /// This is scaled by the scale factor of each camera's render target,
+    /// so lines are as thick in every window and viewport regardless of its DPI.
+    /// Lines thinner than a physical pixel are drawn a pixel wide and faded instead.
+    ///
+    /// Widths above [`MAX_LINE_WIDTH`] are clamped to it, and non-positive or NaN widths
+    /// fall back to `1.0`, with a warning.
+    ///
+    /// If `line_perspective` is `true` then this is the size in pixels at a distance of 1
+    /// from the camera.
     ///
     /// Defaults to `2.0`.
     pub line_width: f32,
-    /// Apply perspective to gizmo lines.
+    /// Apply perspective to gizmo lines, so they get thinner with the distance like geometry.
     ///
-    /// This setting only affects 3D, non-orthographic cameras.
+    /// This setting only affects 3D cameras. With orthographic projections, lines get thinner
+    /// as the camera zooms out instead: they are as wide as with a perspective projection
+    /// with the default field of view that sees as much at the distance of the lines.
+    /// This way switching a camera between projections keeps the width of the lines it frames.
     ///
     /// Defaults to `false`.
     pub line_perspective: bool,
@@ -182,29 +660,265 @@ pub struct GizmoConfig {
     /// and your wireframe is z-fighting (flickering on/off) with your main model.
     /// You would set this value to a negative number close to 0.
     pub depth_bias: f32,
+    /// A shader drawing the lines instead of the built-in one, such as one loaded with the
+    /// [`AssetServer`](bevy_asset::AssetServer) to iterate on the style of gizmos without
+    /// recompiling. It is reloaded when the file changes with the `file_watcher` feature.
+    ///
+    /// It replaces the vertex and fragment stages of every gizmo pipeline, so it needs the same
+    /// bindings, entry points and shader defs as the built-in `lines.wgsl`, which makes a good
+    /// starting point. Gizmos aren't drawn until it is loaded.
+    ///
+    /// Defaults to `None`, meaning the built-in shader is used.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_asset::AssetServer;
+    /// fn setup(mut config: ResMut<GizmoConfig>, asset_server: Res<AssetServer>) {
+    ///     config.shader = Some(asset_server.load("shaders/dashed_gizmos.wgsl"));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(setup);
+    /// ```
+    pub shader: Option<Handle<Shader>>,
+    /// The color of the gizmos drawn with [`Gizmos::DEFAULT_COLOR`].
+    ///
+    /// Defaults to `Color::WHITE`.
+    pub default_color: Color,
     /// Configuration for the [`AabbGizmo`].
     pub aabb: AabbGizmoConfig,
+    /// Configuration for the [`BoundingSphereGizmo`].
+    pub bounding_sphere: BoundingSphereGizmoConfig,
+    /// Configuration for the [`AxesGizmo`].
+    pub axes: AxesGizmoConfig,
+    /// Configuration for the [`AudioGizmo`].
+    pub audio: AudioGizmoConfig,
+    /// Configuration for the [`AnimationPathGizmo`].
+    pub animation_path: AnimationPathGizmoConfig,
+    /// Configuration for the [`FrustumGizmo`].
+    pub frustum: FrustumGizmoConfig,
+    /// Configuration for the [`LightGizmo`].
+    pub light: LightGizmoConfig,
+    /// Configuration for the [`MotionGizmo`].
+    pub motion: MotionGizmoConfig,
+    /// Configuration for the [`NameGizmo`].
+    pub name: NameGizmoConfig,
+    /// Configuration for the [`OriginGizmoPlugin`](origin::OriginGizmoPlugin).
+    pub origin: OriginGizmoConfig,
+    /// Configuration for the [`OrientationGizmoPlugin`](orientation::OrientationGizmoPlugin).
+    pub orientation: OrientationGizmoConfig,
+    /// Configuration for the `ClusterGizmoPlugin`.
+    pub cluster: ClusterGizmoConfig,
+    /// Configuration for the [`DebugRays`].
+    pub debug_rays: DebugRayGizmoConfig,
+    /// Configuration for the [`SelectionGizmo`].
+    pub selection: SelectionGizmoConfig,
+    /// Configuration for the `GizmoGraphOverlayPlugin`.
+    pub graph_overlay: GraphOverlayConfig,
+    /// Configuration for the [`GizmoInspectorPlugin`](inspector::GizmoInspectorPlugin).
+    pub inspector: InspectorConfig,
+    /// Configuration for the [`GizmoPlots`].
+    pub plots: PlotConfig,
+    /// Configuration for the [`TransformHandlePlugin`](handles::TransformHandlePlugin).
+    pub transform_handles: TransformHandleConfig,
+    /// Configuration for the [`SkeletonGizmo`].
+    pub skeleton: SkeletonGizmoConfig,
+    /// Configuration for the [`SpriteGizmo`].
+    pub sprite: SpriteGizmoConfig,
+    /// Configuration for the [`ViewportGizmo`].
+    pub viewport: ViewportGizmoConfig,
+    /// Configuration for the texts drawn by [`Gizmos::text`] and [`Gizmos::text_2d`].
+    pub text: TextGizmoConfig,
+    /// Configuration for the number of segments of circles, arcs and spheres
+    /// that don't set it explicitly.
+    pub adaptive_segments: AdaptiveSegmentsConfig,
+    /// Configuration for reducing the number of segments of circles, arcs and spheres
+    /// that don't set it explicitly with their distance to the camera.
+    pub lod: LodConfig,
     /// Describes which rendering layers gizmos will be rendered to.
     ///
     /// Gizmos will only be rendered to cameras with intersecting layers.
     pub render_layers: RenderLayers,
+    /// Describes which cameras gizmos will be rendered to, such as to render them
+    /// to a single window of a multi-window app.
+    ///
+    /// Defaults to [`GizmoCameraFilter::All`].
+    pub cameras: GizmoCameraFilter,
+    /// Render gizmos to the frames a screenshot is taken of with the
+    /// [`ScreenshotManager`](bevy_render::view::screenshot::ScreenshotManager).
+    ///
+    /// Set this to `false` for clean captures, such as for marketing, and keep it `true`
+    /// for bug reports. Retained [`LineGizmo`]s can override it with [`GizmoInScreenshots`].
+    ///
+    /// Defaults to `true`.
+    pub in_screenshots: bool,
+    /// Upload colors with 32-bit float precision per channel.
+    ///
+    /// By default colors are quantized to 8 bits per channel, which cuts their bandwidth by 4
+    /// but clamps them to the `[0, 1]` range.
+    /// Enable this for HDR colors or when subtle gradients show banding.
+    ///
+    /// Defaults to `false`.
+    pub hdr_colors: bool,
+    /// The maximum number of vertices drawn per frame.
+    ///
+    /// Once this budget is exceeded, gizmos are dropped as set by [`GizmoConfig::budget_overflow`]
+    /// and a warning is logged at most once per second.
+    ///
+    /// Defaults to `None`, meaning there is no limit.
+    pub max_vertices: Option<usize>,
+    /// The maximum memory in bytes used by the CPU and GPU buffers of the gizmos drawn per frame.
+    ///
+    /// This is enforced like [`GizmoConfig::max_vertices`], counting the worst case of
+    /// [`GIZMO_VERTEX_MEMORY`] bytes per vertex.
+    /// GPU buffers grow by powers of two, so they may reserve up to twice the memory they use.
+    ///
+    /// Defaults to `None`, meaning there is no limit.
+    pub max_memory: Option<usize>,
+    /// Which gizmos are dropped once [`GizmoConfig::max_vertices`] or [`GizmoConfig::max_memory`]
+    /// is exceeded.
+    ///
+    /// Defaults to [`GizmoBudgetOverflow::DropNewest`].
+    pub budget_overflow: GizmoBudgetOverflow,
+    /// Draw line strips with an index buffer, so that adjacent segments share their vertices
+    /// and are joined with miter joints.
+    ///
+    /// This requires storage buffers in vertex shaders, strips fall back to being drawn as
+    /// separate segments on devices without them, such as WebGL2.
+    ///
+    /// Defaults to `true`.
+    pub indexed_strips: bool,
+    /// Drop lines that are exact duplicates of lines drawn earlier in the same frame,
+    /// such as the same bounding box drawn by several systems.
+    ///
+    /// This trades the CPU time spent hashing every line for fewer vertices to upload and draw.
+    /// Retained [`LineGizmo`]s are never deduplicated.
+    ///
+    /// Defaults to `false`.
+    pub deduplicate: bool,
+    /// The color space the colors of gradients are interpolated in.
+    ///
+    /// Defaults to [`GradientColorSpace::LinearRgb`].
+    pub gradient_color_space: GradientColorSpace,
 }
 
 impl Default for GizmoConfig {
     fn default() -> Self {
         Self {
             enabled: true,
+            enabled_2d: true,
+            enabled_3d: true,
             line_width: 2.,
             line_perspective: false,
             depth_bias: 0.,
+            shader: None,
+            default_color: Color::WHITE,
             aabb: Default::default(),
+            bounding_sphere: Default::default(),
+            axes: Default::default(),
+            audio: Default::default(),
+            animation_path: Default::default(),
+            frustum: Default::default(),
+            light: Default::default(),
+            motion: Default::default(),
+            name: Default::default(),
+            origin: Default::default(),
+            orientation: Default::default(),
+            cluster: Default::default(),
+            debug_rays: Default::default(),
+            selection: Default::default(),
+            graph_overlay: Default::default(),
+            inspector: Default::default(),
+            plots: Default::default(),
+            transform_handles: Default::default(),
+            skeleton: Default::default(),
+            sprite: Default::default(),
+            viewport: Default::default(),
+            text: Default::default(),
+            adaptive_segments: Default::default(),
+            lod: Default::default(),
             render_layers: Default::default(),
+            cameras: GizmoCameraFilter::All,
+            in_screenshots: true,
+            hdr_colors: false,
+            max_vertices: None,
+            max_memory: None,
+            budget_overflow: GizmoBudgetOverflow::DropNewest,
+            indexed_strips: true,
+            deduplicate: false,
+            gradient_color_space: GradientColorSpace::LinearRgb,
+        }
+    }
+}
+
+impl GizmoConfig {
+    /// The maximum number of vertices drawn per frame,
+    /// combining [`GizmoConfig::max_vertices`] and [`GizmoConfig::max_memory`].
+    pub fn vertex_budget(&self) -> Option<usize> {
+        let memory_budget = self
+            .max_memory
+            .map(|max_memory| max_memory / GIZMO_VERTEX_MEMORY);
+        match (self.max_vertices, memory_budget) {
+            (Some(max_vertices), Some(memory_budget)) => Some(max_vertices.min(memory_budget)),
+            (budget, None) | (None, budget) => budget,
         }
     }
+
+    /// Iterates over the name and reflected value of every nested configuration,
+    /// such as [`GizmoConfig::aabb`].
+    ///
+    /// This allows building settings panels that don't need to know about each configuration type.
+    /// Use [`Struct::field_mut`] with the returned name to edit a configuration.
+    pub fn sub_configs(&self) -> impl Iterator<Item = (&str, &dyn Reflect)> {
+        (0..self.field_len()).filter_map(|index| {
+            let field = self.field_at(index)?;
+            let ReflectRef::Struct(_) = field.reflect_ref() else {
+                return None;
+            };
+            Some((self.name_at(index)?, field))
+        })
+    }
+
+    /// Returns a copy of the whole configuration that can later be put back with [`GizmoConfig::restore`].
+    ///
+    /// This is useful for tools that temporarily override the configuration.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct SavedConfig(GizmoConfigSnapshot);
+    ///
+    /// fn isolate_aabbs(mut commands: Commands, mut config: ResMut<GizmoConfig>) {
+    ///     commands.insert_resource(SavedConfig(config.snapshot()));
+    ///     config.aabb.draw_all = true;
+    /// }
+    ///
+    /// fn restore(mut commands: Commands, mut config: ResMut<GizmoConfig>, saved: Res<SavedConfig>) {
+    ///     config.restore(saved.0.clone());
+    ///     commands.remove_resource::<SavedConfig>();
+    /// }
+    /// # bevy_ecs::system::assert_is_system(isolate_aabbs);
+    /// # bevy_ecs::system::assert_is_system(restore);
+    /// ```
+    pub fn snapshot(&self) -> GizmoConfigSnapshot {
+        GizmoConfigSnapshot(self.clone())
+    }
+
+    /// Restores the configuration to the state it was in when `snapshot` was taken.
+    pub fn restore(&mut self, snapshot: GizmoConfigSnapshot) {
+        *self = snapshot.0;
+    }
 }
 
+/// An opaque copy of a [`GizmoConfig`], created by [`GizmoConfig::snapshot`].
+#[derive(Clone)]
+pub struct GizmoConfigSnapshot(GizmoConfig);
+
 /// Configuration for drawing the [`Aabb`] component on entities.
 #[derive(Clone, Default, Reflect)]
+#[reflect(Default)]
 pub struct AabbGizmoConfig {
     /// Draws all bounding boxes in the scene when set to `true`.
     ///
@@ -220,6 +934,152 @@ pub struct AabbGizmoConfig {
     pub default_color: Option<Color>,
 }
 
+/// Configuration for drawing the bounding spheres used to cull entities.
+#[derive(Clone, Default, Reflect)]
+#[reflect(Default)]
+pub struct BoundingSphereGizmoConfig {
+    /// Draws all bounding spheres in the scene when set to `true`.
+    ///
+    /// To draw a specific entity's bounding sphere,
+    /// you can add the [`BoundingSphereGizmo`] component.
+    ///
+    /// Defaults to `false`.
+    pub draw_all: bool,
+    /// The default color for bounding sphere gizmos.
+    ///
+    /// A random color is chosen per sphere if `None`.
+    ///
+    /// Defaults to `None`.
+    pub default_color: Option<Color>,
+}
+
+/// Configuration for drawing the local axes of entities.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct AxesGizmoConfig {
+    /// Draws the axes of all entities with a [`GlobalTransform`] when set to `true`.
+    ///
+    /// To draw the axes of a specific entity, you can add the [`AxesGizmo`] component.
+    ///
+    /// Defaults to `false`.
+    pub draw_all: bool,
+    /// The default length of the axes before they are scaled by the entity's transform.
+    ///
+    /// Defaults to `1.0`.
+    pub default_length: f32,
+}
+
+impl Default for AxesGizmoConfig {
+    fn default() -> Self {
+        Self {
+            draw_all: false,
+            default_length: 1.,
+        }
+    }
+}
+
+/// Configuration for resolving the number of segments of circles, arcs and spheres
+/// from their size on screen.
+///
+/// This only applies to shapes drawn without setting their number of segments,
+/// which is resolved at the end of the frame from the largest size they have on any active camera.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct AdaptiveSegmentsConfig {
+    /// Whether to resolve segment counts from the size on screen.
+    ///
+    /// Full circles have 32 segments and arcs a proportional amount when this is `false`.
+    ///
+    /// Defaults to `false`.
+    pub enabled: bool,
+    /// The number of segments of a full circle, however small it appears.
+    ///
+    /// Defaults to `8`.
+    pub min_segments: usize,
+    /// The number of segments of a full circle, however large it appears.
+    ///
+    /// Defaults to `128`.
+    pub max_segments: usize,
+    /// The targeted length of a segment on screen in pixels.
+    ///
+    /// Defaults to `10.0`.
+    pub segment_length: f32,
+}
+
+impl Default for AdaptiveSegmentsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_segments: 8,
+            max_segments: 128,
+            segment_length: 10.,
+        }
+    }
+}
+
+/// Configuration for the level of detail of circles, arcs and spheres,
+/// so that scenes with thousands of shapes stay usable when most of them are far away.
+///
+/// This only applies to shapes drawn without setting their number of segments.
+/// Their number of segments, as resolved by the [`AdaptiveSegmentsConfig`],
+/// is scaled by the detail at their distance to the closest active camera.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct LodConfig {
+    /// Whether to reduce the number of segments with the distance to the camera.
+    ///
+    /// Defaults to `false`.
+    pub enabled: bool,
+    /// The detail at a distance to the camera, as `(distance, detail)` pairs sorted by distance.
+    ///
+    /// The detail is interpolated linearly between the pairs and is constant beyond the first and last one.
+    /// Full circles keep at least 3 segments, however low the detail is.
+    ///
+    /// Defaults to full detail up to a distance of `20.0`, going down to a quarter at `100.0`.
+    pub curve: Vec<(f32, f32)>,
+}
+
+impl Default for LodConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            curve: vec![(20., 1.), (100., 0.25)],
+        }
+    }
+}
+
+impl LodConfig {
+    /// The factor of the number of segments of a shape at `distance` from the camera.
+    ///
+    /// This is `1.0` if the curve is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::LodConfig;
+    /// let lod = LodConfig {
+    ///     enabled: true,
+    ///     curve: vec![(10., 1.), (20., 0.5)],
+    /// };
+    /// assert_eq!(lod.detail(5.), 1.);
+    /// assert_eq!(lod.detail(15.), 0.75);
+    /// assert_eq!(lod.detail(30.), 0.5);
+    /// ```
+    pub fn detail(&self, distance: f32) -> f32 {
+        let next = self.curve.partition_point(|&(point, _)| point < distance);
+        match (
+            next.checked_sub(1).map(|prev| self.curve[prev]),
+            self.curve.get(next),
+        ) {
+            (Some((start, start_detail)), Some(&(end, end_detail))) => {
+                let t = (distance - start) / (end - start);
+                start_detail + (end_detail - start_detail) * t
+            }
+            (Some((_, detail)), None) | (None, Some(&(_, detail))) => detail,
+            (None, None) => 1.,
+        }
+    }
+}
+
 /// Add this [`Component`] to an entity to draw its [`Aabb`] component.
 #[derive(Component, Reflect, Default, Debug)]
 #[reflect(Component, Default)]
@@ -228,19 +1088,79 @@ pub struct AabbGizmo {
     ///
     /// The default color from the [`GizmoConfig`] resource is used if `None`,
     pub color: Option<Color>,
+    /// Which boxes are drawn for the entity and its descendants.
+    ///
+    /// Defaults to [`AabbGizmoHierarchy::Own`].
+    pub hierarchy: AabbGizmoHierarchy,
+}
+
+/// Which boxes an [`AabbGizmo`] draws for the entity and its descendants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Default)]
+pub enum AabbGizmoHierarchy {
+    /// Draw the entity's own [`Aabb`].
+    #[default]
+    Own,
+    /// Draw the box enclosing the [`Aabb`]s of the entity and all of its descendants,
+    /// aligned to the world axes.
+    ///
+    /// This is drawn even if the entity has no [`Aabb`] of its own.
+    Merged,
+    /// Draw both the entity's own [`Aabb`] and the merged box of its descendants.
+    OwnAndMerged,
+}
+
+/// Add this [`Component`] to an entity to draw the bounding sphere it is frustum culled with.
+///
+/// The sphere encloses the entity's [`Aabb`] component
+/// after it is transformed by its [`GlobalTransform`].
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct BoundingSphereGizmo {
+    /// The color of the sphere.
+    ///
+    /// The default color from the [`GizmoConfig`] resource is used if `None`,
+    pub color: Option<Color>,
+}
+
+/// Add this [`Component`] to an entity to draw its local X, Y and Z axes, see [`Gizmos::axes`].
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct AxesGizmo {
+    /// The length of the axes before they are scaled by the entity's transform.
+    ///
+    /// The default length from the [`GizmoConfig`] resource is used if `None`.
+    pub length: Option<f32>,
 }
 
 fn draw_aabbs(
-    query: Query<(Entity, &Aabb, &GlobalTransform, &AabbGizmo)>,
+    query: Query<(Entity, &AabbGizmo)>,
+    aabbs: Query<(&Aabb, &GlobalTransform)>,
+    children: Query<&Children>,
     config: Res<GizmoConfig>,
     mut gizmos: Gizmos,
 ) {
-    for (entity, &aabb, &transform, gizmo) in &query {
+    for (entity, gizmo) in &query {
         let color = gizmo
             .color
             .or(config.aabb.default_color)
             .unwrap_or_else(|| color_from_entity(entity));
-        gizmos.cuboid(aabb_transform(aabb, transform), color);
+
+        let (own, merged) = match gizmo.hierarchy {
+            AabbGizmoHierarchy::Own => (true, false),
+            AabbGizmoHierarchy::Merged => (false, true),
+            AabbGizmoHierarchy::OwnAndMerged => (true, true),
+        };
+        if own {
+            if let Ok((&aabb, &transform)) = aabbs.get(entity) {
+                gizmos.cuboid(aabb_transform(aabb, transform), color);
+            }
+        }
+        if merged {
+            if let Some(merged_transform) = merged_aabb_transform(entity, &aabbs, &children) {
+                gizmos.cuboid(merged_transform, color);
+            }
+        }
     }
 }
 
@@ -258,9 +1178,62 @@ fn draw_all_aabbs(
     }
 }
 
+fn draw_bounding_spheres(
+    query: Query<(Entity, &Aabb, &GlobalTransform, &BoundingSphereGizmo)>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    for (entity, aabb, transform, gizmo) in &query {
+        let color = gizmo
+            .color
+            .or(config.bounding_sphere.default_color)
+            .unwrap_or_else(|| color_from_entity(entity));
+        let (center, radius) = bounding_sphere(aabb, transform);
+        gizmos.sphere(center, Quat::IDENTITY, radius, color);
+    }
+}
+
+fn draw_all_bounding_spheres(
+    query: Query<(Entity, &Aabb, &GlobalTransform), Without<BoundingSphereGizmo>>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    for (entity, aabb, transform) in &query {
+        let color = config
+            .bounding_sphere
+            .default_color
+            .unwrap_or_else(|| color_from_entity(entity));
+        let (center, radius) = bounding_sphere(aabb, transform);
+        gizmos.sphere(center, Quat::IDENTITY, radius, color);
+    }
+}
+
+fn draw_axes(
+    query: Query<(&GlobalTransform, &AxesGizmo)>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    for (&transform, gizmo) in &query {
+        let length = gizmo.length.unwrap_or(config.axes.default_length);
+        gizmos.axes(transform, length);
+    }
+}
+
+fn draw_all_axes(
+    query: Query<&GlobalTransform, Without<AxesGizmo>>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    for &transform in &query {
+        gizmos.axes(transform, config.axes.default_length);
+    }
+}
+
 fn color_from_entity(entity: Entity) -> Color {
-    let index = entity.index();
+    color_from_index(entity.index())
+}
 
+fn color_from_index(index: u32) -> Color {
     // from https://extremelearning.com.au/unreasonable-effectiveness-of-quasirandom-sequences/
     //
     // See https://en.wikipedia.org/wiki/Low-discrepancy_sequence
@@ -281,62 +1254,178 @@ fn aabb_transform(aabb: Aabb, transform: GlobalTransform) -> GlobalTransform {
         )
 }
 
-#[derive(Resource, Default)]
-struct LineGizmoHandles {
-    list: Option<Handle<LineGizmo>>,
-    strip: Option<Handle<LineGizmo>>,
+/// The transform of the unit cube onto the world-aligned box enclosing the [`Aabb`]s
+/// of `entity` and all of its descendants, if any of them has one.
+fn merged_aabb_transform(
+    entity: Entity,
+    aabbs: &Query<(&Aabb, &GlobalTransform)>,
+    children: &Query<&Children>,
+) -> Option<Transform> {
+    let (min, max) = std::iter::once(entity)
+        .chain(children.iter_descendants(entity))
+        .filter_map(|entity| aabbs.get(entity).ok())
+        .map(|(aabb, transform)| world_aabb(aabb, transform))
+        .reduce(|(min, max), (aabb_min, aabb_max)| (min.min(aabb_min), max.max(aabb_max)))?;
+    Some(Transform::from_translation((min + max) / 2.).with_scale(max - min))
 }
 
-fn update_gizmo_meshes(
-    mut line_gizmos: ResMut<Assets<LineGizmo>>,
-    mut handles: ResMut<LineGizmoHandles>,
-    mut storage: ResMut<GizmoStorage>,
-) {
-    if storage.list_positions.is_empty() {
-        handles.list = None;
-    } else if let Some(handle) = handles.list.as_ref() {
-        let list = line_gizmos.get_mut(handle).unwrap();
+/// The minimum and maximum corners of the world-aligned box enclosing `aabb`
+/// after it is transformed by `transform`.
+fn world_aabb(aabb: &Aabb, transform: &GlobalTransform) -> (Vec3, Vec3) {
+    let affine = transform.affine();
+    let center = affine.transform_point3a(aabb.center);
+    let half_extents = affine.matrix3.x_axis.abs() * aabb.half_extents.x
+        + affine.matrix3.y_axis.abs() * aabb.half_extents.y
+        + affine.matrix3.z_axis.abs() * aabb.half_extents.z;
+    (
+        (center - half_extents).into(),
+        (center + half_extents).into(),
+    )
+}
 
-        list.positions = mem::take(&mut storage.list_positions);
-        list.colors = mem::take(&mut storage.list_colors);
-    } else {
-        let mut list = LineGizmo {
-            strip: false,
-            ..Default::default()
-        };
+/// The center and radius of the sphere `check_visibility` culls an entity with.
+fn bounding_sphere(aabb: &Aabb, transform: &GlobalTransform) -> (Vec3, f32) {
+    (
+        transform.affine().transform_point3a(aabb.center).into(),
+        transform.radius_vec3a(aabb.half_extents),
+    )
+}
 
-        list.positions = mem::take(&mut storage.list_positions);
-        list.colors = mem::take(&mut storage.list_colors);
+/// The line gizmos drawn this frame, moved into the render world by [`extract_line_gizmos`].
+///
+/// All immediate mode gizmos share these two buffers, one per topology,
+/// so they cost at most two draws per view regardless of how many systems draw them.
+#[derive(Resource)]
+struct LineGizmoFrame {
+    list: LineGizmo,
+    strip: LineGizmo,
+    views: Vec<LineGizmoView>,
+}
 
-        handles.list = Some(line_gizmos.add(list));
+impl Default for LineGizmoFrame {
+    fn default() -> Self {
+        Self {
+            list: LineGizmo::new(Vec::new(), Vec::new(), false),
+            strip: LineGizmo::new(Vec::new(), Vec::new(), true),
+            views: Vec::new(),
+        }
     }
+}
 
-    if storage.strip_positions.is_empty() {
-        handles.strip = None;
-    } else if let Some(handle) = handles.strip.as_ref() {
-        let strip = line_gizmos.get_mut(handle).unwrap();
-
-        strip.positions = mem::take(&mut storage.strip_positions);
-        strip.colors = mem::take(&mut storage.strip_colors);
-    } else {
-        let mut strip = LineGizmo {
-            strip: true,
-            ..Default::default()
-        };
-
-        strip.positions = mem::take(&mut storage.strip_positions);
-        strip.colors = mem::take(&mut storage.strip_colors);
-
-        handles.strip = Some(line_gizmos.add(strip));
-    }
+/// The vertices of the immediate mode line gizmos only drawn in the view of one camera,
+/// which come after the vertices drawn in all views.
+#[derive(Clone, Debug)]
+struct LineGizmoView {
+    camera: Entity,
+    list: Range<usize>,
+    strip: Range<usize>,
 }
 
-fn extract_gizmo_data(
-    mut commands: Commands,
-    handles: Extract<Res<LineGizmoHandles>>,
-    config: Extract<Res<GizmoConfig>>,
+fn update_gizmo_meshes(
+    config: Res<GizmoConfig>,
+    mut frame: ResMut<LineGizmoFrame>,
+    mut storage: ResMut<GizmoStorage>,
+    mut seen_lines: Local<HashMap<u64, Range<usize>>>,
+    mut sort_scratch: Local<GizmoBuffer>,
+    mut gradient_scratch: Local<GradientScratch>,
 ) {
-    if config.is_changed() {
+    if mem::take(&mut storage.truncated) {
+        let now = Instant::now();
+        let warn = storage.last_truncation_warning.map_or(true, |last| {
+            now.duration_since(last) >= Duration::from_secs(1)
+        });
+        if warn {
+            warn!(
+                "Gizmos exceeded the budget of {} vertices set in `GizmoConfig::max_vertices` or `GizmoConfig::max_memory`, some gizmos were dropped",
+                config.vertex_budget().unwrap_or_default()
+            );
+            storage.last_truncation_warning = Some(now);
+        }
+    }
+
+    storage.sort_chunks(&mut sort_scratch);
+    if config.deduplicate {
+        storage.deduplicate(&mut seen_lines);
+    }
+    gradients::interpolate_gradients(
+        &mut storage,
+        config.gradient_color_space,
+        &mut gradient_scratch,
+    );
+
+    // Swap instead of taking, so that the storage reuses the allocations
+    // handed back by the render world and no memory is allocated in steady state.
+    let LineGizmoFrame { list, strip, views } = &mut *frame;
+    let storage = &mut *storage;
+    mem::swap(&mut list.positions, &mut storage.list_positions);
+    mem::swap(&mut list.colors, &mut storage.list_colors);
+    mem::swap(&mut strip.positions, &mut storage.strip_positions);
+    mem::swap(&mut strip.colors, &mut storage.strip_colors);
+
+    // The views of cameras that didn't draw anything this frame are dropped,
+    // the others are kept to reuse their allocations.
+    views.clear();
+    storage.views.retain(|(_, view)| view.vertex_count() > 0);
+    for (camera, view) in &mut storage.views {
+        if config.deduplicate {
+            view.deduplicate(&mut seen_lines);
+        }
+        gradients::interpolate_gradients(view, config.gradient_color_space, &mut gradient_scratch);
+        views.push(LineGizmoView {
+            camera: *camera,
+            list: list.positions.len()..list.positions.len() + view.list_positions.len(),
+            strip: strip.positions.len()..strip.positions.len() + view.strip_positions.len(),
+        });
+        list.positions.append(&mut view.list_positions);
+        list.colors.append(&mut view.list_colors);
+        strip.positions.append(&mut view.strip_positions);
+        strip.colors.append(&mut view.strip_colors);
+        view.clear();
+    }
+
+    storage.list_positions.clear();
+    storage.list_colors.clear();
+    storage.strip_positions.clear();
+    storage.strip_colors.clear();
+    storage.tags.clear();
+    storage.usage.clear();
+    storage.frame_sent = true;
+}
+
+/// The main world entities the immediate mode line gizmos are extracted to.
+///
+/// This way the same render world entities are used every frame instead of spawning new ones.
+#[derive(Resource)]
+struct LineGizmoEntities {
+    list: Entity,
+    strip: Entity,
+}
+
+impl FromWorld for LineGizmoEntities {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            list: world.spawn_empty().id(),
+            strip: world.spawn_empty().id(),
+        }
+    }
+}
+
+fn extract_gizmo_data(
+    mut commands: Commands,
+    mut previous_len: Local<usize>,
+    frame: Extract<Res<LineGizmoFrame>>,
+    entities: Extract<Res<LineGizmoEntities>>,
+    retained_line_gizmos: Extract<
+        Query<(
+            Entity,
+            &Handle<LineGizmo>,
+            Option<&InheritedVisibility>,
+            Option<&GizmoInScreenshots>,
+        )>,
+    >,
+    config: Extract<Res<GizmoConfig>>,
+) {
+    if config.is_changed() {
         commands.insert_resource(config.clone());
     }
 
@@ -344,16 +1433,137 @@ fn extract_gizmo_data(
         return;
     }
 
-    for handle in [&handles.list, &handles.strip].into_iter().flatten() {
-        commands.spawn((
-            LineGizmoUniform {
-                line_width: config.line_width,
-                depth_bias: config.depth_bias,
-                #[cfg(feature = "webgl")]
-                _padding: Default::default(),
-            },
-            handle.clone_weak(),
-        ));
+    let uniform = LineGizmoUniform {
+        line_width: validated_line_width(config.line_width),
+        depth_bias: config.depth_bias,
+        _padding: Default::default(),
+    };
+
+    let in_screenshots = GizmoInScreenshots(config.in_screenshots);
+    let mut values = Vec::with_capacity(*previous_len);
+
+    let handles = [
+        (&frame.list, entities.list, LIST_LINE_GIZMO_HANDLE),
+        (&frame.strip, entities.strip, STRIP_LINE_GIZMO_HANDLE),
+    ];
+    for (line_gizmo, entity, handle) in handles {
+        if line_gizmo.positions.is_empty() {
+            continue;
+        }
+
+        values.push((entity, (uniform, handle, in_screenshots)));
+    }
+
+    for (entity, handle, visibility, screenshots) in &retained_line_gizmos {
+        if visibility.is_some_and(|visibility| !visibility.get()) {
+            continue;
+        }
+
+        let screenshots = screenshots.copied().unwrap_or(in_screenshots);
+        values.push((entity, (uniform, handle.clone_weak(), screenshots)));
+    }
+
+    *previous_len = values.len();
+    commands.insert_or_spawn_batch(values);
+}
+
+/// The retained [`LineGizmo`] assets that were added, modified or removed since the last frame.
+#[derive(Resource, Default)]
+struct ExtractedRetainedLineGizmos {
+    /// The vertices to write and the revision of the line gizmo's changes, see [`LineGizmoChanges`].
+    extracted: Vec<(AssetId<LineGizmo>, u64, LineGizmoUpdate)>,
+    removed: Vec<AssetId<LineGizmo>>,
+}
+
+/// The vertices of a retained [`LineGizmo`] to write to its [`GpuLineGizmo`].
+enum LineGizmoUpdate {
+    /// All vertices, for new line gizmos and those that can't be updated in place,
+    /// see [`GpuLineGizmo::can_update`].
+    All(LineGizmo),
+    /// The vertices of the chunks whose vertices changed.
+    Ranges(Vec<LineGizmoRange>),
+}
+
+/// Vertices of a line gizmo and the [`LineGizmoChunk`]s they update.
+struct LineGizmoRange {
+    chunks: Range<usize>,
+    /// The index of the first vertex in `positions` and `colors`.
+    first: usize,
+    /// The positions of the vertices the chunks need, see [`line_gizmo_chunk_vertices`].
+    positions: Vec<[f32; 3]>,
+    colors: Vec<[f32; 4]>,
+}
+
+fn extract_retained_line_gizmos(
+    mut extracted: ResMut<ExtractedRetainedLineGizmos>,
+    gpu_line_gizmos: Res<GpuLineGizmos>,
+    mut events: Extract<EventReader<AssetEvent<LineGizmo>>>,
+    line_gizmos: Extract<Res<Assets<LineGizmo>>>,
+    config: Extract<Res<GizmoConfig>>,
+) {
+    let mut changed = HashSet::default();
+    for event in events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                changed.insert(id);
+            }
+            AssetEvent::Removed { id } => {
+                changed.remove(&id);
+                extracted.removed.push(id);
+            }
+            AssetEvent::Unused { .. } | AssetEvent::LoadedWithDependencies { .. } => {}
+        }
+    }
+
+    for id in changed {
+        let Some(line_gizmo) = line_gizmos.get(id) else {
+            continue;
+        };
+        let LineGizmo {
+            positions,
+            colors,
+            strip,
+            changes,
+        } = line_gizmo;
+        let vertex_count = positions.len().min(colors.len());
+
+        let changed_chunks = gpu_line_gizmos
+            .0
+            .get(&id)
+            .filter(|gpu_line_gizmo| {
+                gpu_line_gizmo.can_update(
+                    *strip,
+                    vertex_count,
+                    config.hdr_colors,
+                    config.indexed_strips,
+                )
+            })
+            .and_then(|gpu_line_gizmo| changes.since(gpu_line_gizmo.revision))
+            .map(|changes| changed_line_gizmo_chunks(changes, *strip, vertex_count));
+
+        let update = match changed_chunks {
+            Some(changed_chunks) => LineGizmoUpdate::Ranges(
+                changed_chunks
+                    .into_iter()
+                    .map(|chunks| {
+                        let vertices =
+                            line_gizmo_chunk_vertices(chunks.clone(), *strip, vertex_count);
+                        LineGizmoRange {
+                            chunks,
+                            first: vertices.start,
+                            positions: positions[vertices.clone()].to_vec(),
+                            colors: colors[vertices].to_vec(),
+                        }
+                    })
+                    .collect(),
+            ),
+            None => LineGizmoUpdate::All(LineGizmo::new(
+                positions[..vertex_count].to_vec(),
+                colors[..vertex_count].to_vec(),
+                *strip,
+            )),
+        };
+        extracted.extracted.push((id, changes.revision, update));
     }
 }
 
@@ -361,67 +1571,871 @@ fn extract_gizmo_data(
 struct LineGizmoUniform {
     line_width: f32,
     depth_bias: f32,
-    /// WebGL2 structs must be 16 byte aligned.
-    #[cfg(feature = "webgl")]
+    /// WebGL2 structs must be 16 byte aligned,
+    /// the padding is there on all targets so that the layout never depends on features.
     _padding: bevy_math::Vec2,
 }
 
+/// The settings of the line gizmos that depend on the view they are drawn in.
+#[derive(Component, ShaderType, Clone, Copy)]
+struct LineGizmoViewUniform {
+    /// The scale factor of the view's render target, converting line widths to physical pixels.
+    scale_factor: f32,
+    /// WebGL2 structs must be 16 byte aligned.
+    _padding: Vec3,
+}
+
+fn extract_line_gizmo_views(mut commands: Commands, cameras: Extract<Query<(Entity, &Camera)>>) {
+    for (entity, camera) in &cameras {
+        if !camera.is_active {
+            continue;
+        }
+        commands.get_or_spawn(entity).insert(LineGizmoViewUniform {
+            scale_factor: camera.target_scaling_factor().unwrap_or(1.),
+            _padding: Vec3::ZERO,
+        });
+    }
+}
+
+/// Lines that are drawn every frame without being submitted again, unlike [`Gizmos`].
+///
+/// Add a [`Handle<LineGizmo>`] to an entity to draw it with the settings of the [`GizmoConfig`].
+/// Its vertices are only uploaded to the GPU when the asset is modified, which makes it a good fit
+/// for mostly static debug geometry such as level bounds.
+///
+/// Vertices changed with [`LineGizmo::vertices_mut`] or marked with [`LineGizmo::mark_changed`]
+/// are uploaded on their own, so changing a few vertices of a large line gizmo stays cheap.
+/// Any other modification uploads all vertices, as does changing their number or the topology.
+///
+/// The positions are in world space. The lines are hidden if the entity has an
+/// [`InheritedVisibility`] that is not visible.
+///
+/// Changes to [`GizmoConfig::hdr_colors`] and [`GizmoConfig::indexed_strips`]
+/// only apply to a retained line gizmo once it is modified.
+///
+/// With the `serialize` feature, line gizmos can be saved and loaded with `serde`, such as
+/// to keep annotations of a level. Strips are split by NaN positions, which the format has to support,
+/// as RON does but JSON doesn't.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_asset::Assets;
+/// fn setup(mut commands: Commands, mut line_gizmos: ResMut<Assets<LineGizmo>>) {
+///     let level_bounds = line_gizmos.add(LineGizmo::new(
+///         vec![
+///             [-10., 0., -10.],
+///             [10., 0., -10.],
+///             [10., 0., 10.],
+///             [-10., 0., 10.],
+///             [-10., 0., -10.],
+///         ],
+///         vec![Color::RED.as_linear_rgba_f32(); 5],
+///         true,
+///     ));
+///     commands.spawn(level_bounds);
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// ```
 #[derive(Asset, Debug, Default, Clone, TypePath)]
-struct LineGizmo {
-    positions: Vec<[f32; 3]>,
-    colors: Vec<[f32; 4]>,
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineGizmo {
+    /// The positions of the vertices.
+    ///
+    /// Line-lists use each pair of positions as a line,
+    /// line-strips can be split into several strips with a position of [`f32::NAN`].
+    pub positions: Vec<[f32; 3]>,
+    /// The linear RGBA color of each vertex.
+    ///
+    /// This must have the same length as [`LineGizmo::positions`].
+    pub colors: Vec<[f32; 4]>,
     /// Whether this gizmo's topology is a line-strip or line-list
-    strip: bool,
+    pub strip: bool,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    changes: LineGizmoChanges,
+}
+
+impl LineGizmo {
+    /// Creates a line gizmo from the positions of its vertices and their linear RGBA colors.
+    pub fn new(positions: Vec<[f32; 3]>, colors: Vec<[f32; 4]>, strip: bool) -> Self {
+        Self {
+            positions,
+            colors,
+            strip,
+            changes: LineGizmoChanges::default(),
+        }
+    }
+
+    /// Returns the positions and colors of the vertices in `range` to change them,
+    /// so that only these vertices are uploaded to the GPU.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds of the positions or colors.
+    pub fn vertices_mut(&mut self, range: Range<usize>) -> (&mut [[f32; 3]], &mut [[f32; 4]]) {
+        self.changes.record(range.clone());
+        (&mut self.positions[range.clone()], &mut self.colors[range])
+    }
+
+    /// Marks the vertices in `range` as changed through the fields of this line gizmo,
+    /// so that only these vertices are uploaded to the GPU.
+    ///
+    /// Once vertices are marked, changes to other vertices are only uploaded if they are marked as well.
+    pub fn mark_changed(&mut self, range: Range<usize>) {
+        self.changes.record(range);
+    }
+}
+
+/// The most vertex ranges a [`LineGizmoChanges`] remembers.
+const MAX_LINE_GIZMO_CHANGES: usize = 1024;
+
+/// The vertex ranges of a [`LineGizmo`] that were marked as changed.
+///
+/// Instead of being cleared once uploaded, each change is numbered with a revision,
+/// and the GPU side remembers the revision it last wrote.
+#[derive(Debug, Default, Clone)]
+struct LineGizmoChanges {
+    /// The revision of the last change.
+    revision: u64,
+    /// The revision of the last change that was forgotten, to keep at most
+    /// [`MAX_LINE_GIZMO_CHANGES`] ranges.
+    forgotten: u64,
+    /// The changed ranges and the revisions of their last change, oldest first.
+    ranges: VecDeque<(u64, Range<usize>)>,
+}
+
+impl LineGizmoChanges {
+    fn record(&mut self, range: Range<usize>) {
+        self.revision += 1;
+        if let Some((revision, last)) = self.ranges.back_mut() {
+            // Repeated changes to the same vertices, or to the ones right after them, are merged.
+            if range.start <= last.end && last.start <= range.end {
+                *revision = self.revision;
+                *last = last.start.min(range.start)..last.end.max(range.end);
+                return;
+            }
+        }
+        if self.ranges.len() == MAX_LINE_GIZMO_CHANGES {
+            if let Some((revision, _)) = self.ranges.pop_front() {
+                self.forgotten = revision;
+            }
+        }
+        self.ranges.push_back((self.revision, range));
+    }
+
+    /// The ranges changed after `revision`.
+    ///
+    /// Returns `None` if they are unknown, either because some were forgotten,
+    /// or because no change was marked since then, so the line gizmo was changed some other way.
+    fn since(&self, revision: u64) -> Option<impl Iterator<Item = Range<usize>> + '_> {
+        (self.forgotten <= revision && revision < self.revision).then(|| {
+            self.ranges
+                .iter()
+                .filter(move |(changed, _)| *changed > revision)
+                .map(|(_, range)| range.clone())
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
 struct GpuLineGizmo {
     position_buffer: Buffer,
     color_buffer: Buffer,
+    /// Either [`VertexFormat::Unorm8x4`] or [`VertexFormat::Float32x4`], see [`GizmoConfig::hdr_colors`].
+    color_format: VertexFormat,
     vertex_count: u32,
     strip: bool,
+    /// Scratch space for quantizing colors, kept around to avoid allocating every frame.
+    packed_colors: Vec<[u8; 4]>,
+    /// Indices into the strip's vertices, see [`strip_indices`].
+    ///
+    /// Only set when this strip is drawn with [`GizmoConfig::indexed_strips`].
+    index_buffer: Option<Buffer>,
+    index_count: u32,
+    /// Scratch space for building indices, kept around to avoid allocating every frame.
+    indices: Vec<u32>,
+    /// Binds the position and color buffers as storage buffers to pull the vertices from.
+    ///
+    /// Not set on devices that [don't support vertex pulling](supports_vertex_pulling),
+    /// or if either buffer is too large to be bound as a storage buffer.
+    /// The vertices are then read from vertex buffers instead.
+    storage_bind_group: Option<BindGroup>,
+    /// One chunk for every [`LINE_GIZMO_CHUNK_SEGMENTS`] segments.
+    chunks: Vec<LineGizmoChunk>,
+    /// The segments only drawn in the view of a camera, which come after the segments drawn
+    /// in all views, see [`LineGizmoView`].
+    views: Vec<(Entity, Range<u32>)>,
+    /// The revision of the changes of the retained line gizmo that was last written,
+    /// see [`LineGizmoChanges`].
+    revision: u64,
 }
 
-impl RenderAsset for LineGizmo {
-    type PreparedAsset = GpuLineGizmo;
-    type Param = SRes<RenderDevice>;
-
-    fn persistence_policy(&self) -> RenderAssetPersistencePolicy {
-        RenderAssetPersistencePolicy::Keep
+/// The smallest size in bytes of a [`GpuLineGizmo`] vertex buffer.
+const MIN_LINE_GIZMO_BUFFER_SIZE: usize = 4096;
+
+impl GpuLineGizmo {
+    /// Creates a line gizmo without any vertices.
+    fn new(render_device: &RenderDevice) -> Self {
+        let vertex_usage = line_gizmo_vertex_usage(render_device);
+        GpuLineGizmo {
+            position_buffer: create_line_gizmo_buffer(
+                render_device,
+                "LineGizmo Position Buffer",
+                0,
+                vertex_usage,
+            ),
+            color_buffer: create_line_gizmo_buffer(
+                render_device,
+                "LineGizmo Color Buffer",
+                0,
+                vertex_usage,
+            ),
+            color_format: VertexFormat::Float32x4,
+            vertex_count: 0,
+            strip: false,
+            packed_colors: Vec::new(),
+            index_buffer: None,
+            index_count: 0,
+            indices: Vec::new(),
+            storage_bind_group: None,
+            chunks: Vec::new(),
+            views: Vec::new(),
+            revision: 0,
+        }
     }
 
-    fn prepare_asset(
-        self,
-        render_device: &mut SystemParamItem<Self::Param>,
-    ) -> Result<Self::PreparedAsset, PrepareAssetError<Self>> {
-        let position_buffer_data = cast_slice(&self.positions);
-        let position_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
-            usage: BufferUsages::VERTEX,
-            label: Some("LineGizmo Position Buffer"),
-            contents: position_buffer_data,
-        });
+    /// Uploads all vertices of `line_gizmo` into the existing buffers,
+    /// only reallocating them if they are too small.
+    ///
+    /// Unless `hdr_colors` is set, colors are quantized to 8 bits per channel to cut their size by 4.
+    ///
+    /// The vertices are pulled from storage buffers if `storage_layout` is given,
+    /// and strips are drawn indexed if `indexed_strips` is set as well.
+    fn write(
+        &mut self,
+        line_gizmo: &LineGizmo,
+        hdr_colors: bool,
+        indexed_strips: bool,
+        storage_layout: Option<&BindGroupLayout>,
+        render_device: &RenderDevice,
+        render_queue: &RenderQueue,
+    ) {
+        let vertex_usage = line_gizmo_vertex_usage(render_device);
+        let vertex_count = line_gizmo.positions.len();
+        self.color_format = line_gizmo_color_format(hdr_colors);
+
+        let positions_size = vertex_count * VertexFormat::Float32x3.size() as usize;
+        if (self.position_buffer.size() as usize) < positions_size {
+            self.position_buffer = create_line_gizmo_buffer(
+                render_device,
+                "LineGizmo Position Buffer",
+                positions_size,
+                vertex_usage,
+            );
+        }
+        let colors_size = vertex_count * self.color_format.size() as usize;
+        if (self.color_buffer.size() as usize) < colors_size {
+            self.color_buffer = create_line_gizmo_buffer(
+                render_device,
+                "LineGizmo Color Buffer",
+                colors_size,
+                vertex_usage,
+            );
+        }
 
-        let color_buffer_data = cast_slice(&self.colors);
-        let color_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
-            usage: BufferUsages::VERTEX,
-            label: Some("LineGizmo Color Buffer"),
-            contents: color_buffer_data,
+        self.vertex_count = vertex_count as u32;
+        self.strip = line_gizmo.strip;
+        let segment_count = line_gizmo_segment_count(line_gizmo.strip, vertex_count);
+        let chunk_count = segment_count.div_ceil(LINE_GIZMO_CHUNK_SEGMENTS);
+        self.chunks.resize(chunk_count, LineGizmoChunk::default());
+
+        self.storage_bind_group = storage_layout.and_then(|storage_layout| {
+            let (Some(positions_size), Some(colors_size)) = (
+                NonZeroU64::new(positions_size as u64),
+                NonZeroU64::new(colors_size as u64),
+            ) else {
+                return None;
+            };
+            // Colors can take more space than positions, so both bindings must fit the limit.
+            let binding_size = positions_size.max(colors_size).get();
+            if binding_size > render_device.limits().max_storage_buffer_binding_size as u64 {
+                return None;
+            }
+            Some(render_device.create_bind_group(
+                "LineGizmo storage bind group",
+                storage_layout,
+                &BindGroupEntries::sequential((
+                    BufferBinding {
+                        buffer: &self.position_buffer,
+                        offset: 0,
+                        size: Some(positions_size),
+                    },
+                    BufferBinding {
+                        buffer: &self.color_buffer,
+                        offset: 0,
+                        size: Some(colors_size),
+                    },
+                )),
+            ))
         });
 
-        Ok(GpuLineGizmo {
-            position_buffer,
-            color_buffer,
-            vertex_count: self.positions.len() as u32,
-            strip: self.strip,
+        self.index_count = 6 * segment_count as u32;
+        self.index_buffer = if self.is_indexed(indexed_strips) {
+            let indices_size = self.index_count as usize * mem::size_of::<u32>();
+            match self.index_buffer.take() {
+                Some(buffer) if buffer.size() as usize >= indices_size => Some(buffer),
+                _ => Some(create_line_gizmo_buffer(
+                    render_device,
+                    "LineGizmo Index Buffer",
+                    indices_size,
+                    BufferUsages::INDEX,
+                )),
+            }
+        } else {
+            None
+        };
+
+        self.write_range(
+            &line_gizmo.positions,
+            &line_gizmo.colors,
+            0,
+            0..chunk_count,
+            render_queue,
+        );
+    }
+
+    /// Whether this line gizmo is drawn with an index buffer, see [`GizmoConfig::indexed_strips`].
+    fn is_indexed(&self, indexed_strips: bool) -> bool {
+        self.strip && indexed_strips && self.storage_bind_group.is_some() && self.vertex_count > 1
+    }
+
+    /// Whether the vertices of a line gizmo can be written with [`GpuLineGizmo::write_range`],
+    /// which requires the same number of vertices, topology and settings as the last [`GpuLineGizmo::write`].
+    fn can_update(
+        &self,
+        strip: bool,
+        vertex_count: usize,
+        hdr_colors: bool,
+        indexed_strips: bool,
+    ) -> bool {
+        self.vertex_count as usize == vertex_count
+            && self.strip == strip
+            && self.color_format == line_gizmo_color_format(hdr_colors)
+            && self.index_buffer.is_some() == self.is_indexed(indexed_strips)
+    }
+
+    /// Writes the vertices starting at vertex `first` to the buffers,
+    /// and updates the [`LineGizmoChunk`]s in `chunks` and their indices.
+    ///
+    /// `positions` must hold the vertices these chunks need, see [`line_gizmo_chunk_vertices`].
+    fn write_range(
+        &mut self,
+        positions: &[[f32; 3]],
+        colors: &[[f32; 4]],
+        first: usize,
+        chunks: Range<usize>,
+        render_queue: &RenderQueue,
+    ) {
+        if !positions.is_empty() {
+            render_queue.write_buffer(
+                &self.position_buffer,
+                first as u64 * VertexFormat::Float32x3.size(),
+                cast_slice(positions),
+            );
+        }
+
+        let colors_data: &[u8] = if self.color_format == VertexFormat::Float32x4 {
+            cast_slice(colors)
+        } else {
+            self.packed_colors.clear();
+            self.packed_colors
+                .extend(colors.iter().map(|color| color.map(pack_unorm8)));
+            cast_slice(&self.packed_colors)
+        };
+        if !colors_data.is_empty() {
+            render_queue.write_buffer(
+                &self.color_buffer,
+                first as u64 * self.color_format.size(),
+                colors_data,
+            );
+        }
+
+        let segment_count = line_gizmo_segment_count(self.strip, self.vertex_count as usize);
+        let segments = |chunk: usize| {
+            usize::min(chunk * LINE_GIZMO_CHUNK_SEGMENTS, segment_count)
+                ..usize::min((chunk + 1) * LINE_GIZMO_CHUNK_SEGMENTS, segment_count)
+        };
+        for chunk in chunks.clone() {
+            let segments = segments(chunk);
+            let points = if self.strip {
+                segments.start..segments.end + 1
+            } else {
+                2 * segments.start..2 * segments.end
+            };
+            self.chunks[chunk] = LineGizmoChunk {
+                aabb: line_gizmo_aabb(&positions[points.start - first..points.end - first]),
+                segments: segments.start as u32..segments.end as u32,
+            };
+        }
+
+        let Some(index_buffer) = &self.index_buffer else {
+            return;
+        };
+        let segments = segments(chunks.start).start..segments(chunks.end).start;
+        strip_indices(positions, first, segments.clone(), &mut self.indices);
+        if !self.indices.is_empty() {
+            render_queue.write_buffer(
+                index_buffer,
+                (6 * segments.start * mem::size_of::<u32>()) as u64,
+                cast_slice(&self.indices),
+            );
+        }
+    }
+
+    /// How the vertex shader of this line gizmo gets its vertices.
+    fn vertex_input(&self) -> LineGizmoVertexInput {
+        match (&self.storage_bind_group, &self.index_buffer) {
+            (Some(_), Some(_)) => LineGizmoVertexInput::Indexed,
+            (Some(_), None) => LineGizmoVertexInput::Pulled,
+            (None, _) => LineGizmoVertexInput::VertexBuffers,
+        }
+    }
+}
+
+/// How the vertex shader of a line gizmo pipeline gets its vertices, see [`line_gizmo_vertex_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum LineGizmoVertexInput {
+    /// Each segment is an instance reading its two points from vertex buffers.
+    ///
+    /// This is the fallback for devices without storage buffers in vertex shaders, such as WebGL2.
+    VertexBuffers,
+    /// Each segment is an instance pulling its two points from the storage buffers in bind group 2.
+    Pulled,
+    /// The vertices of a strip are pulled from the storage buffers in bind group 2
+    /// by an index buffer, see [`strip_indices`].
+    Indexed,
+}
+
+/// Builds the indices of the `segments` of an indexed line strip,
+/// whose `positions` start at the point `first`.
+///
+/// Each point of the strip has two vertices, one on either side of the line,
+/// which are shared by the segments before and after it.
+/// Segments touching a NaN separator are degenerate, so that the indices of every segment
+/// are at the same place and can be written on their own.
+///
+/// The vertex index of point `i` is `8 * i + side + 2 * has_prev + 4 * has_next`,
+/// which lets the shader know whether it's at the end of a strip without reading the neighbors.
+fn strip_indices(
+    positions: &[[f32; 3]],
+    first: usize,
+    segments: Range<usize>,
+    indices: &mut Vec<u32>,
+) {
+    let is_valid = |index: usize| {
+        index
+            .checked_sub(first)
+            .and_then(|index| positions.get(index))
+            .is_some_and(is_valid_position)
+    };
+    let vertex = |index: usize| {
+        let has_prev = index > 0 && is_valid(index - 1);
+        let has_next = is_valid(index + 1);
+        8 * index as u32 + 2 * has_prev as u32 + 4 * has_next as u32
+    };
+
+    indices.clear();
+    for index in segments {
+        let a = vertex(index);
+        if !is_valid(index) || !is_valid(index + 1) {
+            indices.extend([a; 6]);
+            continue;
+        }
+
+        let b = vertex(index + 1);
+        indices.extend([a, a + 1, b + 1, a, b + 1, b]);
+    }
+}
+
+fn is_valid_position(position: &[f32; 3]) -> bool {
+    !position.iter().any(|value| value.is_nan())
+}
+
+/// The number of segments in a [`LineGizmoChunk`].
+const LINE_GIZMO_CHUNK_SEGMENTS: usize = 1024;
+
+/// A range of consecutive segments of a [`GpuLineGizmo`] that is frustum culled as a whole.
+///
+/// Lines drawn one after another are usually close to each other,
+/// so this skips most of the vertices of large scenes that are out of view.
+#[derive(Debug, Clone, Default)]
+struct LineGizmoChunk {
+    /// The bounds of the chunk's valid positions, `None` if it has none.
+    aabb: Option<Aabb>,
+    /// The segments of this chunk, which are drawn by the instances of the same range,
+    /// or by six indices each if the gizmo is an indexed strip, see [`strip_indices`].
+    segments: Range<u32>,
+}
+
+fn line_gizmo_segment_count(strip: bool, vertex_count: usize) -> usize {
+    if strip {
+        vertex_count.saturating_sub(1)
+    } else {
+        vertex_count / 2
+    }
+}
+
+fn line_gizmo_aabb(points: &[[f32; 3]]) -> Option<Aabb> {
+    let mut min = Vec3::splat(f32::MAX);
+    let mut max = Vec3::splat(f32::MIN);
+    for &point in points.iter().filter(|point| is_valid_position(point)) {
+        min = min.min(point.into());
+        max = max.max(point.into());
+    }
+    (!min.cmpgt(max).any()).then(|| Aabb::from_min_max(min, max))
+}
+
+/// The [`LineGizmoChunk`]s to update after the vertices in `changes` changed,
+/// merged into as few ranges as possible.
+fn changed_line_gizmo_chunks(
+    changes: impl Iterator<Item = Range<usize>>,
+    strip: bool,
+    vertex_count: usize,
+) -> Vec<Range<usize>> {
+    let segment_count = line_gizmo_segment_count(strip, vertex_count);
+    let mut chunks: Vec<Range<usize>> = changes
+        .filter_map(|changed| {
+            let end = changed.end.min(vertex_count);
+            // The indices of a strip's segment depend on the points next to it, see `strip_indices`.
+            let segments = if strip {
+                changed.start.saturating_sub(2)..(end + 1).min(segment_count)
+            } else {
+                changed.start / 2..end.div_ceil(2).min(segment_count)
+            };
+            (segments.start < segments.end).then(|| {
+                segments.start / LINE_GIZMO_CHUNK_SEGMENTS
+                    ..segments.end.div_ceil(LINE_GIZMO_CHUNK_SEGMENTS)
+            })
         })
+        .collect();
+
+    chunks.sort_unstable_by_key(|chunks| chunks.start);
+    chunks.dedup_by(|next, previous| {
+        let merge = next.start <= previous.end;
+        if merge {
+            previous.end = previous.end.max(next.end);
+        }
+        merge
+    });
+    chunks
+}
+
+/// The vertices needed to update the [`LineGizmoChunk`]s in `chunks`.
+///
+/// Strips also need the points right before and after the chunks, see [`strip_indices`].
+fn line_gizmo_chunk_vertices(
+    chunks: Range<usize>,
+    strip: bool,
+    vertex_count: usize,
+) -> Range<usize> {
+    let segment_count = line_gizmo_segment_count(strip, vertex_count);
+    let start = usize::min(chunks.start * LINE_GIZMO_CHUNK_SEGMENTS, segment_count);
+    let end = usize::min(chunks.end * LINE_GIZMO_CHUNK_SEGMENTS, segment_count);
+    if strip {
+        start.saturating_sub(1)..usize::min(end + 2, vertex_count)
+    } else {
+        2 * start..2 * end
+    }
+}
+
+fn line_gizmo_color_format(hdr_colors: bool) -> VertexFormat {
+    if hdr_colors {
+        VertexFormat::Float32x4
+    } else {
+        VertexFormat::Unorm8x4
     }
 }
 
+fn pack_unorm8(value: f32) -> u8 {
+    // NaN, which is used to separate strips, saturates to 0.
+    (value.clamp(0., 1.) * 255.).round() as u8
+}
+
+fn create_line_gizmo_buffer(
+    render_device: &RenderDevice,
+    label: &str,
+    size: usize,
+    usage: BufferUsages,
+) -> Buffer {
+    render_device.create_buffer(&BufferDescriptor {
+        label: Some(label),
+        // Grow geometrically so that buffers are rarely reallocated when gizmo counts increase.
+        size: size.next_power_of_two().max(MIN_LINE_GIZMO_BUFFER_SIZE) as u64,
+        usage: usage | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    })
+}
+
+/// Whether the device can read the position and color buffers of a line gizmo in the vertex shader,
+/// which is required for vertex pulling and [`GizmoConfig::indexed_strips`].
+fn supports_vertex_pulling(render_device: &RenderDevice) -> bool {
+    render_device.limits().max_storage_buffers_per_shader_stage >= 2
+}
+
+fn line_gizmo_vertex_usage(render_device: &RenderDevice) -> BufferUsages {
+    if supports_vertex_pulling(render_device) {
+        BufferUsages::VERTEX | BufferUsages::STORAGE
+    } else {
+        BufferUsages::VERTEX
+    }
+}
+
+/// The GPU representations of all line gizmos.
+///
+/// Unlike [`RenderAssets`](bevy_render::render_asset::RenderAssets), the buffers of a
+/// [`GpuLineGizmo`] are kept alive and written to when its line gizmo is modified.
+#[derive(Resource, Default)]
+struct GpuLineGizmos(HashMap<AssetId<LineGizmo>, GpuLineGizmo>);
+
+/// The immediate mode line gizmos moved out of the main world this frame.
+///
+/// The buffers are cleared once uploaded and handed back to the main world on the next extraction,
+/// so that vertex data is moved between the worlds instead of being copied.
+#[derive(Resource)]
+struct ExtractedLineGizmos {
+    list: LineGizmo,
+    strip: LineGizmo,
+    views: Vec<LineGizmoView>,
+}
+
+impl Default for ExtractedLineGizmos {
+    fn default() -> Self {
+        let LineGizmoFrame { list, strip, views } = LineGizmoFrame::default();
+        Self { list, strip, views }
+    }
+}
+
+fn extract_line_gizmos(
+    mut extracted_line_gizmos: ResMut<ExtractedLineGizmos>,
+    mut main_world: ResMut<MainWorld>,
+) {
+    let mut frame = main_world.resource_mut::<LineGizmoFrame>();
+    let frame = &mut *frame;
+    let ExtractedLineGizmos { list, strip, views } = &mut *extracted_line_gizmos;
+
+    for (main, render) in [(&mut frame.list, list), (&mut frame.strip, strip)] {
+        mem::swap(&mut main.positions, &mut render.positions);
+        mem::swap(&mut main.colors, &mut render.colors);
+    }
+    views.clone_from(&frame.views);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prepare_line_gizmos(
+    mut extracted_line_gizmos: ResMut<ExtractedLineGizmos>,
+    mut retained_line_gizmos: ResMut<ExtractedRetainedLineGizmos>,
+    mut gpu_line_gizmos: ResMut<GpuLineGizmos>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    config: Res<GizmoConfig>,
+    storage_layout: Option<Res<LineGizmoStorageBindGroupLayout>>,
+) {
+    let ExtractedLineGizmos { list, strip, views } = &mut *extracted_line_gizmos;
+    let retained_line_gizmos = &mut *retained_line_gizmos;
+    let storage_layout = storage_layout
+        .as_ref()
+        .map(|storage_layout| &storage_layout.layout);
+    let render_device = &*render_device;
+    let render_queue = &*render_queue;
+
+    // The line gizmos to write, with the revision of the retained ones.
+    let mut updates: HashMap<AssetId<LineGizmo>, (&LineGizmoUpdate, u64)> = HashMap::default();
+    for (id, revision, update) in &retained_line_gizmos.extracted {
+        updates.insert(*id, (update, *revision));
+    }
+    let immediate = [
+        (LIST_LINE_GIZMO_HANDLE.id(), &*list),
+        (STRIP_LINE_GIZMO_HANDLE.id(), &*strip),
+    ];
+
+    for &id in updates.keys().chain(immediate.iter().map(|(id, _)| id)) {
+        gpu_line_gizmos
+            .0
+            .entry(id)
+            .or_insert_with(|| GpuLineGizmo::new(render_device));
+    }
+
+    // Packing colors, building indices and computing bounds takes a while for large line gizmos,
+    // so they are written in parallel while the render queue stages the uploads.
+    ComputeTaskPool::get().scope(|scope| {
+        for (id, gpu_line_gizmo) in &mut gpu_line_gizmos.0 {
+            let immediate = immediate
+                .iter()
+                .find_map(|(immediate, line_gizmo)| (immediate == id).then_some(*line_gizmo));
+            let update = updates.get(id).copied();
+            if immediate.is_none() && update.is_none() {
+                continue;
+            }
+            let config = &config;
+            scope.spawn(async move {
+                let write_all = |gpu_line_gizmo: &mut GpuLineGizmo, line_gizmo: &LineGizmo| {
+                    let _span =
+                        info_span!("write_line_gizmo", vertices = line_gizmo.positions.len())
+                            .entered();
+                    gpu_line_gizmo.write(
+                        line_gizmo,
+                        config.hdr_colors,
+                        config.indexed_strips,
+                        storage_layout,
+                        render_device,
+                        render_queue,
+                    );
+                };
+                match (immediate, update) {
+                    (Some(line_gizmo), _) => write_all(gpu_line_gizmo, line_gizmo),
+                    (None, Some((LineGizmoUpdate::All(line_gizmo), revision))) => {
+                        write_all(gpu_line_gizmo, line_gizmo);
+                        gpu_line_gizmo.revision = revision;
+                    }
+                    (None, Some((LineGizmoUpdate::Ranges(ranges), revision))) => {
+                        for range in ranges {
+                            gpu_line_gizmo.write_range(
+                                &range.positions,
+                                &range.colors,
+                                range.first,
+                                range.chunks.clone(),
+                                render_queue,
+                            );
+                        }
+                        gpu_line_gizmo.revision = revision;
+                    }
+                    (None, None) => {}
+                }
+            });
+        }
+    });
+    drop(updates);
+
+    for (id, line_gizmo) in [
+        (LIST_LINE_GIZMO_HANDLE.id(), &*list),
+        (STRIP_LINE_GIZMO_HANDLE.id(), &*strip),
+    ] {
+        let Some(gpu_line_gizmo) = gpu_line_gizmos.0.get_mut(&id) else {
+            continue;
+        };
+        gpu_line_gizmo.views.clear();
+        gpu_line_gizmo.views.extend(views.iter().map(|view| {
+            let segments = if line_gizmo.strip {
+                view.strip.start..view.strip.end.saturating_sub(1).max(view.strip.start)
+            } else {
+                view.list.start / 2..view.list.end / 2
+            };
+            (view.camera, segments.start as u32..segments.end as u32)
+        }));
+    }
+
+    for line_gizmo in [list, strip] {
+        line_gizmo.positions.clear();
+        line_gizmo.colors.clear();
+    }
+    retained_line_gizmos.extracted.clear();
+
+    for id in retained_line_gizmos.removed.drain(..) {
+        gpu_line_gizmos.0.remove(&id);
+    }
+}
+
+/// The draw ranges of the [`LineGizmoChunk`]s each view can see, keyed by view and line gizmo.
+///
+/// Segments only drawn in the view of another camera are left out.
+/// Line gizmos that weren't culled for a view are drawn entirely.
+#[derive(Resource, Default)]
+struct VisibleLineGizmoChunks(HashMap<(Entity, AssetId<LineGizmo>), Vec<Range<u32>>>);
+
+fn cull_line_gizmo_chunks(
+    views: Query<(Entity, &Frustum), With<ExtractedView>>,
+    gpu_line_gizmos: Res<GpuLineGizmos>,
+    mut visible_chunks: ResMut<VisibleLineGizmoChunks>,
+) {
+    visible_chunks.0.clear();
+
+    let mut shown: Vec<Range<u32>> = Vec::new();
+    for (view, frustum) in &views {
+        for (&id, line_gizmo) in &gpu_line_gizmos.0 {
+            // The segments drawn in all views, and those only drawn in this one.
+            shown.clear();
+            shown.push(
+                0..line_gizmo
+                    .views
+                    .first()
+                    .map_or(u32::MAX, |(_, segments)| segments.start),
+            );
+            shown.extend(
+                line_gizmo
+                    .views
+                    .iter()
+                    .filter(|(camera, _)| *camera == view)
+                    .map(|(_, segments)| segments.clone()),
+            );
+            let indexed = line_gizmo.vertex_input() == LineGizmoVertexInput::Indexed;
+
+            let mut ranges: Vec<Range<u32>> = Vec::new();
+            for chunk in &line_gizmo.chunks {
+                let Some(aabb) = &chunk.aabb else {
+                    continue;
+                };
+                if !frustum.intersects_obb(aabb, &Affine3A::IDENTITY, true, false) {
+                    continue;
+                }
+
+                for shown in &shown {
+                    let start = chunk.segments.start.max(shown.start);
+                    let end = chunk.segments.end.min(shown.end);
+                    let range = if indexed {
+                        6 * start..6 * end
+                    } else {
+                        start..end
+                    };
+                    // Merge adjacent chunks to issue as few draws as possible.
+                    match ranges.last_mut() {
+                        _ if range.is_empty() => {}
+                        Some(last) if last.end == range.start => last.end = range.end,
+                        _ => ranges.push(range),
+                    }
+                }
+            }
+            visible_chunks.0.insert((view, id), ranges);
+        }
+    }
+}
+
+/// Whether `view` can see any part of the line gizmo `id`.
+fn is_line_gizmo_visible(
+    visible_chunks: &VisibleLineGizmoChunks,
+    view: Entity,
+    id: AssetId<LineGizmo>,
+) -> bool {
+    visible_chunks
+        .0
+        .get(&(view, id))
+        .map_or(true, |ranges| !ranges.is_empty())
+}
+
 #[derive(Resource)]
 struct LineGizmoUniformBindgroupLayout {
     layout: BindGroupLayout,
 }
 
+/// The layout of [`GpuLineGizmo::storage_bind_group`].
+///
+/// Only present if the device [supports vertex pulling](supports_vertex_pulling).
+#[derive(Resource)]
+struct LineGizmoStorageBindGroupLayout {
+    layout: BindGroupLayout,
+}
+
 #[derive(Resource)]
 struct LineGizmoUniformBindgroup {
     bindgroup: BindGroup,
@@ -432,13 +2446,17 @@ fn prepare_line_gizmo_bind_group(
     line_gizmo_uniform_layout: Res<LineGizmoUniformBindgroupLayout>,
     render_device: Res<RenderDevice>,
     line_gizmo_uniforms: Res<ComponentUniforms<LineGizmoUniform>>,
+    line_gizmo_view_uniforms: Res<ComponentUniforms<LineGizmoViewUniform>>,
 ) {
-    if let Some(binding) = line_gizmo_uniforms.uniforms().binding() {
+    if let (Some(binding), Some(view_binding)) = (
+        line_gizmo_uniforms.uniforms().binding(),
+        line_gizmo_view_uniforms.uniforms().binding(),
+    ) {
         commands.insert_resource(LineGizmoUniformBindgroup {
             bindgroup: render_device.create_bind_group(
                 "LineGizmoUniform bindgroup",
                 &line_gizmo_uniform_layout.layout,
-                &BindGroupEntries::single(binding),
+                &BindGroupEntries::sequential((binding, view_binding)),
             ),
         });
     }
@@ -447,13 +2465,13 @@ fn prepare_line_gizmo_bind_group(
 struct SetLineGizmoBindGroup<const I: usize>;
 impl<const I: usize, P: PhaseItem> RenderCommand<P> for SetLineGizmoBindGroup<I> {
     type Param = SRes<LineGizmoUniformBindgroup>;
-    type ViewData = ();
+    type ViewData = Read<DynamicUniformIndex<LineGizmoViewUniform>>;
     type ItemData = Read<DynamicUniformIndex<LineGizmoUniform>>;
 
     #[inline]
     fn render<'w>(
         _item: &P,
-        _view: ROQueryItem<'w, Self::ViewData>,
+        view_uniform_index: ROQueryItem<'w, Self::ViewData>,
         uniform_index: ROQueryItem<'w, Self::ItemData>,
         bind_group: SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
@@ -461,7 +2479,7 @@ impl<const I: usize, P: PhaseItem> RenderCommand<P> for SetLineGizmoBindGroup<I>
         pass.set_bind_group(
             I,
             &bind_group.into_inner().bindgroup,
-            &[uniform_index.index()],
+            &[uniform_index.index(), view_uniform_index.index()],
         );
         RenderCommandResult::Success
     }
@@ -469,52 +2487,151 @@ impl<const I: usize, P: PhaseItem> RenderCommand<P> for SetLineGizmoBindGroup<I>
 
 struct DrawLineGizmo;
 impl<P: PhaseItem> RenderCommand<P> for DrawLineGizmo {
-    type Param = SRes<RenderAssets<LineGizmo>>;
-    type ViewData = ();
+    type Param = (
+        SRes<GpuLineGizmos>,
+        SRes<VisibleLineGizmoChunks>,
+        Option<SRes<GizmoRenderDiagnostics>>,
+    );
+    type ViewData = Entity;
     type ItemData = Read<Handle<LineGizmo>>;
 
     #[inline]
     fn render<'w>(
         _item: &P,
-        _view: ROQueryItem<'w, Self::ViewData>,
+        view: ROQueryItem<'w, Self::ViewData>,
         handle: ROQueryItem<'w, Self::ItemData>,
-        line_gizmos: SystemParamItem<'w, '_, Self::Param>,
+        (line_gizmos, visible_chunks, diagnostics): SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
-        let Some(line_gizmo) = line_gizmos.into_inner().get(handle) else {
+        let Some(line_gizmo) = line_gizmos.into_inner().0.get(&handle.id()) else {
             return RenderCommandResult::Failure;
         };
+        let visible_ranges = visible_chunks.into_inner().0.get(&(view, handle.id()));
 
         if line_gizmo.vertex_count < 2 {
             return RenderCommandResult::Success;
         }
 
         let instances = if line_gizmo.strip {
-            let item_size = VertexFormat::Float32x3.size();
-            let buffer_size = line_gizmo.position_buffer.size() - item_size;
-            pass.set_vertex_buffer(0, line_gizmo.position_buffer.slice(..buffer_size));
-            pass.set_vertex_buffer(1, line_gizmo.position_buffer.slice(item_size..));
-
-            let item_size = VertexFormat::Float32x4.size();
-            let buffer_size = line_gizmo.color_buffer.size() - item_size;
-            pass.set_vertex_buffer(2, line_gizmo.color_buffer.slice(..buffer_size));
-            pass.set_vertex_buffer(3, line_gizmo.color_buffer.slice(item_size..));
-
-            u32::max(line_gizmo.vertex_count, 1) - 1
+            line_gizmo.vertex_count - 1
         } else {
-            pass.set_vertex_buffer(0, line_gizmo.position_buffer.slice(..));
-            pass.set_vertex_buffer(1, line_gizmo.color_buffer.slice(..));
-
             line_gizmo.vertex_count / 2
         };
 
-        pass.draw(0..6, 0..instances);
+        match (&line_gizmo.storage_bind_group, &line_gizmo.index_buffer) {
+            (Some(storage_bind_group), Some(index_buffer)) => {
+                pass.set_bind_group(2, storage_bind_group, &[]);
+                pass.set_index_buffer(index_buffer.slice(..), 0, IndexFormat::Uint32);
+                match visible_ranges {
+                    Some(ranges) => {
+                        for range in ranges {
+                            pass.draw_indexed(range.clone(), 0, 0..1);
+                        }
+                    }
+                    None => pass.draw_indexed(0..line_gizmo.index_count, 0, 0..1),
+                }
+                if let Some(diagnostics) = diagnostics {
+                    diagnostics.add_draw_calls(visible_ranges.map_or(1, Vec::len));
+                }
+                return RenderCommandResult::Success;
+            }
+            (Some(storage_bind_group), None) => {
+                pass.set_bind_group(2, storage_bind_group, &[]);
+            }
+            (None, _) => {
+                // The buffers may be larger than the data they hold, so only bind the used range.
+                let vertex_count = line_gizmo.vertex_count as u64;
+                let position_size = VertexFormat::Float32x3.size();
+                let positions_len = position_size * vertex_count;
+                let color_size = line_gizmo.color_format.size();
+                let colors_len = color_size * vertex_count;
+
+                if line_gizmo.strip {
+                    let position_buffer = &line_gizmo.position_buffer;
+                    pass.set_vertex_buffer(
+                        0,
+                        position_buffer.slice(..positions_len - position_size),
+                    );
+                    pass.set_vertex_buffer(1, position_buffer.slice(position_size..positions_len));
+
+                    let color_buffer = &line_gizmo.color_buffer;
+                    pass.set_vertex_buffer(2, color_buffer.slice(..colors_len - color_size));
+                    pass.set_vertex_buffer(3, color_buffer.slice(color_size..colors_len));
+                } else {
+                    pass.set_vertex_buffer(0, line_gizmo.position_buffer.slice(..positions_len));
+                    pass.set_vertex_buffer(1, line_gizmo.color_buffer.slice(..colors_len));
+                }
+            }
+        }
+
+        match visible_ranges {
+            Some(ranges) => {
+                for range in ranges {
+                    pass.draw(0..6, range.clone());
+                }
+            }
+            None => pass.draw(0..6, 0..instances),
+        }
+        if let Some(diagnostics) = diagnostics {
+            diagnostics.add_draw_calls(visible_ranges.map_or(1, Vec::len));
+        }
 
         RenderCommandResult::Success
     }
 }
 
-fn line_gizmo_vertex_buffer_layouts(strip: bool) -> Vec<VertexBufferLayout> {
+/// The shader of the line gizmo pipelines, see [`GizmoConfig::shader`].
+fn line_gizmo_shader(config: &GizmoConfig) -> Handle<Shader> {
+    config
+        .shader
+        .as_ref()
+        .map_or(LINE_SHADER_HANDLE, Handle::clone_weak)
+}
+
+/// The vertex state of a line gizmo pipeline.
+///
+/// Unless the `vertex_input` is [`LineGizmoVertexInput::VertexBuffers`], the vertices are pulled
+/// from the storage buffers in bind group 2, which have to be added to the pipeline layout.
+fn line_gizmo_vertex_state(
+    shader: Handle<Shader>,
+    strip: bool,
+    vertex_input: LineGizmoVertexInput,
+    color_format: VertexFormat,
+    mut shader_defs: Vec<ShaderDefVal>,
+) -> VertexState {
+    let entry_point = match vertex_input {
+        LineGizmoVertexInput::VertexBuffers => {
+            return VertexState {
+                shader,
+                entry_point: "vertex".into(),
+                shader_defs,
+                buffers: line_gizmo_vertex_buffer_layouts(strip, color_format),
+            };
+        }
+        LineGizmoVertexInput::Pulled => "vertex_pulled",
+        LineGizmoVertexInput::Indexed => "vertex_strip_indexed",
+    };
+
+    shader_defs.push("VERTEX_PULLING".into());
+    if strip {
+        shader_defs.push("STRIP".into());
+    }
+    if color_format == VertexFormat::Unorm8x4 {
+        shader_defs.push("PACKED_COLORS".into());
+    }
+
+    VertexState {
+        shader,
+        entry_point: entry_point.into(),
+        shader_defs,
+        buffers: vec![],
+    }
+}
+
+fn line_gizmo_vertex_buffer_layouts(
+    strip: bool,
+    color_format: VertexFormat,
+) -> Vec<VertexBufferLayout> {
     use VertexFormat::*;
     let mut position_layout = VertexBufferLayout {
         array_stride: Float32x3.size(),
@@ -527,10 +2644,10 @@ fn line_gizmo_vertex_buffer_layouts(strip: bool) -> Vec<VertexBufferLayout> {
     };
 
     let mut color_layout = VertexBufferLayout {
-        array_stride: Float32x4.size(),
+        array_stride: color_format.size(),
         step_mode: VertexStepMode::Instance,
         attributes: vec![VertexAttribute {
-            format: Float32x4,
+            format: color_format,
             offset: 0,
             shader_location: 2,
         }],
@@ -559,11 +2676,166 @@ fn line_gizmo_vertex_buffer_layouts(strip: bool) -> Vec<VertexBufferLayout> {
 
         color_layout.array_stride *= 2;
         color_layout.attributes.push(VertexAttribute {
-            format: Float32x4,
-            offset: Float32x4.size(),
+            format: color_format,
+            offset: color_format.size(),
             shader_location: 3,
         });
 
         vec![position_layout, color_layout]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, Update};
+    use bevy_asset::{AssetApp, AssetPlugin, Assets};
+    use bevy_core::{TaskPoolPlugin, TypeRegistrationPlugin};
+    use bevy_ecs::{
+        reflect::AppTypeRegistry,
+        system::{Commands, ResMut},
+        world::World,
+    };
+    use bevy_math::Vec3;
+    use bevy_render::color::Color;
+    use bevy_render::render_resource::Shader;
+    use bevy_scene::{ron, serde::SceneDeserializer, DynamicSceneBuilder};
+    use bevy_time::TimePlugin;
+    use serde::de::DeserializeSeed;
+
+    use crate::{
+        changed_line_gizmo_chunks,
+        gizmos::Gizmos,
+        line_gizmo_chunk_vertices,
+        shape::{Gizmo, GizmoShape},
+        strip_indices, AabbGizmo, GizmoPlugin, LineGizmo, LINE_GIZMO_CHUNK_SEGMENTS,
+    };
+
+    /// An app without the `RenderPlugin`, like a dedicated server.
+    fn app_without_renderer() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            TypeRegistrationPlugin,
+            TimePlugin,
+            AssetPlugin::default(),
+        ))
+        .init_asset::<Shader>()
+        .add_plugins(GizmoPlugin);
+        app
+    }
+
+    #[test]
+    fn updates_without_renderer() {
+        let mut app = app_without_renderer();
+        app.update();
+        app.update();
+    }
+
+    /// Shared gameplay code drawing gizmos must also run in headless builds, where gizmos are never drawn.
+    #[test]
+    fn draws_gizmos_without_renderer() {
+        fn draw(
+            mut commands: Commands,
+            mut line_gizmos: ResMut<Assets<LineGizmo>>,
+            mut gizmos: Gizmos,
+        ) {
+            gizmos.line(Vec3::ZERO, Vec3::X, Color::RED);
+            commands.spawn(line_gizmos.add(LineGizmo::new(
+                vec![[0., 0., 0.], [1., 0., 0.]],
+                vec![Color::RED.as_linear_rgba_f32(); 2],
+                false,
+            )));
+        }
+
+        let mut app = app_without_renderer();
+        app.add_systems(Update, draw);
+        app.update();
+        app.update();
+
+        assert_eq!(app.world.resource::<Assets<LineGizmo>>().len(), 2);
+    }
+
+    #[test]
+    fn gizmo_components_round_trip_through_scenes() {
+        let mut app = app_without_renderer();
+        // The field types registered by other plugins, such as the `RenderPlugin`.
+        app.register_type::<Color>()
+            .register_type::<Option<Color>>()
+            .register_type::<Option<f32>>()
+            .register_type::<Option<usize>>();
+        let annotation = app
+            .world
+            .spawn((
+                Gizmo {
+                    shape: GizmoShape::Circle { radius: 2. },
+                    color: Some(Color::RED),
+                },
+                AabbGizmo::default(),
+            ))
+            .id();
+
+        let registry = app.world.resource::<AppTypeRegistry>().clone();
+        let scene = DynamicSceneBuilder::from_world(&app.world)
+            .extract_entity(annotation)
+            .build();
+        let serialized = scene.serialize_ron(&registry).unwrap();
+
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        let mut world = World::new();
+        world.insert_resource(registry);
+        let mut entity_map = Default::default();
+        scene.write_to_world(&mut world, &mut entity_map).unwrap();
+
+        let loaded = world.entity(entity_map[&annotation]);
+        let gizmo = loaded.get::<Gizmo>().unwrap();
+        assert_eq!(gizmo.shape, GizmoShape::Circle { radius: 2. });
+        assert_eq!(gizmo.color, Some(Color::RED));
+        assert!(loaded.contains::<AabbGizmo>());
+    }
+
+    #[test]
+    fn retained_line_gizmos_only_update_changed_chunks() {
+        let mut line_gizmo = LineGizmo::new(vec![[0.; 3]; 5000], vec![[1.; 4]; 5000], true);
+        // Without marked changes, the whole line gizmo is uploaded.
+        assert!(line_gizmo.changes.since(0).is_none());
+
+        line_gizmo.vertices_mut(10..12).0[0] = [1., 0., 0.];
+        line_gizmo.mark_changed(3000..3001);
+        let uploaded = line_gizmo.changes.revision;
+        line_gizmo.mark_changed(1023..1024);
+
+        let changes = line_gizmo.changes.since(0).unwrap();
+        assert_eq!(changed_line_gizmo_chunks(changes, true, 5000), vec![0..3]);
+        let changes = line_gizmo.changes.since(uploaded).unwrap();
+        assert_eq!(changed_line_gizmo_chunks(changes, true, 5000), vec![0..2]);
+        assert!(line_gizmo
+            .changes
+            .since(line_gizmo.changes.revision)
+            .is_none());
+    }
+
+    #[test]
+    fn strip_indices_of_chunks_match_the_whole_strip() {
+        let mut positions = vec![[0.; 3]; 3000];
+        for separator in [1023, 2048, 2049] {
+            positions[separator] = [f32::NAN; 3];
+        }
+        let mut all_indices = Vec::new();
+        strip_indices(&positions, 0, 0..2999, &mut all_indices);
+
+        let mut indices = Vec::new();
+        for chunks in [0..1, 1..2, 2..3, 1..3] {
+            let segments = (chunks.start * LINE_GIZMO_CHUNK_SEGMENTS).min(2999)
+                ..(chunks.end * LINE_GIZMO_CHUNK_SEGMENTS).min(2999);
+            let vertices = line_gizmo_chunk_vertices(chunks, true, positions.len());
+            let first = vertices.start;
+            strip_indices(&positions[vertices], first, segments.clone(), &mut indices);
+            assert_eq!(indices, all_indices[6 * segments.start..6 * segments.end]);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Gizmos for lights.
+//!
+//! Includes the [`LightGizmo`] component and the `LightGizmoPlugin` drawing it.
+
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+
+#[cfg(feature = "bevy_pbr")]
+use crate::{gizmos::Gizmos, GizmoConfig};
+#[cfg(feature = "bevy_pbr")]
+use bevy_app::{Plugin, PostUpdate};
+#[cfg(feature = "bevy_pbr")]
+use bevy_ecs::{
+    schedule::IntoSystemConfigs,
+    system::{Query, Res},
+};
+#[cfg(feature = "bevy_pbr")]
+use bevy_math::{Quat, Vec3};
+#[cfg(feature = "bevy_pbr")]
+use bevy_pbr::{DirectionalLight, PointLight, SpotLight};
+#[cfg(feature = "bevy_pbr")]
+use bevy_transform::{components::GlobalTransform, TransformSystem};
+
+/// Configuration for drawing the range and direction of lights.
+#[derive(Clone, Default, Reflect)]
+#[reflect(Default)]
+pub struct LightGizmoConfig {
+    /// Draws all lights in the scene when set to `true`.
+    ///
+    /// To draw a specific light, you can add the [`LightGizmo`] component.
+    ///
+    /// Defaults to `false`.
+    pub draw_all: bool,
+    /// The default color for light gizmos.
+    ///
+    /// The color of the light itself is used if `None`.
+    ///
+    /// Defaults to `None`.
+    pub default_color: Option<Color>,
+}
+
+/// Add this [`Component`] to a light to draw it.
+///
+/// A [`PointLight`](bevy_pbr::PointLight) is drawn as a sphere of its range,
+/// a [`SpotLight`](bevy_pbr::SpotLight) as its inner and outer cones,
+/// and a [`DirectionalLight`](bevy_pbr::DirectionalLight) as a bundle of arrows in its direction.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct LightGizmo {
+    /// The color of the light gizmo.
+    ///
+    /// The default color from the [`GizmoConfig`](crate::GizmoConfig) resource is used if `None`.
+    pub color: Option<Color>,
+}
+
+/// A [`Plugin`] that draws the [`LightGizmo`]s, added by the [`GizmoPlugin`](crate::GizmoPlugin).
+#[cfg(feature = "bevy_pbr")]
+pub struct LightGizmoPlugin;
+
+#[cfg(feature = "bevy_pbr")]
+impl Plugin for LightGizmoPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(
+            PostUpdate,
+            draw_lights.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// The length of the arrows of a [`DirectionalLight`] and their distance to its center.
+#[cfg(feature = "bevy_pbr")]
+const DIRECTIONAL_LIGHT_ARROW_LENGTH: f32 = 1.;
+
+#[cfg(feature = "bevy_pbr")]
+pub(crate) fn draw_lights(
+    point_lights: Query<(&PointLight, &GlobalTransform, Option<&LightGizmo>)>,
+    spot_lights: Query<(&SpotLight, &GlobalTransform, Option<&LightGizmo>)>,
+    directional_lights: Query<(&DirectionalLight, &GlobalTransform, Option<&LightGizmo>)>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let light_config = &config.light;
+    let color = |gizmo: Option<&LightGizmo>, light_color: Color| {
+        gizmo
+            .and_then(|gizmo| gizmo.color)
+            .or(light_config.default_color)
+            .unwrap_or(light_color)
+    };
+    let is_drawn = |gizmo: Option<&LightGizmo>| light_config.draw_all || gizmo.is_some();
+
+    for (light, transform, gizmo) in &point_lights {
+        if !is_drawn(gizmo) {
+            continue;
+        }
+        let (_, rotation, translation) = transform.to_scale_rotation_translation();
+        gizmos.sphere(
+            translation,
+            rotation,
+            light.range,
+            color(gizmo, light.color),
+        );
+    }
+
+    for (light, transform, gizmo) in &spot_lights {
+        if !is_drawn(gizmo) {
+            continue;
+        }
+        let (_, rotation, translation) = transform.to_scale_rotation_translation();
+        let color = color(gizmo, light.color);
+        for angle in [light.inner_angle, light.outer_angle] {
+            draw_cone(
+                &mut gizmos,
+                translation,
+                rotation,
+                light.range,
+                angle,
+                color,
+            );
+        }
+    }
+
+    for (light, transform, gizmo) in &directional_lights {
+        if !is_drawn(gizmo) {
+            continue;
+        }
+        let (_, rotation, translation) = transform.to_scale_rotation_translation();
+        let color = color(gizmo, light.color);
+        let direction = rotation * Vec3::NEG_Z * DIRECTIONAL_LIGHT_ARROW_LENGTH;
+        for offset in [Vec3::ZERO, Vec3::X, Vec3::NEG_X, Vec3::Y, Vec3::NEG_Y] {
+            let start = translation + rotation * offset * DIRECTIONAL_LIGHT_ARROW_LENGTH / 2.;
+            gizmos.arrow(start, start + direction, color);
+        }
+    }
+}
+
+/// Draws a cone pointing from `apex` along the local `-Z` axis of `rotation`,
+/// with a slant height of `range` and its sides at `angle` from its axis.
+#[cfg(feature = "bevy_pbr")]
+fn draw_cone(
+    gizmos: &mut Gizmos,
+    apex: Vec3,
+    rotation: Quat,
+    range: f32,
+    angle: f32,
+    color: Color,
+) {
+    let (sin, cos) = angle.sin_cos();
+    let center = apex + rotation * Vec3::NEG_Z * range * cos;
+    let radius = range * sin;
+    gizmos.circle(center, rotation * Vec3::Z, radius, color);
+    for direction in [Vec3::X, Vec3::NEG_X, Vec3::Y, Vec3::NEG_Y] {
+        gizmos.line(apex, center + rotation * direction * radius, color);
+    }
+}
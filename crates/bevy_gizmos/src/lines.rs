@@ -0,0 +1,153 @@
+//! Reading the lines of the gizmos drawn in a frame.
+//!
+//! Includes the [`GizmoLines`] system parameter and the types it returns.
+
+use crate::gizmos::{GizmoStorage, GizmoTag};
+use bevy_ecs::system::{Res, SystemParam};
+use bevy_math::Vec3;
+use bevy_render::color::Color;
+
+/// A [`SystemParam`] reading the lines of all the gizmos drawn in the current frame,
+/// such as to export them, pick them or render them in another way.
+///
+/// The lines are complete in the [`ReadGizmos`](crate::ReadGizmos) set of the
+/// [`Last`](bevy_app::Last) schedule, after circles and texts are resolved into lines
+/// and before they are sent to the render world.
+/// Lines only drawn in the view of one camera, such as
+/// [billboard circles](crate::gizmos::Gizmos::billboard_circle), aren't included.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_gizmos::ReadGizmos;
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// fn count_segments(lines: GizmoLines) {
+///     println!("{} gizmo segments this frame", lines.segments().count());
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_systems(Last, count_segments.in_set(ReadGizmos));
+/// ```
+#[derive(SystemParam)]
+pub struct GizmoLines<'w> {
+    storage: Res<'w, GizmoStorage>,
+}
+
+impl GizmoLines<'_> {
+    /// Iterate over the lines drawn as separate segments.
+    pub fn lines(&self) -> impl Iterator<Item = [GizmoVertex; 2]> + '_ {
+        let positions = self.storage.list_positions.chunks_exact(2);
+        let colors = self.storage.list_colors.chunks_exact(2);
+        positions
+            .zip(colors)
+            .map(|(positions, colors)| [0, 1].map(|i| GizmoVertex::new(positions[i], colors[i])))
+    }
+
+    /// Iterate over the lines drawn as strips of connected segments.
+    ///
+    /// Strips of a single point draw nothing and are skipped.
+    pub fn strips(&self) -> impl Iterator<Item = GizmoStrip<'_>> {
+        let colors = &self.storage.strip_colors;
+        let mut start = 0;
+        // Strips are separated by a NaN position.
+        self.storage
+            .strip_positions
+            .split(|position| position[0].is_nan())
+            .map(move |positions| {
+                let end = start + positions.len();
+                let strip = GizmoStrip {
+                    positions,
+                    colors: &colors[start..end],
+                };
+                start = end + 1;
+                strip
+            })
+            .filter(|strip| strip.len() >= 2)
+    }
+
+    /// Iterate over all the segments, of both [lines](Self::lines) and [strips](Self::strips).
+    pub fn segments(&self) -> impl Iterator<Item = [GizmoVertex; 2]> + '_ {
+        let strip_segments = self.strips().flat_map(|strip| {
+            let vertices = strip.vertices();
+            vertices.clone().zip(vertices.skip(1)).map(<[_; 2]>::from)
+        });
+        self.lines().chain(strip_segments)
+    }
+
+    /// Iterate over the segments drawn in [`Gizmos::tagged`](crate::gizmos::Gizmos::tagged) scopes,
+    /// along with their tag, such as to tell which gizmo is under the cursor.
+    pub fn tagged_segments(&self) -> impl Iterator<Item = (GizmoTag, [GizmoVertex; 2])> + '_ {
+        let storage = &*self.storage;
+        let list_vertex =
+            |i: usize| GizmoVertex::new(storage.list_positions[i], storage.list_colors[i]);
+        let strip_vertex =
+            |i: usize| GizmoVertex::new(storage.strip_positions[i], storage.strip_colors[i]);
+        storage.tags.iter().flat_map(move |tagged| {
+            let lines = tagged.list.clone().step_by(2).map(move |i| [i, i + 1]);
+            // Strips are separated by a NaN position.
+            let strips = (tagged.strip.start..tagged.strip.end.saturating_sub(1))
+                .map(|i| [i, i + 1])
+                .filter(|segment| {
+                    segment
+                        .iter()
+                        .all(|&i| !storage.strip_positions[i][0].is_nan())
+                });
+            let lines = lines.map(move |segment| segment.map(list_vertex));
+            let strips = strips.map(move |segment| segment.map(strip_vertex));
+            lines.chain(strips).map(|segment| (tagged.tag, segment))
+        })
+    }
+
+    /// The number of vertices of all the lines and strips.
+    pub fn vertex_count(&self) -> usize {
+        self.lines().count() * 2 + self.strips().map(|strip| strip.len()).sum::<usize>()
+    }
+}
+
+/// A vertex of a line returned by [`GizmoLines`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GizmoVertex {
+    /// The position of the vertex in world space.
+    pub position: Vec3,
+    /// The color of the vertex.
+    pub color: Color,
+}
+
+impl GizmoVertex {
+    fn new(position: [f32; 3], [red, green, blue, alpha]: [f32; 4]) -> Self {
+        Self {
+            position: Vec3::from(position),
+            color: Color::rgba_linear(red, green, blue, alpha),
+        }
+    }
+}
+
+/// A strip of connected segments returned by [`GizmoLines::strips`].
+#[derive(Clone, Copy, Debug)]
+pub struct GizmoStrip<'a> {
+    positions: &'a [[f32; 3]],
+    colors: &'a [[f32; 4]],
+}
+
+impl<'a> GizmoStrip<'a> {
+    /// Iterate over the vertices of the strip, in order.
+    pub fn vertices(&self) -> impl Iterator<Item = GizmoVertex> + Clone + 'a {
+        let colors = self.colors.iter().copied();
+        self.positions
+            .iter()
+            .copied()
+            .zip(colors)
+            .map(|(position, color)| GizmoVertex::new(position, color))
+    }
+
+    /// The number of vertices of the strip.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns `true` if the strip has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+}
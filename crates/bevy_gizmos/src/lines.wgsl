@@ -7,14 +7,21 @@
 struct LineGizmoUniform {
     line_width: f32,
     depth_bias: f32,
-#ifdef SIXTEEN_BYTE_ALIGNMENT
     // WebGL2 structs must be 16 byte aligned.
     _padding: vec2<f32>,
-#endif
 }
 
 @group(1) @binding(0) var<uniform> line_gizmo: LineGizmoUniform;
 
+struct LineGizmoViewUniform {
+    // Converts line widths from logical to physical pixels.
+    scale_factor: f32,
+    // WebGL2 structs must be 16 byte aligned.
+    _padding: vec3<f32>,
+}
+
+@group(1) @binding(1) var<uniform> line_gizmo_view: LineGizmoViewUniform;
+
 struct VertexInput {
     @location(0) position_a: vec3<f32>,
     @location(1) position_b: vec3<f32>,
@@ -32,6 +39,17 @@ const EPSILON: f32 = 4.88e-04;
 
 @vertex
 fn vertex(vertex: VertexInput) -> VertexOutput {
+    return line_segment(vertex.position_a, vertex.position_b, vertex.color_a, vertex.color_b, vertex.index);
+}
+
+// Draws the vertex `index` of the quad of the segment from a to b.
+fn line_segment(
+    position_a: vec3<f32>,
+    position_b: vec3<f32>,
+    color_a: vec4<f32>,
+    color_b: vec4<f32>,
+    index: u32,
+) -> VertexOutput {
     var positions = array<vec3<f32>, 6>(
         vec3(0., -0.5, 0.),
         vec3(0., -0.5, 1.),
@@ -40,11 +58,11 @@ fn vertex(vertex: VertexInput) -> VertexOutput {
         vec3(0., 0.5, 1.),
         vec3(0., 0.5, 0.)
     );
-    let position = positions[vertex.index];
+    let position = positions[index];
 
     // algorithm based on https://wwwtyro.net/2019/11/18/instanced-lines.html
-    var clip_a = view.view_proj * vec4(vertex.position_a, 1.);
-    var clip_b = view.view_proj * vec4(vertex.position_b, 1.);
+    var clip_a = view.view_proj * vec4(position_a, 1.);
+    var clip_b = view.view_proj * vec4(position_b, 1.);
 
     // Manual near plane clipping to avoid errors when doing the perspective divide inside this shader.
     clip_a = clip_near_plane(clip_a, clip_b);
@@ -56,16 +74,23 @@ fn vertex(vertex: VertexInput) -> VertexOutput {
     let screen_a = resolution * (0.5 * clip_a.xy / clip_a.w + 0.5);
     let screen_b = resolution * (0.5 * clip_b.xy / clip_b.w + 0.5);
 
-    let x_basis = normalize(screen_a - screen_b);
+    // Segments of zero length are points, which are drawn as squares as wide as lines.
+    let delta = screen_a - screen_b;
+    var x_basis = vec2(1., 0.);
+    var cap = 0.5;
+    if dot(delta, delta) > EPSILON {
+        x_basis = normalize(delta);
+        cap = 0.;
+    }
     let y_basis = vec2(-x_basis.y, x_basis.x);
 
-    var color = mix(vertex.color_a, vertex.color_b, position.z);
+    var color = mix(color_a, color_b, position.z);
 
-    var line_width = line_gizmo.line_width;
+    var line_width = line_gizmo.line_width * line_gizmo_view.scale_factor;
     var alpha = 1.;
 
 #ifdef PERSPECTIVE
-    line_width /= clip.w;
+    line_width /= perspective_distance(clip.w);
 #endif
 
     // Line thinness fade from https://acegikmo.com/shapes/docs/#anti-aliasing
@@ -74,27 +99,161 @@ fn vertex(vertex: VertexInput) -> VertexOutput {
         line_width = 1.;
     }
 
-    let offset = line_width * (position.x * x_basis + position.y * y_basis);
+    let along = position.x + cap * (1. - 2. * position.z);
+    let offset = line_width * (along * x_basis + position.y * y_basis);
     let screen = mix(screen_a, screen_b, position.z) + offset;
 
-    var depth: f32;
-    if line_gizmo.depth_bias >= 0. {
-        depth = clip.z * (1. - line_gizmo.depth_bias);
+    var clip_position = vec4(clip.w * ((2. * screen) / resolution - 1.), biased_depth(clip), clip.w);
+
+    return VertexOutput(clip_position, color);
+}
+
+#ifdef VERTEX_PULLING
+@group(2) @binding(0) var<storage> line_positions: array<f32>;
+#ifdef PACKED_COLORS
+@group(2) @binding(1) var<storage> line_colors: array<u32>;
+#else
+@group(2) @binding(1) var<storage> line_colors: array<vec4<f32>>;
+#endif
+
+fn line_position(point: u32) -> vec3<f32> {
+    // Positions are tightly packed, which `array<vec3<f32>>` isn't.
+    let i = point * 3u;
+    return vec3(line_positions[i], line_positions[i + 1u], line_positions[i + 2u]);
+}
+
+fn line_color(point: u32) -> vec4<f32> {
+#ifdef PACKED_COLORS
+    return unpack4x8unorm(line_colors[point]);
+#else
+    return line_colors[point];
+#endif
+}
+
+// Draws a vertex of a segment, pulling its points from the storage buffers instead of vertex buffers.
+@vertex
+fn vertex_pulled(
+    @builtin(vertex_index) index: u32,
+    @builtin(instance_index) segment: u32,
+) -> VertexOutput {
+#ifdef STRIP
+    let a = segment;
+#else
+    let a = segment * 2u;
+#endif
+    let b = a + 1u;
+    return line_segment(line_position(a), line_position(b), line_color(a), line_color(b), index);
+}
+
+// Joints are at most `1 / MIN_MITER_COS` times as wide as the line, sharper ones are cut off.
+const MIN_MITER_COS: f32 = 0.25;
+
+// Draws a vertex of an indexed line strip, the index encodes the point and its joints.
+// See `strip_indices` in lib.rs.
+@vertex
+fn vertex_strip_indexed(@builtin(vertex_index) index: u32) -> VertexOutput {
+    let point = index / 8u;
+    let side = f32(index & 1u) - 0.5;
+    let has_prev = (index & 2u) != 0u;
+    let has_next = (index & 4u) != 0u;
+
+    var clip = view.view_proj * vec4(line_position(point), 1.);
+    var clip_prev = clip;
+    var clip_next = clip;
+    if has_prev {
+        clip_prev = view.view_proj * vec4(line_position(point - 1u), 1.);
+    }
+    if has_next {
+        clip_next = view.view_proj * vec4(line_position(point + 1u), 1.);
+    }
+
+    // Manual near plane clipping, see `vertex`.
+    // As the vertex is shared, it can only be clipped towards one of the neighbors.
+    if has_next && clip_next.z <= clip_next.w {
+        clip = clip_near_plane(clip, clip_next);
+    } else {
+        clip = clip_near_plane(clip, clip_prev);
+    }
+    clip_prev = clip_near_plane(clip_prev, clip);
+    clip_next = clip_near_plane(clip_next, clip);
+
+    let resolution = view.viewport.zw;
+    let screen = resolution * (0.5 * clip.xy / clip.w + 0.5);
+    let screen_prev = resolution * (0.5 * clip_prev.xy / clip_prev.w + 0.5);
+    let screen_next = resolution * (0.5 * clip_next.xy / clip_next.w + 0.5);
+
+    var x_basis: vec2<f32>;
+    var miter = 1.;
+    if has_prev && has_next {
+        let x_prev = normalize(screen - screen_prev);
+        let x_next = normalize(screen_next - screen);
+        let tangent = x_prev + x_next;
+        if dot(tangent, tangent) > EPSILON {
+            // The joint is extended so that both segments keep their width.
+            x_basis = normalize(tangent);
+            miter = 1. / max(dot(x_basis, x_next), MIN_MITER_COS);
+        } else {
+            // The strip turns back on itself.
+            x_basis = x_next;
+        }
+    } else if has_next {
+        x_basis = normalize(screen_next - screen);
     } else {
-        // depth * (clip.w / depth)^-depth_bias. So that when -depth_bias is 1.0, this is equal to clip.w
-        // and when equal to 0.0, it is exactly equal to depth.
-        // the epsilon is here to prevent the depth from exceeding clip.w when -depth_bias = 1.0
-        // clip.w represents the near plane in homogeneous clip space in bevy, having a depth
-        // of this value means nothing can be in front of this
-        // The reason this uses an exponential function is that it makes it much easier for the
-        // user to chose a value that is convenient for them
-        depth = clip.z * exp2(-line_gizmo.depth_bias * log2(clip.w / clip.z - EPSILON));
+        x_basis = normalize(screen - screen_prev);
+    }
+    let y_basis = vec2(-x_basis.y, x_basis.x);
+
+    var color = line_color(point);
+
+    var line_width = line_gizmo.line_width * line_gizmo_view.scale_factor;
+
+#ifdef PERSPECTIVE
+    line_width /= perspective_distance(clip.w);
+#endif
+
+    // Line thinness fade from https://acegikmo.com/shapes/docs/#anti-aliasing
+    if line_width > 0.0 && line_width < 1. {
+        color.a *= line_width;
+        line_width = 1.;
     }
 
-    var clip_position = vec4(clip.w * ((2. * screen) / resolution - 1.), depth, clip.w);
+    let offset = line_width * side * miter * y_basis;
+    let screen_offset = screen + offset;
+
+    var clip_position = vec4(clip.w * ((2. * screen_offset) / resolution - 1.), biased_depth(clip), clip.w);
 
     return VertexOutput(clip_position, color);
 }
+#endif
+
+#ifdef PERSPECTIVE
+// The y scale of a perspective projection with the default vertical field of view of 45 degrees.
+const DEFAULT_PERSPECTIVE_SCALE: f32 = 2.4142135;
+
+// The distance lines are `line_width` wide at, given the `w` of their clip position.
+// Orthographic projections use the distance a perspective projection with the default field
+// of view sees as much at, so lines scale with the zoom and switching projections keeps their width.
+fn perspective_distance(w: f32) -> f32 {
+    if view.projection[3][3] == 1. {
+        return DEFAULT_PERSPECTIVE_SCALE / view.projection[1][1];
+    }
+    return w;
+}
+#endif
+
+fn biased_depth(clip: vec4<f32>) -> f32 {
+    if line_gizmo.depth_bias >= 0. {
+        return clip.z * (1. - line_gizmo.depth_bias);
+    }
+    // depth * (clip.w / depth)^-depth_bias. So that when -depth_bias is 1.0, this is equal to clip.w
+    // and when equal to 0.0, it is exactly equal to depth.
+    // the epsilon is here to prevent the depth from exceeding clip.w when -depth_bias = 1.0
+    // clip.w represents the near plane in homogeneous clip space in bevy, having a depth
+    // of this value means nothing can be in front of this
+    // The reason this uses an exponential function is that it makes it much easier for the
+    // user to chose a value that is convenient for them
+    return clip.z * exp2(-line_gizmo.depth_bias * log2(clip.w / clip.z - EPSILON));
+}
 
 fn clip_near_plane(a: vec4<f32>, b: vec4<f32>) -> vec4<f32> {
     // Move a if a is behind the near plane and b is in front. 
@@ -0,0 +1,158 @@
+//! Additional [`Gizmos`] Functions -- Measurements
+//!
+//! Includes the implementation of [`Gizmos::measure`] and [`Gizmos::measure_2d`],
+//! and assorted support items.
+
+use crate::prelude::Gizmos;
+use bevy_math::{Mat3, Quat, Vec2, Vec3};
+use bevy_render::color::Color;
+
+/// The default size of the label of a measurement, relative to the measured distance.
+const DEFAULT_TEXT_SIZE: f32 = 0.1;
+
+impl<'s> Gizmos<'s> {
+    /// Draw a dimension line in 3D between `start` and `end`, with ticks at both ends
+    /// and the distance between them as a label above its midpoint.
+    ///
+    /// The label is drawn along the line, upright relative to the `+Y` axis,
+    /// unless its rotation is set with [`MeasureBuilder::text_rotation`].
+    ///
+    /// This should be called for each frame the measurement needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.measure(Vec3::ZERO, Vec3::new(4., 0., 0.), Color::WHITE);
+    ///
+    ///     // The size and precision of the label can be changed.
+    ///     gizmos
+    ///         .measure(Vec3::ZERO, Vec3::new(0., 2.5, 0.), Color::WHITE)
+    ///         .text_size(0.2)
+    ///         .precision(1);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn measure(&mut self, start: Vec3, end: Vec3, color: Color) -> MeasureBuilder<'_, 's> {
+        MeasureBuilder {
+            gizmos: self,
+            start,
+            end,
+            color,
+            is_2d: false,
+            text_size: None,
+            text_rotation: None,
+            precision: 2,
+        }
+    }
+
+    /// Draw a dimension line in 2D between `start` and `end`, with ticks at both ends
+    /// and the distance between them as a label above its midpoint.
+    ///
+    /// This should be called for each frame the measurement needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos
+    ///         .measure_2d(Vec2::ZERO, Vec2::new(120., 50.), Color::WHITE)
+    ///         .precision(0);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn measure_2d(&mut self, start: Vec2, end: Vec2, color: Color) -> MeasureBuilder<'_, 's> {
+        MeasureBuilder {
+            gizmos: self,
+            start: start.extend(0.),
+            end: end.extend(0.),
+            color,
+            is_2d: true,
+            text_size: None,
+            text_rotation: None,
+            precision: 2,
+        }
+    }
+}
+
+/// A builder returned by [`Gizmos::measure`] and [`Gizmos::measure_2d`].
+pub struct MeasureBuilder<'a, 's> {
+    gizmos: &'a mut Gizmos<'s>,
+    start: Vec3,
+    end: Vec3,
+    color: Color,
+    is_2d: bool,
+    text_size: Option<f32>,
+    text_rotation: Option<Quat>,
+    precision: usize,
+}
+
+impl MeasureBuilder<'_, '_> {
+    /// Set the height of the label and the length of the ticks.
+    ///
+    /// Defaults to a tenth of the measured distance.
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Set the rotation of the label, for example to face the camera.
+    ///
+    /// The label is drawn along the line by default.
+    pub fn text_rotation(mut self, rotation: Quat) -> Self {
+        self.text_rotation = Some(rotation);
+        self
+    }
+
+    /// Set the number of decimals the distance is labeled with.
+    ///
+    /// Defaults to `2`.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl Drop for MeasureBuilder<'_, '_> {
+    fn drop(&mut self) {
+        let distance = self.start.distance(self.end);
+        if distance == 0. {
+            return;
+        }
+        // Read the label left to right by going along the line towards `+X`.
+        let mut along = (self.end - self.start) / distance;
+        if along.x < 0. {
+            along = -along;
+        }
+        let normal = if self.is_2d {
+            Vec3::Z
+        } else {
+            let reference = if along.y.abs() < 0.99 {
+                Vec3::Y
+            } else {
+                Vec3::Z
+            };
+            along.cross(reference).normalize()
+        };
+        let up = normal.cross(along);
+
+        let size = self.text_size.unwrap_or(distance * DEFAULT_TEXT_SIZE);
+        let rotation = self
+            .text_rotation
+            .unwrap_or_else(|| Quat::from_mat3(&Mat3::from_cols(along, up, normal)));
+        let label = format!("{distance:.*}", self.precision);
+        let position = self.start.lerp(self.end, 0.5) + up * size;
+        self.gizmos.draw_2d(self.is_2d, |gizmos| {
+            for end in [self.start, self.end] {
+                let offset = up * size / 2.;
+                gizmos.line(end - offset, end + offset, self.color);
+            }
+            gizmos.line(self.start, self.end, self.color);
+            gizmos.text(position, rotation, &label, size, self.color);
+        });
+    }
+}
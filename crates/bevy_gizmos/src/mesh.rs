@@ -0,0 +1,124 @@
+//! Turning gizmos into line-list [`Mesh`]es.
+//!
+//! Includes the [`GizmoShapeMeshBuilder`].
+
+use crate::{
+    circles,
+    gizmos::{GizmoStorage, Gizmos},
+    gradients::{self, GradientScratch},
+    shape::GizmoShape,
+    text, GizmoConfig,
+};
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use bevy_render::{
+    color::Color, mesh::Mesh, render_asset::RenderAssetPersistencePolicy,
+    render_resource::PrimitiveTopology,
+};
+
+/// Builds line-list [`Mesh`]es out of gizmos, to keep a debug visualization as regular geometry
+/// drawn with any material.
+///
+/// The gizmos are drawn with the same [`Gizmos`] methods as every frame, or from a [`GizmoShape`].
+/// They are drawn even in builds where [`Gizmos`] are no-ops, such as with the `strip_in_release` feature.
+///
+/// The meshes have a [position](Mesh::ATTRIBUTE_POSITION) and a linear RGBA
+/// [color](Mesh::ATTRIBUTE_COLOR) per vertex. They are drawn with the line width of the GPU,
+/// which is one pixel on most platforms, instead of [`GizmoConfig::line_width`].
+///
+/// As there are no cameras, circles and arcs have 32 segments per full circle unless given
+/// a number of segments, and billboards face `+Z`. Gizmos drawn with [`Gizmos::persist`]
+/// and [`Gizmos::screen_sized`] are left out.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_asset::Assets;
+/// fn setup(mut meshes: ResMut<Assets<Mesh>>) {
+///     let builder = GizmoShapeMeshBuilder::default();
+///     let marker = meshes.add(builder.build(|gizmos| {
+///         gizmos.circle(Vec3::ZERO, Vec3::Y, 1., Color::RED);
+///         gizmos.arrow(Vec3::ZERO, Vec3::Y * 2., Color::GREEN);
+///     }));
+///     let bounds = meshes.add(builder.build_shape(
+///         &GizmoShape::Cuboid { size: Vec3::splat(10.) },
+///         Color::WHITE,
+///     ));
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+///
+/// let mesh = GizmoShapeMeshBuilder::default().build(|gizmos| {
+///     gizmos.linestrip([Vec3::ZERO, Vec3::X, Vec3::Y], Color::BLUE);
+/// });
+/// assert_eq!(mesh.count_vertices(), 4);
+/// ```
+#[derive(Clone, Default)]
+pub struct GizmoShapeMeshBuilder {
+    /// The configuration the gizmos are drawn with, such as for the
+    /// [default color](GizmoConfig::default_color), [texts](GizmoConfig::text)
+    /// and [gradients](GizmoConfig::gradient_color_space).
+    ///
+    /// The vertex budget is ignored.
+    pub config: GizmoConfig,
+    /// Whether the meshes are kept on the CPU after they are sent to the GPU.
+    pub persistence_policy: RenderAssetPersistencePolicy,
+}
+
+impl GizmoShapeMeshBuilder {
+    /// Create a builder drawing gizmos with the given `config`.
+    pub fn new(config: GizmoConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Build a mesh out of the gizmos drawn by `draw`.
+    pub fn build(&self, draw: impl FnOnce(&mut Gizmos)) -> Mesh {
+        let mut world = World::new();
+        world.insert_resource(GizmoConfig {
+            max_vertices: None,
+            max_memory: None,
+            ..self.config.clone()
+        });
+        let mut storage = GizmoStorage::default();
+        storage.draw_retained(world.resource::<GizmoConfig>(), draw);
+        world.insert_resource(storage);
+
+        world.run_system_once(text::resolve_pending_texts);
+        world.run_system_once(circles::resolve_pending_arcs);
+        let mut storage = world.remove_resource::<GizmoStorage>().unwrap();
+        gradients::interpolate_gradients(
+            &mut storage,
+            self.config.gradient_color_space,
+            &mut GradientScratch::default(),
+        );
+
+        let mut positions = storage.list_positions;
+        let mut colors = storage.list_colors;
+        for (segment, segment_colors) in storage
+            .strip_positions
+            .windows(2)
+            .zip(storage.strip_colors.windows(2))
+        {
+            // Strips are separated by a NaN position.
+            if segment[0][0].is_nan() || segment[1][0].is_nan() {
+                continue;
+            }
+            positions.extend_from_slice(segment);
+            colors.extend_from_slice(segment_colors);
+        }
+
+        let mut mesh = Mesh::new(PrimitiveTopology::LineList, self.persistence_policy);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+        mesh
+    }
+
+    /// Build a mesh out of a [`GizmoShape`], centered on the origin.
+    pub fn build_shape(&self, shape: &GizmoShape, color: Color) -> Mesh {
+        self.build(|gizmos| shape.draw(gizmos, color))
+    }
+}
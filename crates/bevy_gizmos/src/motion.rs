@@ -0,0 +1,110 @@
+//! Gizmos for the motion of entities.
+//!
+//! Includes the [`MotionGizmo`] component and the system drawing it.
+
+use crate::{color_from_entity, gizmos::Gizmos, GizmoConfig};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    reflect::ReflectComponent,
+    system::{Local, Query, Res},
+};
+use bevy_math::Vec3;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+use bevy_time::Time;
+use bevy_transform::components::GlobalTransform;
+use bevy_utils::EntityHashMap;
+use std::collections::VecDeque;
+
+/// Configuration for drawing the velocity and trail of entities with a [`MotionGizmo`].
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct MotionGizmoConfig {
+    /// The default color for motion gizmos.
+    ///
+    /// A random color is chosen per entity if `None`.
+    ///
+    /// Defaults to `None`.
+    pub default_color: Option<Color>,
+    /// The number of seconds of movement at the current velocity the arrow spans.
+    ///
+    /// Defaults to `1.0`, so the length of the arrow is the speed in units per second.
+    pub velocity_scale: f32,
+    /// The default number of past positions drawn as a trail behind each entity.
+    ///
+    /// Defaults to `0`, which draws no trail.
+    pub default_trail_length: usize,
+}
+
+impl Default for MotionGizmoConfig {
+    fn default() -> Self {
+        Self {
+            default_color: None,
+            velocity_scale: 1.,
+            default_trail_length: 0,
+        }
+    }
+}
+
+/// Add this [`Component`] to an entity to draw an arrow of its velocity,
+/// measured from the change of its [`GlobalTransform`] since the previous frame,
+/// and optionally a trail of its past positions.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct MotionGizmo {
+    /// The color of the arrow and trail.
+    ///
+    /// The default color from the [`GizmoConfig`] resource is used if `None`.
+    pub color: Option<Color>,
+    /// The number of past positions drawn as a trail, one per frame.
+    ///
+    /// The default trail length from the [`GizmoConfig`] resource is used if `None`.
+    pub trail_length: Option<usize>,
+}
+
+/// The past positions of an entity with a [`MotionGizmo`], the most recent last.
+#[derive(Default)]
+pub(crate) struct MotionTrail(VecDeque<Vec3>);
+
+pub(crate) fn draw_motion(
+    query: Query<(Entity, &GlobalTransform, &MotionGizmo)>,
+    config: Res<GizmoConfig>,
+    time: Res<Time>,
+    mut trails: Local<EntityHashMap<Entity, MotionTrail>>,
+    mut gizmos: Gizmos,
+) {
+    let motion_config = &config.motion;
+    let delta_seconds = time.delta_seconds();
+    for (entity, transform, gizmo) in &query {
+        let position = transform.translation();
+        let color = gizmo
+            .color
+            .or(motion_config.default_color)
+            .unwrap_or_else(|| color_from_entity(entity));
+        let trail_length = gizmo
+            .trail_length
+            .unwrap_or(motion_config.default_trail_length);
+
+        let MotionTrail(trail) = trails.entry(entity).or_default();
+        if let Some(&previous) = trail.back() {
+            if delta_seconds > 0. {
+                let velocity = (position - previous) / delta_seconds;
+                let end = position + velocity * motion_config.velocity_scale;
+                if end != position {
+                    gizmos.arrow(position, end, color);
+                }
+            }
+            if trail_length > 0 {
+                gizmos.linestrip(trail.iter().copied().chain([position]), color);
+            }
+        }
+
+        // Keep the previous position for the velocity even without a trail.
+        trail.push_back(position);
+        let len = trail.len().saturating_sub(trail_length.max(1));
+        trail.drain(..len);
+    }
+
+    trails.retain(|&entity, _| query.contains(entity));
+}
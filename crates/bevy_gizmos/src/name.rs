@@ -0,0 +1,101 @@
+//! Gizmos for the names of entities.
+//!
+//! Includes the [`NameGizmo`] component and the system drawing it.
+
+use crate::{color_from_entity, gizmos::Gizmos, GizmoConfig};
+use bevy_core::Name;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_math::{Quat, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{camera::Camera, color::Color};
+use bevy_transform::components::GlobalTransform;
+
+/// Configuration for drawing the names of entities as text labels.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct NameGizmoConfig {
+    /// Draws the names of all entities with a [`Name`] when set to `true`.
+    ///
+    /// To draw a specific entity's name, you can add the [`NameGizmo`] component.
+    ///
+    /// Defaults to `false`.
+    pub draw_all: bool,
+    /// The default color for name labels.
+    ///
+    /// A random color is chosen per entity if `None`.
+    ///
+    /// Defaults to `None`.
+    pub default_color: Option<Color>,
+    /// The offset of the center of the label from the entity's translation, in world space.
+    ///
+    /// Defaults to `Vec3::Y`.
+    pub offset: Vec3,
+    /// The height of capital letters in the label.
+    ///
+    /// Defaults to `0.2`.
+    pub size: f32,
+}
+
+impl Default for NameGizmoConfig {
+    fn default() -> Self {
+        Self {
+            draw_all: false,
+            default_color: None,
+            offset: Vec3::Y,
+            size: 0.2,
+        }
+    }
+}
+
+/// Add this [`Component`] to an entity to draw its [`Name`] as a text label above it,
+/// or its [`Entity`] id if it has no name.
+///
+/// The label faces the active camera with the highest [`Camera::order`].
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct NameGizmo {
+    /// The color of the label.
+    ///
+    /// The default color from the [`GizmoConfig`] resource is used if `None`.
+    pub color: Option<Color>,
+}
+
+pub(crate) fn draw_names(
+    query: Query<(Entity, &GlobalTransform, Option<&Name>, Option<&NameGizmo>)>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let name_config = &config.name;
+    let rotation = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+        .map_or(Quat::IDENTITY, |(_, transform)| {
+            transform.to_scale_rotation_translation().1
+        });
+
+    for (entity, transform, name, gizmo) in &query {
+        let label = match (name, gizmo) {
+            (Some(name), _) if gizmo.is_some() || name_config.draw_all => name.to_string(),
+            (None, Some(_)) => format!("{entity:?}"),
+            _ => continue,
+        };
+        let color = gizmo
+            .and_then(|gizmo| gizmo.color)
+            .or(name_config.default_color)
+            .unwrap_or_else(|| color_from_entity(entity));
+        gizmos.text(
+            transform.translation() + name_config.offset,
+            rotation,
+            &label,
+            name_config.size,
+            color,
+        );
+    }
+}
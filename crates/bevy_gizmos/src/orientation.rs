@@ -0,0 +1,137 @@
+//! Gizmos for the orientation of cameras.
+//!
+//! Includes the [`OrientationGizmoPlugin`] drawing the world axes in a corner of each viewport.
+
+use crate::{gizmos::Gizmos, GizmoConfig};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{
+    schedule::IntoSystemConfigs,
+    system::{Query, Res},
+};
+use bevy_math::{Vec2, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{camera::Camera, color::Color};
+use bevy_transform::{components::GlobalTransform, TransformSystem};
+
+/// A [`Plugin`] that draws the world axes as seen by each active camera in a corner
+/// of its viewport, configured by [`GizmoConfig::orientation`].
+///
+/// Unlike most other gizmo plugins, it isn't added by the [`GizmoPlugin`](crate::GizmoPlugin).
+pub struct OrientationGizmoPlugin;
+
+impl Plugin for OrientationGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            draw_orientation.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// A corner of a viewport.
+#[derive(Clone, Copy, Reflect, Default, Debug, PartialEq, Eq)]
+#[reflect(Default)]
+pub enum ScreenCorner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    #[default]
+    BottomLeft,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl ScreenCorner {
+    /// The position `offset` logical pixels away from this corner towards the center
+    /// of a viewport of `size`, in logical pixels from its top left corner.
+    pub fn position(self, size: Vec2, offset: f32) -> Vec2 {
+        let (right, bottom) = match self {
+            ScreenCorner::TopLeft => (false, false),
+            ScreenCorner::TopRight => (true, false),
+            ScreenCorner::BottomLeft => (false, true),
+            ScreenCorner::BottomRight => (true, true),
+        };
+        Vec2::new(
+            if right { size.x - offset } else { offset },
+            if bottom { size.y - offset } else { offset },
+        )
+    }
+}
+
+/// Configuration for the [`OrientationGizmoPlugin`].
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct OrientationGizmoConfig {
+    /// The corner of the viewport the axes are drawn in.
+    ///
+    /// Defaults to [`ScreenCorner::BottomLeft`].
+    pub corner: ScreenCorner,
+    /// The distance from the corner of the viewport to the center of the axes in logical pixels,
+    /// both horizontally and vertically.
+    ///
+    /// Defaults to `50.0`.
+    pub margin: f32,
+    /// The length of the axes in logical pixels.
+    ///
+    /// Defaults to `30.0`.
+    pub length: f32,
+    /// The height of the labels at the end of the axes in logical pixels.
+    ///
+    /// No labels are drawn if `None`.
+    ///
+    /// Defaults to `Some(8.0)`.
+    pub label_size: Option<f32>,
+}
+
+impl Default for OrientationGizmoConfig {
+    fn default() -> Self {
+        Self {
+            corner: ScreenCorner::BottomLeft,
+            margin: 50.,
+            length: 30.,
+            label_size: Some(8.),
+        }
+    }
+}
+
+pub(crate) fn draw_orientation(
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let orientation_config = &config.orientation;
+    for (camera, camera_transform) in &cameras {
+        if !camera.is_active {
+            continue;
+        }
+        let mut screen = gizmos.screen(camera, camera_transform);
+        let Some(size) = screen.viewport_size() else {
+            continue;
+        };
+        let center = orientation_config
+            .corner
+            .position(size, orientation_config.margin);
+
+        let inverse_rotation = camera_transform.compute_transform().rotation.inverse();
+        let mut axes = [
+            (Vec3::X, Color::RED, "X"),
+            (Vec3::Y, Color::GREEN, "Y"),
+            (Vec3::Z, Color::BLUE, "Z"),
+        ]
+        .map(|(axis, color, label)| (inverse_rotation * axis, color, label));
+        // Draw the axes pointing away from the camera first, so the others are drawn over them.
+        axes.sort_by(|(a, ..), (b, ..)| a.z.total_cmp(&b.z));
+
+        for (direction, color, label) in axes {
+            // The viewport goes down while the view space goes up.
+            let end = center + Vec2::new(direction.x, -direction.y) * orientation_config.length;
+            screen.line(center, end, color);
+            if let Some(label_size) = orientation_config.label_size {
+                let offset = (end - center).normalize_or_zero() * label_size;
+                screen.text(end + offset, label, label_size, color);
+            }
+        }
+    }
+}
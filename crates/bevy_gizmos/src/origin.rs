@@ -0,0 +1,94 @@
+//! Gizmos for the world origin.
+//!
+//! Includes the [`OriginGizmoPlugin`] drawing a ground grid and the world axes.
+
+use crate::{gizmos::Gizmos, GizmoConfig};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::system::Res;
+use bevy_math::{Quat, UVec2, Vec2, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+use bevy_transform::components::Transform;
+use std::f32::consts::FRAC_PI_2;
+
+/// A [`Plugin`] that draws a grid on the XZ plane and the world axes at the origin,
+/// configured by [`GizmoConfig::origin`].
+///
+/// Unlike the other gizmo plugins, it isn't added by the [`GizmoPlugin`](crate::GizmoPlugin).
+pub struct OriginGizmoPlugin;
+
+impl Plugin for OriginGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, draw_origin);
+    }
+}
+
+/// Configuration for the [`OriginGizmoPlugin`].
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct OriginGizmoConfig {
+    /// The distance from the origin to the edges of the grid.
+    ///
+    /// It is rounded up to a whole number of major lines, so the grid ends on a major line.
+    ///
+    /// Defaults to `10.0`.
+    pub extent: f32,
+    /// The distance between two lines of the grid.
+    ///
+    /// Defaults to `1.0`.
+    pub spacing: f32,
+    /// The number of cells between two major lines.
+    ///
+    /// Defaults to `5`.
+    pub major_line_interval: u32,
+    /// The color of the lines of the grid.
+    ///
+    /// Defaults to `Color::rgba(0.5, 0.5, 0.5, 0.5)`.
+    pub color: Color,
+    /// The color of the major lines of the grid.
+    ///
+    /// Defaults to `Color::GRAY`.
+    pub major_color: Color,
+    /// The length of the world axes drawn at the origin.
+    ///
+    /// No axes are drawn if `None`.
+    ///
+    /// Defaults to `Some(1.0)`.
+    pub axes_length: Option<f32>,
+}
+
+impl Default for OriginGizmoConfig {
+    fn default() -> Self {
+        Self {
+            extent: 10.,
+            spacing: 1.,
+            major_line_interval: 5,
+            color: Color::rgba(0.5, 0.5, 0.5, 0.5),
+            major_color: Color::GRAY,
+            axes_length: Some(1.),
+        }
+    }
+}
+
+pub(crate) fn draw_origin(config: Res<GizmoConfig>, mut gizmos: Gizmos) {
+    let origin_config = &config.origin;
+    if let Some(length) = origin_config.axes_length {
+        gizmos.axes(Transform::IDENTITY, length);
+    }
+
+    if origin_config.spacing <= 0. {
+        return;
+    }
+    let interval = origin_config.major_line_interval.max(1);
+    let major_lines = (origin_config.extent / origin_config.spacing / interval as f32).ceil();
+    let cell_count = 2 * major_lines as u32 * interval;
+    gizmos
+        .grid(
+            Vec3::ZERO,
+            Quat::from_rotation_x(FRAC_PI_2),
+            UVec2::splat(cell_count),
+            Vec2::splat(origin_config.spacing),
+            origin_config.color,
+        )
+        .major_lines(interval, origin_config.major_color);
+}
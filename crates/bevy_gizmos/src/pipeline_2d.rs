@@ -1,6 +1,9 @@
 use crate::{
-    line_gizmo_vertex_buffer_layouts, DrawLineGizmo, GizmoConfig, GizmoRenderSystem, LineGizmo,
-    LineGizmoUniformBindgroupLayout, SetLineGizmoBindGroup, LINE_SHADER_HANDLE,
+    cull_line_gizmo_chunks, is_gizmo_camera, is_line_gizmo_visible, is_screenshot_view,
+    line_gizmo_shader, line_gizmo_vertex_state, DrawLineGizmo, GizmoCamera, GizmoConfig,
+    GizmoInScreenshots, GizmoRenderSystem, GpuLineGizmos, LineGizmo,
+    LineGizmoStorageBindGroupLayout, LineGizmoUniformBindgroupLayout, LineGizmoVertexInput,
+    SetLineGizmoBindGroup, VisibleLineGizmoChunks,
 };
 use bevy_app::{App, Plugin};
 use bevy_asset::Handle;
@@ -8,16 +11,17 @@ use bevy_core_pipeline::core_2d::Transparent2d;
 
 use bevy_ecs::{
     prelude::Entity,
+    query::Has,
     schedule::{IntoSystemConfigs, IntoSystemSetConfigs},
     system::{Query, Res, ResMut, Resource},
     world::{FromWorld, World},
 };
 use bevy_render::{
-    render_asset::{prepare_assets, RenderAssets},
+    camera::ExtractedCamera,
     render_phase::{AddRenderCommand, DrawFunctions, RenderPhase, SetItemPipeline},
     render_resource::*,
     texture::BevyDefault,
-    view::{ExtractedView, Msaa, RenderLayers, ViewTarget},
+    view::{ExtractedView, ExtractedWindows, Msaa, RenderLayers, ViewTarget},
     Render, RenderApp, RenderSet,
 };
 use bevy_sprite::{Mesh2dPipeline, Mesh2dPipelineKey, SetMesh2dViewBindGroup};
@@ -42,7 +46,7 @@ impl Plugin for LineGizmo2dPlugin {
                 Render,
                 queue_line_gizmos_2d
                     .in_set(GizmoRenderSystem::QueueLineGizmos2d)
-                    .after(prepare_assets::<LineGizmo>),
+                    .after(cull_line_gizmo_chunks),
             );
     }
 
@@ -59,6 +63,7 @@ impl Plugin for LineGizmo2dPlugin {
 struct LineGizmoPipeline {
     mesh_pipeline: Mesh2dPipeline,
     uniform_layout: BindGroupLayout,
+    storage_layout: Option<BindGroupLayout>,
 }
 
 impl FromWorld for LineGizmoPipeline {
@@ -69,6 +74,9 @@ impl FromWorld for LineGizmoPipeline {
                 .resource::<LineGizmoUniformBindgroupLayout>()
                 .layout
                 .clone(),
+            storage_layout: render_world
+                .get_resource::<LineGizmoStorageBindGroupLayout>()
+                .map(|layout| layout.layout.clone()),
         }
     }
 }
@@ -76,7 +84,10 @@ impl FromWorld for LineGizmoPipeline {
 #[derive(PartialEq, Eq, Hash, Clone)]
 struct LineGizmoPipelineKey {
     mesh_key: Mesh2dPipelineKey,
+    shader: Handle<Shader>,
     strip: bool,
+    vertex_input: LineGizmoVertexInput,
+    color_format: VertexFormat,
 }
 
 impl SpecializedRenderPipeline for LineGizmoPipeline {
@@ -89,25 +100,26 @@ impl SpecializedRenderPipeline for LineGizmoPipeline {
             TextureFormat::bevy_default()
         };
 
-        let shader_defs = vec![
-            #[cfg(feature = "webgl")]
-            "SIXTEEN_BYTE_ALIGNMENT".into(),
-        ];
+        let shader_defs: Vec<ShaderDefVal> = Vec::new();
 
-        let layout = vec![
+        let mut layout = vec![
             self.mesh_pipeline.view_layout.clone(),
             self.uniform_layout.clone(),
         ];
+        if key.vertex_input != LineGizmoVertexInput::VertexBuffers {
+            layout.extend(self.storage_layout.clone());
+        }
 
         RenderPipelineDescriptor {
-            vertex: VertexState {
-                shader: LINE_SHADER_HANDLE,
-                entry_point: "vertex".into(),
-                shader_defs: shader_defs.clone(),
-                buffers: line_gizmo_vertex_buffer_layouts(key.strip),
-            },
+            vertex: line_gizmo_vertex_state(
+                key.shader.clone_weak(),
+                key.strip,
+                key.vertex_input,
+                key.color_format,
+                shader_defs.clone(),
+            ),
             fragment: Some(FragmentState {
-                shader: LINE_SHADER_HANDLE,
+                shader: key.shader,
                 shader_defs,
                 entry_point: "fragment".into(),
                 targets: vec![Some(ColorTargetState {
@@ -145,35 +157,53 @@ fn queue_line_gizmos_2d(
     pipeline_cache: Res<PipelineCache>,
     msaa: Res<Msaa>,
     config: Res<GizmoConfig>,
-    line_gizmos: Query<(Entity, &Handle<LineGizmo>)>,
-    line_gizmo_assets: Res<RenderAssets<LineGizmo>>,
+    line_gizmos: Query<(Entity, &Handle<LineGizmo>, &GizmoInScreenshots)>,
+    gpu_line_gizmos: Res<GpuLineGizmos>,
+    visible_chunks: Res<VisibleLineGizmoChunks>,
+    windows: Res<ExtractedWindows>,
     mut views: Query<(
+        Entity,
         &ExtractedView,
         &mut RenderPhase<Transparent2d>,
         Option<&RenderLayers>,
+        Option<&ExtractedCamera>,
+        Has<GizmoCamera>,
     )>,
 ) {
     let draw_function = draw_functions.read().get_id::<DrawLineGizmo2d>().unwrap();
+    let shader = line_gizmo_shader(&config);
 
-    for (view, mut transparent_phase, render_layers) in &mut views {
+    for (view_entity, view, mut transparent_phase, render_layers, camera, marked) in &mut views {
         let render_layers = render_layers.copied().unwrap_or_default();
-        if !config.render_layers.intersects(&render_layers) {
+        if !config.render_layers.intersects(&render_layers)
+            || !is_gizmo_camera(config.cameras, camera, marked, &windows)
+        {
             continue;
         }
         let mesh_key = Mesh2dPipelineKey::from_msaa_samples(msaa.samples())
             | Mesh2dPipelineKey::from_hdr(view.hdr);
 
-        for (entity, handle) in &line_gizmos {
-            let Some(line_gizmo) = line_gizmo_assets.get(handle) else {
+        let screenshot = is_screenshot_view(camera, &windows);
+        for (entity, handle, in_screenshots) in &line_gizmos {
+            if screenshot && !in_screenshots.0 {
+                continue;
+            }
+            let Some(line_gizmo) = gpu_line_gizmos.0.get(&handle.id()) else {
                 continue;
             };
+            if !is_line_gizmo_visible(&visible_chunks, view_entity, handle.id()) {
+                continue;
+            }
 
             let pipeline = pipelines.specialize(
                 &pipeline_cache,
                 &pipeline,
                 LineGizmoPipelineKey {
                     mesh_key,
+                    shader: shader.clone_weak(),
                     strip: line_gizmo.strip,
+                    vertex_input: line_gizmo.vertex_input(),
+                    color_format: line_gizmo.color_format,
                 },
             );
 
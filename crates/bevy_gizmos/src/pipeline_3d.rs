@@ -1,6 +1,9 @@
 use crate::{
-    line_gizmo_vertex_buffer_layouts, DrawLineGizmo, GizmoConfig, GizmoRenderSystem, LineGizmo,
-    LineGizmoUniformBindgroupLayout, SetLineGizmoBindGroup, LINE_SHADER_HANDLE,
+    cull_line_gizmo_chunks, is_gizmo_camera, is_line_gizmo_visible, is_screenshot_view,
+    line_gizmo_shader, line_gizmo_vertex_state, DrawLineGizmo, GizmoCamera, GizmoConfig,
+    GizmoInScreenshots, GizmoRenderSystem, GpuLineGizmos, LineGizmo,
+    LineGizmoStorageBindGroupLayout, LineGizmoUniformBindgroupLayout, LineGizmoVertexInput,
+    SetLineGizmoBindGroup, VisibleLineGizmoChunks,
 };
 use bevy_app::{App, Plugin};
 use bevy_asset::Handle;
@@ -18,11 +21,11 @@ use bevy_ecs::{
 };
 use bevy_pbr::{MeshPipeline, MeshPipelineKey, SetMeshViewBindGroup};
 use bevy_render::{
-    render_asset::{prepare_assets, RenderAssets},
+    camera::ExtractedCamera,
     render_phase::{AddRenderCommand, DrawFunctions, RenderPhase, SetItemPipeline},
     render_resource::*,
     texture::BevyDefault,
-    view::{ExtractedView, Msaa, RenderLayers, ViewTarget},
+    view::{ExtractedView, ExtractedWindows, Msaa, RenderLayers, ViewTarget},
     Render, RenderApp, RenderSet,
 };
 
@@ -44,7 +47,7 @@ impl Plugin for LineGizmo3dPlugin {
                 Render,
                 queue_line_gizmos_3d
                     .in_set(GizmoRenderSystem::QueueLineGizmos3d)
-                    .after(prepare_assets::<LineGizmo>),
+                    .after(cull_line_gizmo_chunks),
             );
     }
 
@@ -61,6 +64,7 @@ impl Plugin for LineGizmo3dPlugin {
 struct LineGizmoPipeline {
     mesh_pipeline: MeshPipeline,
     uniform_layout: BindGroupLayout,
+    storage_layout: Option<BindGroupLayout>,
 }
 
 impl FromWorld for LineGizmoPipeline {
@@ -71,6 +75,9 @@ impl FromWorld for LineGizmoPipeline {
                 .resource::<LineGizmoUniformBindgroupLayout>()
                 .layout
                 .clone(),
+            storage_layout: render_world
+                .get_resource::<LineGizmoStorageBindGroupLayout>()
+                .map(|layout| layout.layout.clone()),
         }
     }
 }
@@ -78,7 +85,10 @@ impl FromWorld for LineGizmoPipeline {
 #[derive(PartialEq, Eq, Hash, Clone)]
 struct LineGizmoPipelineKey {
     view_key: MeshPipelineKey,
+    shader: Handle<Shader>,
     strip: bool,
+    vertex_input: LineGizmoVertexInput,
+    color_format: VertexFormat,
     perspective: bool,
 }
 
@@ -86,10 +96,7 @@ impl SpecializedRenderPipeline for LineGizmoPipeline {
     type Key = LineGizmoPipelineKey;
 
     fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
-        let mut shader_defs = vec![
-            #[cfg(feature = "webgl")]
-            "SIXTEEN_BYTE_ALIGNMENT".into(),
-        ];
+        let mut shader_defs: Vec<ShaderDefVal> = Vec::new();
 
         if key.perspective {
             shader_defs.push("PERSPECTIVE".into());
@@ -106,17 +113,21 @@ impl SpecializedRenderPipeline for LineGizmoPipeline {
             .get_view_layout(key.view_key.into())
             .clone();
 
-        let layout = vec![view_layout, self.uniform_layout.clone()];
+        let mut layout = vec![view_layout, self.uniform_layout.clone()];
+        if key.vertex_input != LineGizmoVertexInput::VertexBuffers {
+            layout.extend(self.storage_layout.clone());
+        }
 
         RenderPipelineDescriptor {
-            vertex: VertexState {
-                shader: LINE_SHADER_HANDLE,
-                entry_point: "vertex".into(),
-                shader_defs: shader_defs.clone(),
-                buffers: line_gizmo_vertex_buffer_layouts(key.strip),
-            },
+            vertex: line_gizmo_vertex_state(
+                key.shader.clone_weak(),
+                key.strip,
+                key.vertex_input,
+                key.color_format,
+                shader_defs.clone(),
+            ),
             fragment: Some(FragmentState {
-                shader: LINE_SHADER_HANDLE,
+                shader: key.shader,
                 shader_defs,
                 entry_point: "fragment".into(),
                 targets: vec![Some(ColorTargetState {
@@ -160,12 +171,17 @@ fn queue_line_gizmos_3d(
     pipeline_cache: Res<PipelineCache>,
     msaa: Res<Msaa>,
     config: Res<GizmoConfig>,
-    line_gizmos: Query<(Entity, &Handle<LineGizmo>)>,
-    line_gizmo_assets: Res<RenderAssets<LineGizmo>>,
+    line_gizmos: Query<(Entity, &Handle<LineGizmo>, &GizmoInScreenshots)>,
+    gpu_line_gizmos: Res<GpuLineGizmos>,
+    visible_chunks: Res<VisibleLineGizmoChunks>,
+    windows: Res<ExtractedWindows>,
     mut views: Query<(
+        Entity,
         &ExtractedView,
         &mut RenderPhase<Transparent3d>,
         Option<&RenderLayers>,
+        Option<&ExtractedCamera>,
+        Has<GizmoCamera>,
         (
             Has<NormalPrepass>,
             Has<DepthPrepass>,
@@ -175,16 +191,22 @@ fn queue_line_gizmos_3d(
     )>,
 ) {
     let draw_function = draw_functions.read().get_id::<DrawLineGizmo3d>().unwrap();
+    let shader = line_gizmo_shader(&config);
 
     for (
+        view_entity,
         view,
         mut transparent_phase,
         render_layers,
+        camera,
+        marked,
         (normal_prepass, depth_prepass, motion_vector_prepass, deferred_prepass),
     ) in &mut views
     {
         let render_layers = render_layers.copied().unwrap_or_default();
-        if !config.render_layers.intersects(&render_layers) {
+        if !config.render_layers.intersects(&render_layers)
+            || !is_gizmo_camera(config.cameras, camera, marked, &windows)
+        {
             continue;
         }
 
@@ -207,17 +229,27 @@ fn queue_line_gizmos_3d(
             view_key |= MeshPipelineKey::DEFERRED_PREPASS;
         }
 
-        for (entity, handle) in &line_gizmos {
-            let Some(line_gizmo) = line_gizmo_assets.get(handle) else {
+        let screenshot = is_screenshot_view(camera, &windows);
+        for (entity, handle, in_screenshots) in &line_gizmos {
+            if screenshot && !in_screenshots.0 {
+                continue;
+            }
+            let Some(line_gizmo) = gpu_line_gizmos.0.get(&handle.id()) else {
                 continue;
             };
+            if !is_line_gizmo_visible(&visible_chunks, view_entity, handle.id()) {
+                continue;
+            }
 
             let pipeline = pipelines.specialize(
                 &pipeline_cache,
                 &pipeline,
                 LineGizmoPipelineKey {
                     view_key,
+                    shader: shader.clone_weak(),
                     strip: line_gizmo.strip,
+                    vertex_input: line_gizmo.vertex_input(),
+                    color_format: line_gizmo.color_format,
                     perspective: config.line_perspective,
                 },
             );
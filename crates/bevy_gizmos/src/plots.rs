@@ -0,0 +1,191 @@
+//! Scrolling plots of values recorded by any system.
+//!
+//! Includes the [`GizmoPlots`] resource values are recorded in and the system drawing them.
+
+use crate::{color_from_index, gizmos::Gizmos, GizmoConfig};
+use bevy_ecs::system::{Query, Res, ResMut, Resource};
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{camera::Camera, color::Color};
+use bevy_transform::components::GlobalTransform;
+use std::collections::VecDeque;
+
+/// Configuration for drawing the plots recorded in [`GizmoPlots`].
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct PlotConfig {
+    /// The position of the top left corner of the first plot,
+    /// in logical pixels from the top left corner of the viewport.
+    ///
+    /// The other plots are stacked below it.
+    ///
+    /// Defaults to `Vec2::new(10.0, 100.0)`.
+    pub position: Vec2,
+    /// The size of each plot in logical pixels.
+    ///
+    /// Defaults to `Vec2::new(240.0, 60.0)`.
+    pub size: Vec2,
+    /// The vertical space between two plots in logical pixels.
+    ///
+    /// Defaults to `10.0`.
+    pub spacing: f32,
+    /// The number of values each plot spans.
+    ///
+    /// Older values are dropped.
+    ///
+    /// Defaults to `240`.
+    pub history_length: usize,
+    /// The color of the frame and the label of the plots.
+    ///
+    /// Defaults to `Color::GRAY`.
+    pub frame_color: Color,
+}
+
+impl Default for PlotConfig {
+    fn default() -> Self {
+        Self {
+            position: Vec2::new(10., 100.),
+            size: Vec2::new(240., 60.),
+            spacing: 10.,
+            history_length: 240,
+            frame_color: Color::GRAY,
+        }
+    }
+}
+
+/// A [`Resource`] that any system can record named values in to have their history plotted
+/// as scrolling line graphs in the viewport of the active camera with the highest
+/// [`Camera::order`].
+///
+/// Each plot spans the range of the values it shows, and is labeled with its name
+/// and its latest value.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # #[derive(Component)]
+/// # struct Enemy;
+/// fn system(mut plots: ResMut<GizmoPlots>, enemies: Query<(), With<Enemy>>) {
+///     plots.push("enemy_count", enemies.iter().count() as f32);
+///     plots.set_color("enemy_count", Color::RED);
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct GizmoPlots {
+    plots: Vec<Plot>,
+}
+
+#[derive(Debug)]
+struct Plot {
+    name: String,
+    values: VecDeque<f32>,
+    color: Option<Color>,
+}
+
+impl GizmoPlots {
+    /// Record `value` in the plot named `name`, adding it if it doesn't exist yet.
+    pub fn push(&mut self, name: &str, value: f32) {
+        // Gizmos are never drawn in stripped or headless builds, so don't let values pile up.
+        if crate::gizmos::DISABLED {
+            return;
+        }
+        self.plot_mut(name).values.push_back(value);
+    }
+
+    /// Set the color of the plot named `name`, adding it if it doesn't exist yet.
+    ///
+    /// Plots are colored by the order they were added in by default.
+    pub fn set_color(&mut self, name: &str, color: Color) {
+        self.plot_mut(name).color = Some(color);
+    }
+
+    /// Remove the plot named `name`.
+    pub fn remove(&mut self, name: &str) {
+        self.plots.retain(|plot| plot.name != name);
+    }
+
+    /// Remove all plots.
+    pub fn clear(&mut self) {
+        self.plots.clear();
+    }
+
+    fn plot_mut(&mut self, name: &str) -> &mut Plot {
+        let index = match self.plots.iter().position(|plot| plot.name == name) {
+            Some(index) => index,
+            None => {
+                self.plots.push(Plot {
+                    name: name.to_owned(),
+                    values: VecDeque::new(),
+                    color: None,
+                });
+                self.plots.len() - 1
+            }
+        };
+        &mut self.plots[index]
+    }
+}
+
+/// The height of the labels of the plots in logical pixels.
+const LABEL_SIZE: f32 = 10.;
+
+pub(crate) fn draw_plots(
+    mut plots: ResMut<GizmoPlots>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let plot_config = &config.plots;
+    for plot in &mut plots.plots {
+        let excess = plot.values.len().saturating_sub(plot_config.history_length);
+        plot.values.drain(..excess);
+    }
+    if plots.plots.is_empty() {
+        return;
+    }
+
+    let Some((camera, camera_transform)) = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+    else {
+        return;
+    };
+    let mut screen = gizmos.screen(camera, camera_transform);
+
+    let size = plot_config.size;
+    for (i, plot) in plots.plots.iter().enumerate() {
+        let min = plot_config.position + Vec2::Y * i as f32 * (size.y + plot_config.spacing);
+        screen.rect(min + size / 2., size, plot_config.frame_color);
+
+        let (low, high) = plot
+            .values
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(low, high), &value| {
+                (low.min(value), high.max(value))
+            });
+        // Center constant values vertically.
+        let (low, high) = if low < high {
+            (low, high)
+        } else {
+            (low - 1., high + 1.)
+        };
+        let color = plot.color.unwrap_or_else(|| color_from_index(i as u32));
+        screen.graph(
+            min,
+            size,
+            plot.values.iter().copied(),
+            plot_config.history_length,
+            low..=high,
+            color,
+        );
+
+        if let Some(value) = plot.values.back() {
+            let label = format!("{}: {value:.2}", plot.name);
+            let position = Vec2::new(min.x + size.x / 2., min.y + LABEL_SIZE);
+            screen.text(position, &label, LABEL_SIZE, plot_config.frame_color);
+        }
+    }
+}
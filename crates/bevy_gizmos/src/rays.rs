@@ -0,0 +1,314 @@
+//! Gizmos for rays cast by any system.
+//!
+//! Includes the implementation of [`Gizmos::ray_hit`],
+//! and the [`DebugRays`] resource rays are recorded in and the system drawing them.
+
+use crate::{gizmos::Gizmos, GizmoConfig};
+use bevy_ecs::system::{Local, Res, ResMut, Resource};
+use bevy_math::{Quat, Ray3d, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+use bevy_time::Time;
+
+impl<'s> Gizmos<'s> {
+    /// Draw a ray cast from `origin` along `direction`, with the distance and surface normal
+    /// of its `hit` if any.
+    ///
+    /// `direction` can be a [`Direction3d`](bevy_math::primitives::Direction3d),
+    /// or a [`Vec3`] that must be normalized.
+    ///
+    /// The ray is drawn up to the hit point, with a circle on the surface around it
+    /// and an arrow along the normal, or with a length of `10.0` if it missed.
+    ///
+    /// This should be called for each frame the ray needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_math::primitives::Direction3d;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.ray_hit(Vec3::ZERO, Vec3::NEG_Z, Some((5., Vec3::Z)), Color::YELLOW);
+    ///     let direction = Direction3d::new(Vec3::new(1., -1., 0.)).unwrap();
+    ///     gizmos.ray_hit(Vec3::Y, direction, Some((1.4, Vec3::Y)), Color::YELLOW);
+    ///
+    ///     // The length of missed rays and the size of the hit marker can be changed.
+    ///     gizmos
+    ///         .ray_hit(Vec3::ZERO, Vec3::X, None, Color::YELLOW)
+    ///         .max_length(100.)
+    ///         .marker_size(0.5);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn ray_hit(
+        &mut self,
+        origin: impl Into<Vec3>,
+        direction: impl Into<Vec3>,
+        hit: Option<(f32, Vec3)>,
+        color: Color,
+    ) -> RayHitBuilder<'_, 's> {
+        let direction = direction.into();
+        debug_assert!(
+            direction.is_normalized(),
+            "the direction of a ray must be normalized, got {direction}"
+        );
+        RayHitBuilder {
+            gizmos: self,
+            origin: origin.into(),
+            direction,
+            hit,
+            color,
+            max_length: 10.,
+            marker_size: 0.2,
+        }
+    }
+}
+
+/// A builder returned by [`Gizmos::ray_hit`].
+pub struct RayHitBuilder<'a, 's> {
+    gizmos: &'a mut Gizmos<'s>,
+    origin: Vec3,
+    direction: Vec3,
+    hit: Option<(f32, Vec3)>,
+    color: Color,
+    max_length: f32,
+    marker_size: f32,
+}
+
+impl RayHitBuilder<'_, '_> {
+    /// Set the length the ray is drawn with if it missed.
+    ///
+    /// Defaults to `10.0`.
+    pub fn max_length(mut self, length: f32) -> Self {
+        self.max_length = length;
+        self
+    }
+
+    /// Set the radius of the circle marking the hit point,
+    /// the arrow along the normal being drawn with twice this length.
+    ///
+    /// Defaults to `0.2`.
+    pub fn marker_size(mut self, size: f32) -> Self {
+        self.marker_size = size;
+        self
+    }
+}
+
+impl Drop for RayHitBuilder<'_, '_> {
+    fn drop(&mut self) {
+        let Some((distance, normal)) = self.hit else {
+            let end = self.origin + self.direction * self.max_length;
+            self.gizmos.line(self.origin, end, self.color);
+            return;
+        };
+        let point = self.origin + self.direction * distance;
+        self.gizmos.line(self.origin, point, self.color);
+
+        let Some(normal) = normal.try_normalize() else {
+            return;
+        };
+        let rotation = Quat::from_rotation_arc(Vec3::Z, normal);
+        self.gizmos
+            .circle(point, normal, self.marker_size, self.color)
+            .segments(16);
+        // A cross on the surface makes the exact hit point visible.
+        for axis in [Vec3::X, Vec3::Y] {
+            let offset = rotation * axis * self.marker_size / 2.;
+            self.gizmos.line(point - offset, point + offset, self.color);
+        }
+        self.gizmos
+            .arrow(point, point + normal * self.marker_size * 2., self.color);
+    }
+}
+
+/// Configuration for drawing the rays recorded in [`DebugRays`].
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct DebugRayGizmoConfig {
+    /// The default color of rays.
+    ///
+    /// Defaults to `Color::YELLOW`.
+    pub color: Color,
+    /// The color of the crosses marking hit points.
+    ///
+    /// Defaults to `Color::RED`.
+    pub hit_color: Color,
+    /// The length rays without hit points are drawn with.
+    ///
+    /// Rays with hit points are drawn up to their farthest hit point.
+    ///
+    /// Defaults to `10.0`.
+    pub length: f32,
+    /// The size of the crosses marking hit points.
+    ///
+    /// Defaults to `0.1`.
+    pub hit_size: f32,
+    /// The default number of seconds rays are drawn for.
+    ///
+    /// Rays are drawn for a single frame if `0.0`.
+    ///
+    /// Defaults to `0.0`.
+    pub duration: f32,
+    /// Fades rays out over their duration when set to `true`.
+    ///
+    /// Defaults to `true`.
+    pub fade: bool,
+}
+
+impl Default for DebugRayGizmoConfig {
+    fn default() -> Self {
+        Self {
+            color: Color::YELLOW,
+            hit_color: Color::RED,
+            length: 10.,
+            hit_size: 0.1,
+            duration: 0.,
+            fade: true,
+        }
+    }
+}
+
+/// A ray recorded in [`DebugRays`], with its optional hit points.
+#[derive(Clone, Debug)]
+pub struct DebugRay {
+    /// The ray.
+    pub ray: Ray3d,
+    /// The points the ray hit.
+    pub hits: Vec<Vec3>,
+    /// The color of the ray.
+    ///
+    /// The color from the [`GizmoConfig`] resource is used if `None`.
+    pub color: Option<Color>,
+    /// The number of seconds the ray is drawn for.
+    ///
+    /// The duration from the [`GizmoConfig`] resource is used if `None`.
+    pub duration: Option<f32>,
+}
+
+impl DebugRay {
+    /// Create a new [`DebugRay`] without hit points.
+    pub fn new(ray: Ray3d) -> Self {
+        Self {
+            ray,
+            hits: Vec::new(),
+            color: None,
+            duration: None,
+        }
+    }
+
+    /// Add a point the ray hit.
+    pub fn with_hit(mut self, hit: Vec3) -> Self {
+        self.hits.push(hit);
+        self
+    }
+
+    /// Set the color of the ray.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the number of seconds the ray is drawn for.
+    pub fn with_duration(mut self, seconds: f32) -> Self {
+        self.duration = Some(seconds);
+        self
+    }
+}
+
+impl From<Ray3d> for DebugRay {
+    fn from(ray: Ray3d) -> Self {
+        Self::new(ray)
+    }
+}
+
+/// A [`Resource`] that any system can record rays in to have them drawn,
+/// including systems without a [`Gizmos`] parameter such as picking backends.
+///
+/// Recorded rays are drained and drawn in `PostUpdate`.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use bevy_render::prelude::*;
+/// fn system(mut rays: ResMut<DebugRays>) {
+///     let ray = Ray3d::new(Vec3::ZERO, Vec3::NEG_Z);
+///     rays.push(ray);
+///     rays.push(
+///         DebugRay::new(ray)
+///             .with_hit(Vec3::new(0., 0., -5.))
+///             .with_color(Color::GREEN)
+///             .with_duration(2.),
+///     );
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct DebugRays {
+    rays: Vec<DebugRay>,
+}
+
+impl DebugRays {
+    /// Record a ray to be drawn.
+    pub fn push(&mut self, ray: impl Into<DebugRay>) {
+        // Gizmos are never drawn in stripped or headless builds, so don't let rays pile up.
+        if crate::gizmos::DISABLED {
+            return;
+        }
+        self.rays.push(ray.into());
+    }
+
+    /// Record a ray to be drawn with a single hit point, if any.
+    pub fn push_hit(&mut self, ray: Ray3d, hit: Option<Vec3>) {
+        let mut ray = DebugRay::new(ray);
+        ray.hits.extend(hit);
+        self.push(ray);
+    }
+}
+
+pub(crate) fn draw_debug_rays(
+    mut rays: ResMut<DebugRays>,
+    mut drawn: Local<Vec<(DebugRay, f32)>>,
+    time: Res<Time>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let rays_config = &config.debug_rays;
+    let now = time.elapsed_seconds();
+    drawn.extend(rays.rays.drain(..).map(|ray| (ray, now)));
+
+    drawn.retain(|(ray, recorded)| {
+        let duration = ray.duration.unwrap_or(rays_config.duration);
+        let age = now - recorded;
+        if age > duration {
+            return false;
+        }
+        let alpha = if rays_config.fade && duration > 0. {
+            1. - age / duration
+        } else {
+            1.
+        };
+        let fade = |color: Color| color.with_a(color.a() * alpha);
+
+        let Ray3d { origin, direction } = ray.ray;
+        let length = ray
+            .hits
+            .iter()
+            .map(|&hit| (hit - origin).dot(*direction))
+            .reduce(f32::max)
+            .unwrap_or(rays_config.length);
+        let color = fade(ray.color.unwrap_or(rays_config.color));
+        gizmos.line(origin, ray.ray.get_point(length), color);
+
+        let hit_color = fade(rays_config.hit_color);
+        for &hit in &ray.hits {
+            for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+                let offset = axis * rays_config.hit_size / 2.;
+                gizmos.line(hit - offset, hit + offset, hit_color);
+            }
+        }
+        true
+    });
+}
@@ -0,0 +1,370 @@
+//! Recording and playback of the gizmos drawn each frame.
+//!
+//! Includes the [`GizmoRecorder`] resource and the system feeding it.
+
+use crate::gizmos::GizmoStorage;
+use bevy_ecs::system::{ResMut, Resource};
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Write},
+};
+
+/// The bytes every recording written by [`GizmoRecorder::save`] starts with.
+const MAGIC: &[u8; 8] = b"GIZMOREC";
+
+/// The version of the format written by [`GizmoRecorder::save`].
+const VERSION: u32 = 1;
+
+/// A [`Resource`] that records the lines drawn each frame, and plays them back
+/// in place of the live gizmos to step through them.
+///
+/// Recordings can be saved to and loaded from any [`Write`] and [`Read`] implementor,
+/// such as a file, to inspect them in another session.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::prelude::*;
+/// fn system(mut recorder: ResMut<GizmoRecorder>, keys: Res<ButtonInput<KeyCode>>) {
+///     if keys.just_pressed(KeyCode::KeyR) {
+///         recorder.start_recording();
+///     }
+///     if keys.just_pressed(KeyCode::KeyP) {
+///         recorder.stop_recording();
+///         recorder.play(0);
+///     }
+///     if keys.just_pressed(KeyCode::ArrowLeft) {
+///         recorder.step(-1);
+///     }
+///     if keys.just_pressed(KeyCode::ArrowRight) {
+///         recorder.step(1);
+///     }
+///     if keys.just_pressed(KeyCode::Escape) {
+///         recorder.stop_playback();
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+#[derive(Resource, Debug)]
+pub struct GizmoRecorder {
+    /// The maximum number of frames kept, the oldest ones being dropped first.
+    ///
+    /// Defaults to `600`.
+    pub max_frames: usize,
+    frames: VecDeque<GizmoFrame>,
+    recording: bool,
+    playback: Option<usize>,
+}
+
+impl Default for GizmoRecorder {
+    fn default() -> Self {
+        Self {
+            max_frames: 600,
+            frames: VecDeque::new(),
+            recording: false,
+            playback: None,
+        }
+    }
+}
+
+/// The lines drawn during a frame.
+#[derive(Clone, Default, Debug, PartialEq)]
+struct GizmoFrame {
+    list_positions: Vec<[f32; 3]>,
+    list_colors: Vec<[f32; 4]>,
+    strip_positions: Vec<[f32; 3]>,
+    strip_colors: Vec<[f32; 4]>,
+}
+
+impl GizmoRecorder {
+    /// Start recording the gizmos drawn each frame, after the frames recorded so far.
+    pub fn start_recording(&mut self) {
+        self.recording = true;
+    }
+
+    /// Stop recording gizmos.
+    pub fn stop_recording(&mut self) {
+        self.recording = false;
+    }
+
+    /// Whether gizmos are being recorded.
+    ///
+    /// Nothing is recorded during playback.
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// The number of recorded frames.
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Remove all recorded frames, stopping playback.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.playback = None;
+    }
+
+    /// Draw the recorded frame at `index` instead of the live gizmos until playback is stopped.
+    ///
+    /// The index is clamped to the recorded frames.
+    pub fn play(&mut self, index: usize) {
+        self.playback = Some(index.min(self.frames.len().saturating_sub(1)));
+    }
+
+    /// Move the played back frame by `frames`, backwards if negative,
+    /// starting playback from the last recorded frame if it wasn't playing.
+    pub fn step(&mut self, frames: isize) {
+        let index = self.playback.unwrap_or(self.frames.len().saturating_sub(1));
+        self.play(index.saturating_add_signed(frames));
+    }
+
+    /// Go back to drawing the live gizmos.
+    pub fn stop_playback(&mut self) {
+        self.playback = None;
+    }
+
+    /// The index of the played back frame, if any.
+    pub fn playback_frame(&self) -> Option<usize> {
+        self.playback
+    }
+
+    /// Write the recorded frames to `writer`, to be read back with [`GizmoRecorder::load`].
+    pub fn save(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.frames.len() as u64).to_le_bytes())?;
+        for frame in &self.frames {
+            write_items(&mut writer, &frame.list_positions)?;
+            write_items(&mut writer, &frame.list_colors)?;
+            write_items(&mut writer, &frame.strip_positions)?;
+            write_items(&mut writer, &frame.strip_colors)?;
+        }
+        Ok(())
+    }
+
+    /// Replace the recorded frames with the ones written by [`GizmoRecorder::save`] to `reader`,
+    /// stopping playback.
+    pub fn load(&mut self, mut reader: impl Read) -> io::Result<()> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not a gizmo recording",
+            ));
+        }
+        let version = u32::from_le_bytes(read_bytes(&mut reader)?);
+        if version != VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported gizmo recording version {version}"),
+            ));
+        }
+        let frame_count = u64::from_le_bytes(read_bytes(&mut reader)?);
+
+        let mut frames = VecDeque::new();
+        for _ in 0..frame_count {
+            frames.push_back(GizmoFrame {
+                list_positions: read_items(&mut reader)?,
+                list_colors: read_items(&mut reader)?,
+                strip_positions: read_items(&mut reader)?,
+                strip_colors: read_items(&mut reader)?,
+            });
+        }
+        self.frames = frames;
+        self.playback = None;
+        Ok(())
+    }
+}
+
+fn write_items<const N: usize>(writer: &mut impl Write, items: &[[f32; N]]) -> io::Result<()> {
+    writer.write_all(&(items.len() as u64).to_le_bytes())?;
+    for value in items.iter().flatten() {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+fn read_items<const N: usize>(reader: &mut impl Read) -> io::Result<Vec<[f32; N]>> {
+    let len = u64::from_le_bytes(read_bytes(reader)?);
+    // Don't trust the length with the allocation, in case the data is corrupted.
+    let mut items = Vec::new();
+    for _ in 0..len {
+        let mut item = [0.; N];
+        for value in &mut item {
+            *value = f32::from_le_bytes(read_bytes(reader)?);
+        }
+        items.push(item);
+    }
+    Ok(items)
+}
+
+fn read_bytes<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Records the gizmos of this frame, or replaces them with the played back frame.
+pub(crate) fn record_gizmos(
+    mut recorder: ResMut<GizmoRecorder>,
+    mut storage: ResMut<GizmoStorage>,
+) {
+    let recorder = &mut *recorder;
+    if let Some(index) = recorder.playback {
+        let Some(frame) = recorder.frames.get(index) else {
+            return;
+        };
+        storage.list_positions.clone_from(&frame.list_positions);
+        storage.list_colors.clone_from(&frame.list_colors);
+        storage.strip_positions.clone_from(&frame.strip_positions);
+        storage.strip_colors.clone_from(&frame.strip_colors);
+        storage.chunks.clear();
+        storage.tags.clear();
+        storage.views.clear();
+    } else if recorder.recording {
+        let mut frame = if recorder.frames.len() >= recorder.max_frames {
+            // Reuse the allocations of the oldest frame.
+            recorder.frames.pop_front().unwrap_or_default()
+        } else {
+            GizmoFrame::default()
+        };
+        frame.list_positions.clone_from(&storage.list_positions);
+        frame.list_colors.clone_from(&storage.list_colors);
+        frame.strip_positions.clone_from(&storage.strip_positions);
+        frame.strip_colors.clone_from(&storage.strip_colors);
+        if recorder.max_frames > 0 {
+            recorder.frames.push_back(frame);
+        }
+        while recorder.frames.len() > recorder.max_frames {
+            recorder.frames.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, Last, Update};
+    use bevy_ecs::system::{Local, Res, RunSystemOnce};
+    use bevy_math::Vec3;
+    use bevy_render::color::Color;
+
+    use super::{record_gizmos, GizmoFrame, GizmoRecorder};
+    use crate::{
+        gizmos::{GizmoStorage, Gizmos},
+        testing::GizmoTestPlugin,
+    };
+
+    /// Draws different lines every frame.
+    fn draw(mut gizmos: Gizmos, mut frame: Local<f32>) {
+        gizmos.line(Vec3::ZERO, Vec3::X * *frame, Color::RED);
+        gizmos.linestrip([Vec3::Y, Vec3::Z * *frame], Color::BLUE);
+        *frame += 1.;
+    }
+
+    /// The bits of the lines drawn this frame, to compare the NaN separators of strips too.
+    fn drawn_frame(storage: Res<GizmoStorage>) -> Vec<u32> {
+        frame_bits(&GizmoFrame {
+            list_positions: storage.list_positions.clone(),
+            list_colors: storage.list_colors.clone(),
+            strip_positions: storage.strip_positions.clone(),
+            strip_colors: storage.strip_colors.clone(),
+        })
+    }
+
+    fn frame_bits(frame: &GizmoFrame) -> Vec<u32> {
+        let positions = frame.list_positions.iter().chain(&frame.strip_positions);
+        let colors = frame.list_colors.iter().chain(&frame.strip_colors);
+        positions
+            .flatten()
+            .chain(colors.flatten())
+            .map(|value| value.to_bits())
+            .collect()
+    }
+
+    #[test]
+    fn plays_back_recorded_frames() {
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .init_resource::<GizmoRecorder>()
+            .add_systems(Update, draw)
+            .add_systems(Last, record_gizmos);
+
+        app.world.resource_mut::<GizmoRecorder>().start_recording();
+        let recorded: Vec<_> = (0..3)
+            .map(|_| {
+                app.update();
+                app.world.run_system_once(drawn_frame)
+            })
+            .collect();
+        let mut recorder = app.world.resource_mut::<GizmoRecorder>();
+        recorder.stop_recording();
+        assert_eq!(recorder.frame_count(), 3);
+
+        for (index, frame) in recorded.iter().enumerate().rev() {
+            app.world.resource_mut::<GizmoRecorder>().play(index);
+            app.update();
+            assert_eq!(&app.world.run_system_once(drawn_frame), frame);
+        }
+
+        // Live gizmos are drawn again once playback stops.
+        app.world.resource_mut::<GizmoRecorder>().stop_playback();
+        app.update();
+        assert!(!recorded.contains(&app.world.run_system_once(drawn_frame)));
+    }
+
+    #[test]
+    fn loads_saved_recordings() {
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .init_resource::<GizmoRecorder>()
+            .add_systems(Update, draw)
+            .add_systems(Last, record_gizmos);
+        app.world.resource_mut::<GizmoRecorder>().start_recording();
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let recorder = app.world.resource::<GizmoRecorder>();
+        let mut saved = Vec::new();
+        recorder.save(&mut saved).unwrap();
+        let mut loaded = GizmoRecorder::default();
+        loaded.load(saved.as_slice()).unwrap();
+        assert!(loaded
+            .frames
+            .iter()
+            .map(frame_bits)
+            .eq(recorder.frames.iter().map(frame_bits)));
+
+        assert!(loaded.load(&b"not a recording"[..]).is_err());
+    }
+
+    #[test]
+    fn keeps_the_latest_frames() {
+        let mut recorder = GizmoRecorder {
+            max_frames: 2,
+            ..Default::default()
+        };
+        recorder.start_recording();
+        let mut app = App::new();
+        app.add_plugins(GizmoTestPlugin)
+            .insert_resource(recorder)
+            .add_systems(Update, draw)
+            .add_systems(Last, record_gizmos);
+        let drawn: Vec<_> = (0..3)
+            .map(|_| {
+                app.update();
+                app.world.run_system_once(drawn_frame)
+            })
+            .collect();
+
+        let recorder = app.world.resource::<GizmoRecorder>();
+        assert!(recorder
+            .frames
+            .iter()
+            .map(frame_bits)
+            .eq(drawn[1..].iter().cloned()));
+    }
+}
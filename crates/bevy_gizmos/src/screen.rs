@@ -0,0 +1,267 @@
+//! Additional [`Gizmos`] Functions -- Screen Space
+//!
+//! Includes the implementation of [`Gizmos::screen`] and the [`ScreenGizmos`] it returns.
+
+use crate::{prelude::Gizmos, text::text_segments};
+use bevy_math::{Vec2, Vec3, Vec4};
+use bevy_render::{camera::Camera, color::Color};
+use bevy_transform::components::GlobalTransform;
+use std::ops::RangeInclusive;
+
+/// The depth in Normalized Device Coordinates screen space gizmos are drawn at,
+/// just past the near plane so that they aren't clipped or hidden by the scene.
+const SCREEN_DEPTH: f32 = 0.999;
+
+impl<'s> Gizmos<'s> {
+    /// Draw in the viewport of `camera`, with positions in logical pixels
+    /// from the top left corner of the viewport.
+    ///
+    /// Screen space gizmos are drawn just in front of the camera's near plane,
+    /// so they are in front of the scene but are also visible to other cameras looking at it.
+    ///
+    /// This should be called for each frame the gizmos need to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn system(mut gizmos: Gizmos, cameras: Query<(&Camera, &GlobalTransform)>) {
+    ///     for (camera, transform) in &cameras {
+    ///         let mut screen = gizmos.screen(camera, transform);
+    ///         screen.rect(Vec2::new(60., 30.), Vec2::new(100., 40.), Color::WHITE);
+    ///         screen.text(Vec2::new(60., 30.), "Paused", 16., Color::WHITE);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn screen<'a>(
+        &'a mut self,
+        camera: &'a Camera,
+        camera_transform: &'a GlobalTransform,
+    ) -> ScreenGizmos<'a, 's> {
+        ScreenGizmos {
+            gizmos: self,
+            camera,
+            camera_transform,
+        }
+    }
+}
+
+/// A [`Gizmos`] wrapper returned by [`Gizmos::screen`] drawing in the viewport of a camera,
+/// with positions in logical pixels from the top left corner of the viewport.
+pub struct ScreenGizmos<'a, 's> {
+    gizmos: &'a mut Gizmos<'s>,
+    camera: &'a Camera,
+    camera_transform: &'a GlobalTransform,
+}
+
+impl ScreenGizmos<'_, '_> {
+    /// The size of the viewport in logical pixels, if it is known.
+    pub fn viewport_size(&self) -> Option<Vec2> {
+        self.camera.logical_viewport_size()
+    }
+
+    /// The world space position gizmos at `position` in the viewport are drawn at.
+    pub fn to_world(&self, position: Vec2) -> Option<Vec3> {
+        let ndc = self.to_ndc(position)?;
+        self.camera
+            .ndc_to_world(self.camera_transform, ndc.extend(SCREEN_DEPTH))
+    }
+
+    /// The world space position under `position` in the viewport,
+    /// `depth` world units in front of the camera along its view direction.
+    pub fn to_world_at_depth(&self, position: Vec2, depth: f32) -> Option<Vec3> {
+        let ndc = self.to_ndc(position)?;
+        let clip = self.camera.projection_matrix() * Vec4::new(0., 0., -depth, 1.);
+        self.camera
+            .ndc_to_world(self.camera_transform, ndc.extend(clip.z / clip.w))
+    }
+
+    /// The Normalized Device Coordinates of `position` in the viewport.
+    fn to_ndc(&self, position: Vec2) -> Option<Vec2> {
+        let size = self.viewport_size()?;
+        Some(Vec2::new(position.x / size.x, 1. - position.y / size.y) * 2. - Vec2::ONE)
+    }
+
+    /// Draw a line from `start` to `end`.
+    pub fn line(&mut self, start: Vec2, end: Vec2, color: Color) {
+        if let (Some(start), Some(end)) = (self.to_world(start), self.to_world(end)) {
+            self.gizmos.line(start, end, color);
+        }
+    }
+
+    /// Draw a line in the world from `start` to `end`, given as positions in the viewport
+    /// with their depth in front of the camera along its view direction as `z`.
+    ///
+    /// Unlike [`ScreenGizmos::line`], the line is part of the scene and can be hidden by it,
+    /// such as to visualize screen space algorithms.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn system(mut gizmos: Gizmos, cameras: Query<(&Camera, &GlobalTransform)>) {
+    ///     for (camera, transform) in &cameras {
+    ///         // A screen space ray marched from 2 to 20 units deep.
+    ///         gizmos.screen(camera, transform).line_at_depth(
+    ///             Vec3::new(100., 100., 2.),
+    ///             Vec3::new(300., 200., 20.),
+    ///             Color::CYAN,
+    ///         );
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn line_at_depth(&mut self, start: Vec3, end: Vec3, color: Color) {
+        if let (Some(start), Some(end)) = (
+            self.to_world_at_depth(start.truncate(), start.z),
+            self.to_world_at_depth(end.truncate(), end.z),
+        ) {
+            self.gizmos.line(start, end, color);
+        }
+    }
+
+    /// Draw a line in the world from `start` to `end`, given in Normalized Device Coordinates,
+    /// with `x` and `y` from `-1` to `1` across the viewport and `z` the depth.
+    ///
+    /// See [`Camera::ndc_to_world`] for how the depth maps to the world.
+    pub fn ndc_line(&mut self, start: Vec3, end: Vec3, color: Color) {
+        let to_world = |ndc| self.camera.ndc_to_world(self.camera_transform, ndc);
+        if let (Some(start), Some(end)) = (to_world(start), to_world(end)) {
+            self.gizmos.line(start, end, color);
+        }
+    }
+
+    /// Draw lines between a list of points.
+    pub fn linestrip(&mut self, positions: impl IntoIterator<Item = Vec2>, color: Color) {
+        let positions: Vec<_> = positions
+            .into_iter()
+            .filter_map(|position| self.to_world(position))
+            .collect();
+        self.gizmos.linestrip(positions, color);
+    }
+
+    /// Draw a rectangle of `size` centered on `position`.
+    pub fn rect(&mut self, position: Vec2, size: Vec2, color: Color) {
+        let [min, max] = [position - size / 2., position + size / 2.];
+        self.linestrip(
+            [
+                min,
+                Vec2::new(max.x, min.y),
+                max,
+                Vec2::new(min.x, max.y),
+                min,
+            ],
+            color,
+        );
+    }
+
+    /// Draw a crosshair `size` pixels wide at the center of the viewport.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn system(mut gizmos: Gizmos, cameras: Query<(&Camera, &GlobalTransform)>) {
+    ///     for (camera, transform) in &cameras {
+    ///         gizmos.screen(camera, transform).crosshair(16., Color::WHITE);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn crosshair(&mut self, size: f32, color: Color) {
+        let Some(viewport_size) = self.viewport_size() else {
+            return;
+        };
+        let center = viewport_size / 2.;
+        let half_size = size / 2.;
+        self.line(
+            center - Vec2::X * half_size,
+            center + Vec2::X * half_size,
+            color,
+        );
+        self.line(
+            center - Vec2::Y * half_size,
+            center + Vec2::Y * half_size,
+            color,
+        );
+    }
+
+    /// Draw the outline of the safe area of the viewport, inset from each of its edges
+    /// by `margins` times its size.
+    ///
+    /// The action safe area of televisions is usually inset by `0.05`,
+    /// and the title safe area by `0.1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn system(mut gizmos: Gizmos, cameras: Query<(&Camera, &GlobalTransform)>) {
+    ///     for (camera, transform) in &cameras {
+    ///         let mut screen = gizmos.screen(camera, transform);
+    ///         // Action safe.
+    ///         screen.safe_area(0.05, Color::YELLOW);
+    ///         // Title safe.
+    ///         screen.safe_area(0.1, Color::RED);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn safe_area(&mut self, margins: f32, color: Color) {
+        let Some(viewport_size) = self.viewport_size() else {
+            return;
+        };
+        let size = viewport_size * (1. - 2. * margins);
+        self.rect(viewport_size / 2., size, color);
+    }
+
+    /// Draw `values` as a line graph in the rectangle of `size` from its top left corner `min`,
+    /// with `length` values spanning its width, the last one on its right edge,
+    /// and `range` spanning its height.
+    ///
+    /// Values outside of `range` are clamped to it.
+    pub(crate) fn graph(
+        &mut self,
+        min: Vec2,
+        size: Vec2,
+        values: impl ExactSizeIterator<Item = f32>,
+        length: usize,
+        range: RangeInclusive<f32>,
+        color: Color,
+    ) {
+        let (low, high) = (*range.start(), *range.end());
+        let step = size.x / length.saturating_sub(1).max(1) as f32;
+        let right = min.x + size.x;
+        let count = values.len();
+        let points = values.enumerate().map(|(i, value)| {
+            let height = ((value - low) / (high - low)).clamp(0., 1.);
+            Vec2::new(
+                right - (count - 1 - i) as f32 * step,
+                min.y + size.y * (1. - height),
+            )
+        });
+        self.linestrip(points, color);
+    }
+
+    /// Draw `text` centered on `position`, with capital letters `size` pixels high.
+    ///
+    /// See [`Gizmos::text`] for how the text is drawn.
+    pub fn text(&mut self, position: Vec2, text: &str, size: f32, color: Color) {
+        // Glyphs go up along `+Y`, while the viewport goes down.
+        let flip = Vec2::new(size, -size);
+        for (start, end) in text_segments(text) {
+            self.line(position + start * flip, position + end * flip, color);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Gizmos highlighting selected entities.
+//!
+//! Includes the [`SelectionGizmo`] component and the system drawing it.
+
+use crate::{aabb_transform, gizmos::Gizmos, merged_aabb_transform, GizmoConfig};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_hierarchy::Children;
+use bevy_math::Vec3;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{color::Color, primitives::Aabb};
+use bevy_transform::{components::GlobalTransform, TransformPoint};
+
+/// Configuration for drawing the outline of selected entities.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct SelectionGizmoConfig {
+    /// The default color of selection outlines.
+    ///
+    /// Defaults to `Color::ORANGE`.
+    pub default_color: Color,
+    /// The length of the brackets drawn at each corner, relative to the length of the edges.
+    ///
+    /// The whole box is drawn if `0.5` or more.
+    ///
+    /// Defaults to `0.25`.
+    pub bracket_length: f32,
+}
+
+impl Default for SelectionGizmoConfig {
+    fn default() -> Self {
+        Self {
+            default_color: Color::ORANGE,
+            bracket_length: 0.25,
+        }
+    }
+}
+
+/// Add this [`Component`] to an entity to highlight it as selected.
+///
+/// The entity is outlined with brackets at the corners of its [`Aabb`],
+/// or of the box enclosing the [`Aabb`]s of its descendants if it has none, such as the root
+/// of a scene.
+///
+/// To keep the outline visible behind other geometry, set
+/// [`GizmoConfig::depth_bias`](crate::GizmoConfig::depth_bias) to `-1.0`.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct SelectionGizmo {
+    /// The color of the outline.
+    ///
+    /// The default color from the [`GizmoConfig`] resource is used if `None`.
+    pub color: Option<Color>,
+}
+
+pub(crate) fn draw_selections(
+    query: Query<(Entity, &SelectionGizmo)>,
+    aabbs: Query<(&Aabb, &GlobalTransform)>,
+    children: Query<&Children>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let selection_config = &config.selection;
+    for (entity, gizmo) in &query {
+        let color = gizmo.color.unwrap_or(selection_config.default_color);
+        let bracket_length = selection_config.bracket_length;
+
+        if let Ok((&aabb, &transform)) = aabbs.get(entity) {
+            let cube = aabb_transform(aabb, transform);
+            draw_corner_brackets(&mut gizmos, cube, bracket_length, color);
+        } else if let Some(cube) = merged_aabb_transform(entity, &aabbs, &children) {
+            draw_corner_brackets(&mut gizmos, cube, bracket_length, color);
+        }
+    }
+}
+
+/// Draws brackets along the edges of the unit cube transformed by `transform`
+/// at each of its corners, `length` long relative to the edges.
+fn draw_corner_brackets(
+    gizmos: &mut Gizmos,
+    transform: impl TransformPoint,
+    length: f32,
+    color: Color,
+) {
+    let length = length.min(0.5);
+    for x in [-0.5, 0.5] {
+        for y in [-0.5, 0.5] {
+            for z in [-0.5, 0.5] {
+                let corner = Vec3::new(x, y, z);
+                for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+                    let end = corner - axis * corner * 2. * length;
+                    gizmos.line(
+                        transform.transform_point(corner),
+                        transform.transform_point(end),
+                        color,
+                    );
+                }
+            }
+        }
+    }
+}
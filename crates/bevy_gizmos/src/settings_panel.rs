@@ -0,0 +1,328 @@
+//! A panel of UI widgets editing the [`GizmoConfig`].
+//!
+//! Includes the [`GizmoSettingsPanelPlugin`], behind the `settings_panel` feature.
+
+use crate::GizmoConfig;
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::{Changed, With},
+    schedule::IntoSystemConfigs,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_hierarchy::{BuildChildren, ChildBuilder, DespawnRecursiveExt};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_reflect::{Reflect, ReflectMut, ReflectRef, Struct};
+use bevy_render::color::Color;
+use bevy_text::{Text, TextStyle};
+use bevy_ui::{
+    node_bundles::{ButtonBundle, NodeBundle, TextBundle},
+    AlignItems, BackgroundColor, FlexDirection, Interaction, PositionType, RelativeCursorPosition,
+    Style, UiRect, Val, ZIndex,
+};
+use std::fmt::Write;
+
+/// A [`Plugin`] that shows a panel of UI widgets to edit the [`GizmoConfig`] when
+/// [`GizmoSettingsPanelPlugin::toggle_key`] is pressed, and hides it when it is pressed again.
+///
+/// The panel has a checkbox for each boolean setting, such as [`GizmoConfig::enabled`] and
+/// [`AabbGizmoConfig::draw_all`](crate::AabbGizmoConfig::draw_all), found through reflection with
+/// [`GizmoConfig::sub_configs`], and a slider for [`GizmoConfig::line_width`].
+///
+/// The labels need a font, such as the one of the `default_font` feature of `bevy_text`.
+///
+/// Unlike most other gizmo plugins, it isn't added by the [`GizmoPlugin`](crate::GizmoPlugin).
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::{prelude::*, settings_panel::GizmoSettingsPanelPlugin};
+/// # use bevy_app::prelude::*;
+/// # use bevy_input::keyboard::KeyCode;
+/// # let mut app = App::new();
+/// app.add_plugins(GizmoSettingsPanelPlugin {
+///     toggle_key: KeyCode::F2,
+/// });
+/// ```
+pub struct GizmoSettingsPanelPlugin {
+    /// The key showing and hiding the panel.
+    ///
+    /// Defaults to [`KeyCode::F4`].
+    pub toggle_key: KeyCode,
+}
+
+impl Default for GizmoSettingsPanelPlugin {
+    fn default() -> Self {
+        Self {
+            toggle_key: KeyCode::F4,
+        }
+    }
+}
+
+impl Plugin for GizmoSettingsPanelPlugin {
+    fn build(&self, app: &mut App) {
+        let toggle_key = self.toggle_key;
+        app.add_systems(
+            Update,
+            (
+                move |commands: Commands,
+                      keys: Res<ButtonInput<KeyCode>>,
+                      panels: Query<Entity, With<SettingsPanel>>,
+                      config: Res<GizmoConfig>| {
+                    if keys.just_pressed(toggle_key) {
+                        toggle_panel(commands, panels, config);
+                    }
+                },
+                (press_checkboxes, drag_line_width_slider),
+                sync_panel,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The largest line width the slider can be set to, in logical pixels.
+const MAX_SLIDER_LINE_WIDTH: f32 = 16.;
+const FONT_SIZE: f32 = 14.;
+const CHECKBOX_SIZE: f32 = 12.;
+const CHECKED_COLOR: Color = Color::rgb(0.35, 0.75, 0.35);
+const UNCHECKED_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+
+/// The root node of the panel.
+#[derive(Component)]
+struct SettingsPanel;
+
+/// A checkbox toggling a boolean field of the [`GizmoConfig`],
+/// or of one of its [sub-configurations](GizmoConfig::sub_configs) if `config` is set.
+#[derive(Component)]
+struct Checkbox {
+    config: Option<String>,
+    field: String,
+}
+
+/// The track of the slider setting [`GizmoConfig::line_width`].
+#[derive(Component)]
+struct LineWidthSlider;
+
+/// The part of the [`LineWidthSlider`] filled up to the line width.
+#[derive(Component)]
+struct LineWidthSliderFill;
+
+/// The label showing [`GizmoConfig::line_width`].
+#[derive(Component)]
+struct LineWidthLabel;
+
+fn toggle_panel(
+    mut commands: Commands,
+    panels: Query<Entity, With<SettingsPanel>>,
+    config: Res<GizmoConfig>,
+) {
+    if !panels.is_empty() {
+        for panel in &panels {
+            commands.entity(panel).despawn_recursive();
+        }
+        return;
+    }
+
+    let panel = NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.),
+            right: Val::Px(10.),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(8.)),
+            row_gap: Val::Px(2.),
+            ..Default::default()
+        },
+        background_color: Color::rgba(0.05, 0.05, 0.05, 0.85).into(),
+        z_index: ZIndex::Global(i32::MAX),
+        ..Default::default()
+    };
+    commands
+        .spawn((panel, SettingsPanel))
+        .with_children(|panel| {
+            for field in ["enabled", "enabled_2d", "enabled_3d"] {
+                spawn_checkbox(panel, None, field);
+            }
+            spawn_line_width_slider(panel);
+            for (name, sub_config) in config.sub_configs() {
+                let ReflectRef::Struct(sub_config) = sub_config.reflect_ref() else {
+                    continue;
+                };
+                for index in 0..sub_config.field_len() {
+                    let (Some(field), Some(value)) =
+                        (sub_config.name_at(index), sub_config.field_at(index))
+                    else {
+                        continue;
+                    };
+                    if value.is::<bool>() {
+                        spawn_checkbox(panel, Some(name), field);
+                    }
+                }
+            }
+        });
+}
+
+fn label(value: impl Into<String>) -> TextBundle {
+    TextBundle::from_section(
+        value,
+        TextStyle {
+            font_size: FONT_SIZE,
+            color: Color::WHITE,
+            ..Default::default()
+        },
+    )
+}
+
+fn spawn_checkbox(panel: &mut ChildBuilder, config: Option<&str>, field: &str) {
+    let row = NodeBundle {
+        style: Style {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(6.),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let checkbox = ButtonBundle {
+        style: Style {
+            width: Val::Px(CHECKBOX_SIZE),
+            height: Val::Px(CHECKBOX_SIZE),
+            ..Default::default()
+        },
+        background_color: UNCHECKED_COLOR.into(),
+        ..Default::default()
+    };
+    let text = match config {
+        Some(config) => format!("{config}.{field}"),
+        None => field.to_string(),
+    };
+    panel.spawn(row).with_children(|row| {
+        row.spawn((
+            checkbox,
+            Checkbox {
+                config: config.map(str::to_string),
+                field: field.to_string(),
+            },
+        ));
+        row.spawn(label(text));
+    });
+}
+
+fn spawn_line_width_slider(panel: &mut ChildBuilder) {
+    let track = NodeBundle {
+        style: Style {
+            width: Val::Px(160.),
+            height: Val::Px(CHECKBOX_SIZE),
+            ..Default::default()
+        },
+        background_color: UNCHECKED_COLOR.into(),
+        ..Default::default()
+    };
+    let fill = NodeBundle {
+        style: Style {
+            height: Val::Percent(100.),
+            ..Default::default()
+        },
+        background_color: CHECKED_COLOR.into(),
+        ..Default::default()
+    };
+    panel.spawn((label(""), LineWidthLabel));
+    panel
+        .spawn((
+            track,
+            LineWidthSlider,
+            Interaction::default(),
+            RelativeCursorPosition::default(),
+        ))
+        .with_children(|track| {
+            track.spawn((fill, LineWidthSliderFill));
+        });
+}
+
+/// Whether the boolean field of the `config` a [`Checkbox`] toggles is set.
+fn is_checked(config: &GizmoConfig, checkbox: &Checkbox) -> bool {
+    let field = match &checkbox.config {
+        Some(name) => match config.field(name).map(Reflect::reflect_ref) {
+            Some(ReflectRef::Struct(sub_config)) => sub_config.field(&checkbox.field),
+            _ => None,
+        },
+        None => config.field(&checkbox.field),
+    };
+    field.and_then(|field| field.downcast_ref()) == Some(&true)
+}
+
+/// The boolean field of the `config` a [`Checkbox`] toggles.
+fn checkbox_value<'a>(config: &'a mut GizmoConfig, checkbox: &Checkbox) -> Option<&'a mut bool> {
+    let field = match &checkbox.config {
+        Some(name) => match config.field_mut(name)?.reflect_mut() {
+            ReflectMut::Struct(sub_config) => sub_config.field_mut(&checkbox.field)?,
+            _ => return None,
+        },
+        None => config.field_mut(&checkbox.field)?,
+    };
+    field.downcast_mut()
+}
+
+fn press_checkboxes(
+    checkboxes: Query<(&Interaction, &Checkbox), Changed<Interaction>>,
+    mut config: ResMut<GizmoConfig>,
+) {
+    for (interaction, checkbox) in &checkboxes {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(value) = checkbox_value(&mut config, checkbox) {
+            *value = !*value;
+        }
+    }
+}
+
+fn drag_line_width_slider(
+    sliders: Query<(&Interaction, &RelativeCursorPosition), With<LineWidthSlider>>,
+    mut config: ResMut<GizmoConfig>,
+) {
+    for (interaction, cursor) in &sliders {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(position) = cursor.normalized {
+            let line_width = (position.x.clamp(0., 1.) * MAX_SLIDER_LINE_WIDTH).max(0.5);
+            // Only trigger change detection when the width actually changes.
+            if config.line_width != line_width {
+                config.line_width = line_width;
+            }
+        }
+    }
+}
+
+/// Shows the values of the [`GizmoConfig`] in the widgets of the panel.
+fn sync_panel(
+    config: Res<GizmoConfig>,
+    mut checkboxes: Query<(&Checkbox, &mut BackgroundColor)>,
+    mut fills: Query<&mut Style, With<LineWidthSliderFill>>,
+    mut labels: Query<&mut Text, With<LineWidthLabel>>,
+) {
+    for (checkbox, mut background) in &mut checkboxes {
+        let color = if is_checked(&config, checkbox) {
+            CHECKED_COLOR
+        } else {
+            UNCHECKED_COLOR
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+    let fraction = (config.line_width / MAX_SLIDER_LINE_WIDTH).clamp(0., 1.);
+    for mut style in &mut fills {
+        if style.width != Val::Percent(fraction * 100.) {
+            style.width = Val::Percent(fraction * 100.);
+        }
+    }
+    for mut text in &mut labels {
+        let mut value = String::new();
+        let _ = write!(value, "line_width: {:.1}", config.line_width);
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    }
+}
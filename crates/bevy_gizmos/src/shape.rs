@@ -0,0 +1,155 @@
+//! Gizmos drawn for entities every frame.
+//!
+//! Includes the [`Gizmo`] component and the system drawing it.
+
+use crate::gizmos::Gizmos;
+use bevy_ecs::{component::Component, reflect::ReflectComponent, system::Query};
+use bevy_math::{Quat, UVec2, Vec2, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use bevy_render::color::Color;
+use bevy_transform::components::{GlobalTransform, Transform};
+
+/// A shape drawn by a [`Gizmo`], in the local space of its entity.
+///
+/// Flat shapes are drawn on the XY plane.
+#[derive(Clone, Reflect, Debug, PartialEq)]
+#[reflect(Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GizmoShape {
+    /// A line from the origin to `end`.
+    Line {
+        /// The end of the line.
+        end: Vec3,
+    },
+    /// An arrow from the origin to `end`.
+    Arrow {
+        /// The end of the arrow, where its tip is.
+        end: Vec3,
+    },
+    /// A circle facing `+Z`.
+    Circle {
+        /// The radius of the circle.
+        radius: f32,
+    },
+    /// A rectangle facing `+Z`.
+    Rect {
+        /// The size of the rectangle.
+        size: Vec2,
+    },
+    /// A sphere made of a circle around each axis.
+    Sphere {
+        /// The radius of the sphere.
+        radius: f32,
+    },
+    /// A cuboid.
+    Cuboid {
+        /// The size of the cuboid.
+        size: Vec3,
+    },
+    /// A grid facing `+Z`.
+    Grid {
+        /// The number of cells along each axis.
+        cell_count: UVec2,
+        /// The size of each cell.
+        spacing: Vec2,
+    },
+    /// Axes along `+X`, `+Y` and `+Z`, see [`Gizmos::axes`].
+    Axes {
+        /// The length of the axes.
+        length: f32,
+    },
+    /// A text facing `+Z`, see [`Gizmos::text`].
+    Text {
+        /// The text to draw.
+        text: String,
+        /// The height of capital letters.
+        size: f32,
+    },
+}
+
+impl Default for GizmoShape {
+    fn default() -> Self {
+        GizmoShape::Sphere { radius: 0.5 }
+    }
+}
+
+impl GizmoShape {
+    /// Draw the shape at the origin of `gizmos`, which can be moved with [`Gizmos::relative_to`].
+    pub fn draw(&self, gizmos: &mut Gizmos, color: Color) {
+        match self {
+            GizmoShape::Line { end } => gizmos.line(Vec3::ZERO, *end, color),
+            GizmoShape::Arrow { end } => {
+                gizmos.arrow(Vec3::ZERO, *end, color);
+            }
+            GizmoShape::Circle { radius } => {
+                gizmos.circle(Vec3::ZERO, Vec3::Z, *radius, color);
+            }
+            GizmoShape::Rect { size } => gizmos.rect(Vec3::ZERO, Quat::IDENTITY, *size, color),
+            GizmoShape::Sphere { radius } => {
+                gizmos.sphere(Vec3::ZERO, Quat::IDENTITY, *radius, color);
+            }
+            GizmoShape::Cuboid { size } => {
+                gizmos.cuboid(Transform::from_scale(*size), color);
+            }
+            GizmoShape::Grid {
+                cell_count,
+                spacing,
+            } => {
+                gizmos.grid(Vec3::ZERO, Quat::IDENTITY, *cell_count, *spacing, color);
+            }
+            GizmoShape::Axes { length } => gizmos.axes(Transform::IDENTITY, *length),
+            GizmoShape::Text { text, size } => {
+                gizmos.text(Vec3::ZERO, Quat::IDENTITY, text, *size, color);
+            }
+        }
+    }
+}
+
+/// Add this [`Component`] to an entity to draw a [`GizmoShape`] at its [`GlobalTransform`]
+/// every frame, without a system of your own.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_transform::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         TransformBundle::default(),
+///         Gizmo {
+///             shape: GizmoShape::Sphere { radius: 2. },
+///             color: Some(Color::RED),
+///         },
+///     ));
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// ```
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Gizmo {
+    /// The shape to draw.
+    pub shape: GizmoShape,
+    /// The color of the shape.
+    ///
+    /// [`GizmoConfig::default_color`](crate::GizmoConfig::default_color) is used if `None`.
+    pub color: Option<Color>,
+}
+
+pub(crate) fn draw_gizmo_shapes(query: Query<(&GlobalTransform, &Gizmo)>, mut gizmos: Gizmos) {
+    for (transform, gizmo) in &query {
+        let color = gizmo.color.unwrap_or(Gizmos::DEFAULT_COLOR);
+        gizmo.shape.draw(&mut gizmos.relative_to(transform), color);
+    }
+}
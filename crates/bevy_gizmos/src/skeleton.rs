@@ -0,0 +1,124 @@
+//! Gizmos for the skeletons of skinned meshes.
+//!
+//! Includes the [`SkeletonGizmo`] component and the system drawing it.
+
+use crate::{color_from_entity, gizmos::Gizmos, GizmoConfig};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_hierarchy::Parent;
+use bevy_math::Vec3;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{color::Color, mesh::skinning::SkinnedMesh};
+use bevy_transform::components::GlobalTransform;
+
+/// Configuration for drawing the joints and bones of skinned meshes.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct SkeletonGizmoConfig {
+    /// Draws the skeletons of all skinned meshes in the scene when set to `true`.
+    ///
+    /// To draw a specific mesh's skeleton, you can add the [`SkeletonGizmo`] component.
+    ///
+    /// Defaults to `false`.
+    pub draw_all: bool,
+    /// The default color for the bones of skeleton gizmos.
+    ///
+    /// A random color is chosen per skinned mesh if `None`.
+    ///
+    /// Defaults to `None`.
+    pub default_color: Option<Color>,
+    /// The length of the axes drawn at each joint before they are scaled by its transform,
+    /// see [`Gizmos::axes`].
+    ///
+    /// No axes are drawn if `None`.
+    ///
+    /// Defaults to `Some(0.1)`.
+    pub joint_axes_length: Option<f32>,
+}
+
+impl Default for SkeletonGizmoConfig {
+    fn default() -> Self {
+        Self {
+            draw_all: false,
+            default_color: None,
+            joint_axes_length: Some(0.1),
+        }
+    }
+}
+
+/// Add this [`Component`] to an entity with a [`SkinnedMesh`] to draw its skeleton.
+///
+/// Each joint whose parent is also a joint of the mesh is connected to it by an octahedral bone,
+/// and the axes of each joint are drawn if [`SkeletonGizmoConfig::joint_axes_length`] is set.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct SkeletonGizmo {
+    /// The color of the bones.
+    ///
+    /// The default color from the [`GizmoConfig`] resource is used if `None`.
+    pub color: Option<Color>,
+}
+
+/// The distance along a bone to its widest point, and its width there, relative to its length.
+const BONE_WIDEST_POINT: f32 = 0.1;
+
+pub(crate) fn draw_skeletons(
+    skinned_meshes: Query<(Entity, &SkinnedMesh, Option<&SkeletonGizmo>)>,
+    joints: Query<(&GlobalTransform, Option<&Parent>)>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let skeleton_config = &config.skeleton;
+    for (entity, skinned_mesh, gizmo) in &skinned_meshes {
+        if !skeleton_config.draw_all && gizmo.is_none() {
+            continue;
+        }
+        let color = gizmo
+            .and_then(|gizmo| gizmo.color)
+            .or(skeleton_config.default_color)
+            .unwrap_or_else(|| color_from_entity(entity));
+
+        for &joint in &skinned_mesh.joints {
+            let Ok((&transform, parent)) = joints.get(joint) else {
+                continue;
+            };
+            if let Some(length) = skeleton_config.joint_axes_length {
+                gizmos.axes(transform, length);
+            }
+
+            let Some(parent) = parent.filter(|parent| skinned_mesh.joints.contains(parent)) else {
+                continue;
+            };
+            if let Ok((parent_transform, _)) = joints.get(parent.get()) {
+                draw_bone(
+                    &mut gizmos,
+                    parent_transform.translation(),
+                    transform.translation(),
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// Draws an octahedral bone from `start` to `end`.
+fn draw_bone(gizmos: &mut Gizmos, start: Vec3, end: Vec3, color: Color) {
+    let direction = end - start;
+    let Some(axis) = direction.try_normalize() else {
+        return;
+    };
+    let (u, v) = axis.any_orthonormal_pair();
+    let width = direction.length() * BONE_WIDEST_POINT;
+    let center = start + direction * BONE_WIDEST_POINT;
+    let ring = [u, v, -u, -v].map(|offset| center + offset * width);
+
+    gizmos.linestrip(ring.into_iter().chain([ring[0]]), color);
+    for point in ring {
+        gizmos.line(start, point, color);
+        gizmos.line(point, end, color);
+    }
+}
@@ -0,0 +1,143 @@
+//! Gizmos for sprites.
+//!
+//! Includes the [`SpriteGizmo`] component and the `SpriteGizmoPlugin` drawing it.
+
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+
+#[cfg(feature = "bevy_sprite")]
+use crate::{color_from_entity, gizmos::Gizmos, GizmoConfig};
+#[cfg(feature = "bevy_sprite")]
+use bevy_app::{Plugin, PostUpdate};
+#[cfg(feature = "bevy_sprite")]
+use bevy_asset::{Assets, Handle};
+#[cfg(feature = "bevy_sprite")]
+use bevy_ecs::{
+    entity::Entity,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res},
+};
+#[cfg(feature = "bevy_sprite")]
+use bevy_math::Vec2;
+#[cfg(feature = "bevy_sprite")]
+use bevy_render::texture::Image;
+#[cfg(feature = "bevy_sprite")]
+use bevy_sprite::{Sprite, TextureAtlas, TextureAtlasLayout};
+#[cfg(feature = "bevy_sprite")]
+use bevy_transform::{components::GlobalTransform, TransformSystem};
+
+/// Configuration for drawing the bounds and anchor of sprites.
+#[derive(Clone, Default, Reflect)]
+#[reflect(Default)]
+pub struct SpriteGizmoConfig {
+    /// Draws all sprites in the scene when set to `true`.
+    ///
+    /// To draw a specific sprite, you can add the [`SpriteGizmo`] component.
+    ///
+    /// Defaults to `false`.
+    pub draw_all: bool,
+    /// The default color for sprite gizmos.
+    ///
+    /// A random color is chosen per sprite if `None`.
+    ///
+    /// Defaults to `None`.
+    pub default_color: Option<Color>,
+}
+
+/// Add this [`Component`] to a sprite to draw the quad it is rendered with and its anchor.
+///
+/// The quad's size is the sprite's custom size, or the size of its texture atlas rect,
+/// or the size of its image, in that order, and it is offset from the anchor like when rendered.
+/// The anchor is drawn as a cross, and a tick marks the corner the top left of the image is
+/// drawn at, which moves when the sprite is flipped.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct SpriteGizmo {
+    /// The color of the sprite gizmo.
+    ///
+    /// The default color from the [`GizmoConfig`](crate::GizmoConfig) resource is used if `None`.
+    pub color: Option<Color>,
+}
+
+/// A [`Plugin`] that draws the [`SpriteGizmo`]s, added by the [`GizmoPlugin`](crate::GizmoPlugin).
+#[cfg(feature = "bevy_sprite")]
+pub struct SpriteGizmoPlugin;
+
+#[cfg(feature = "bevy_sprite")]
+impl Plugin for SpriteGizmoPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(
+            PostUpdate,
+            draw_sprites.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// The size of the anchor cross and the top left tick, relative to the smaller side of the sprite.
+#[cfg(feature = "bevy_sprite")]
+const MARKER_SIZE: f32 = 0.1;
+
+#[cfg(feature = "bevy_sprite")]
+pub(crate) fn draw_sprites(
+    query: Query<(
+        Entity,
+        &Sprite,
+        &GlobalTransform,
+        &Handle<Image>,
+        Option<&TextureAtlas>,
+        Option<&SpriteGizmo>,
+    )>,
+    images: Option<Res<Assets<Image>>>,
+    atlases: Option<Res<Assets<TextureAtlasLayout>>>,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    // Apps without rendering, such as servers, have no images or atlases to size sprites with.
+    let (Some(images), Some(atlases)) = (images, atlases) else {
+        return;
+    };
+    let sprite_config = &config.sprite;
+    for (entity, sprite, transform, image, atlas, gizmo) in &query {
+        if !sprite_config.draw_all && gizmo.is_none() {
+            continue;
+        }
+        // Mirrors the size of the quad in `bevy_sprite::prepare_sprites`.
+        let Some(size) = sprite.custom_size.or_else(|| match atlas {
+            None => images.get(image).map(|image| image.size_f32()),
+            Some(atlas) => atlas.texture_rect(&atlases).map(|rect| rect.size()),
+        }) else {
+            continue;
+        };
+        let color = gizmo
+            .and_then(|gizmo| gizmo.color)
+            .or(sprite_config.default_color)
+            .unwrap_or_else(|| color_from_entity(entity));
+
+        let center = -sprite.anchor.as_vec() * size;
+        let to_world = |local: Vec2| transform.transform_point(local.extend(0.));
+        let corners = [
+            Vec2::new(-0.5, -0.5),
+            Vec2::new(0.5, -0.5),
+            Vec2::new(0.5, 0.5),
+            Vec2::new(-0.5, 0.5),
+            Vec2::new(-0.5, -0.5),
+        ]
+        .map(|corner| to_world(center + corner * size));
+        gizmos.linestrip(corners, color);
+
+        let marker_size = size.min_element() * MARKER_SIZE;
+        for direction in [Vec2::X, Vec2::Y] {
+            let offset = direction * marker_size / 2.;
+            gizmos.line(to_world(-offset), to_world(offset), color);
+        }
+
+        let flip = Vec2::new(
+            if sprite.flip_x { -1. } else { 1. },
+            if sprite.flip_y { -1. } else { 1. },
+        );
+        let top_left = center + Vec2::new(-0.5, 0.5) * flip * size;
+        let inward = Vec2::new(1., -1.) * flip * marker_size;
+        gizmos.line(to_world(top_left), to_world(top_left + inward), color);
+    }
+}
@@ -0,0 +1,297 @@
+//! Capturing the shapes drawn by systems, for tests.
+//!
+//! Includes the [`GizmoTestPlugin`] and the [`CapturedGizmos`] resource it fills.
+
+use crate::{gizmos::GizmoStorage, GizmoConfig};
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::system::{ResMut, Resource};
+use bevy_math::{Affine3A, Quat, UVec2, Vec2, Vec3};
+use bevy_render::color::Color;
+
+/// A [`Plugin`] that records the shapes each system draws with [`Gizmos`](crate::gizmos::Gizmos)
+/// in the [`CapturedGizmos`] resource, so tests can check what was drawn without rendering.
+///
+/// It is meant for headless apps instead of the [`GizmoPlugin`](crate::GizmoPlugin),
+/// and only sets up the resources drawing needs. The shapes of a frame are kept
+/// until the start of the next frame.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use bevy_app::prelude::*;
+/// fn draw_target(mut gizmos: Gizmos) {
+///     gizmos.sphere(Vec3::X, Quat::IDENTITY, 2., Color::RED);
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(GizmoTestPlugin)
+///     .add_systems(Update, draw_target);
+/// app.update();
+///
+/// let captured = app.world.resource::<CapturedGizmos>();
+/// assert!(captured.of_system("draw_target").any(|shape| matches!(
+///     shape,
+///     CapturedShape::Sphere { position, radius, .. } if *position == Vec3::X && *radius == 2.
+/// )));
+/// ```
+pub struct GizmoTestPlugin;
+
+impl Plugin for GizmoTestPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GizmoConfig>()
+            .init_resource::<GizmoStorage>()
+            .init_resource::<CapturedGizmos>()
+            .add_systems(First, clear_captured_gizmos);
+    }
+}
+
+/// A [`Resource`] holding the shapes drawn during the last frame, see [`GizmoTestPlugin`].
+///
+/// Shapes drawn by other shapes, such as the circles of a sphere, aren't recorded separately.
+/// Shapes without a variant of their own in [`CapturedShape`], such as [axes](crate::gizmos::Gizmos::axes),
+/// are recorded as the shapes they are drawn with.
+/// Shapes drawn in [`Gizmos::screen_sized`](crate::gizmos::Gizmos::screen_sized)
+/// or [`Gizmos::draw_async`](crate::gizmos::Gizmos::draw_async) scopes aren't recorded.
+#[derive(Resource, Default, Debug)]
+pub struct CapturedGizmos {
+    gizmos: Vec<CapturedGizmo>,
+}
+
+impl CapturedGizmos {
+    /// Iterate over the captured gizmos, in the order their systems ran.
+    pub fn iter(&self) -> impl Iterator<Item = &CapturedGizmo> {
+        self.gizmos.iter()
+    }
+
+    /// Iterate over the shapes drawn by the system named `system`,
+    /// either its full path or its last segment.
+    pub fn of_system<'a>(&'a self, system: &'a str) -> impl Iterator<Item = &'a CapturedShape> {
+        self.gizmos
+            .iter()
+            .filter(move |gizmo| {
+                gizmo.system == system
+                    || gizmo
+                        .system
+                        .strip_suffix(system)
+                        .is_some_and(|path| path.ends_with("::"))
+            })
+            .map(|gizmo| &gizmo.shape)
+    }
+
+    /// The number of captured gizmos.
+    pub fn len(&self) -> usize {
+        self.gizmos.len()
+    }
+
+    /// Returns `true` if no gizmos were captured.
+    pub fn is_empty(&self) -> bool {
+        self.gizmos.is_empty()
+    }
+
+    /// Adds the `shapes` drawn by `system`.
+    pub(crate) fn extend(
+        &mut self,
+        system: &str,
+        shapes: impl IntoIterator<Item = (Affine3A, CapturedShape)>,
+    ) {
+        self.gizmos
+            .extend(shapes.into_iter().map(|(transform, shape)| CapturedGizmo {
+                system: system.to_owned(),
+                transform,
+                shape,
+            }));
+    }
+}
+
+/// A shape drawn by a system, see [`CapturedGizmos`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapturedGizmo {
+    /// The name of the system that drew the shape.
+    pub system: String,
+    /// The transform of the [`Gizmos::with_transform`](crate::gizmos::Gizmos::with_transform)
+    /// scope the shape was drawn in, which its positions are relative to.
+    pub transform: Affine3A,
+    /// The shape, with the arguments it was drawn with.
+    pub shape: CapturedShape,
+}
+
+/// The arguments a shape was drawn with, see [`CapturedGizmos`].
+///
+/// 2D shapes without a variant of their own are recorded as their 3D counterparts in the XY plane.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CapturedShape {
+    /// A line, see [`Gizmos::line`](crate::gizmos::Gizmos::line).
+    Line {
+        /// The start of the line.
+        start: Vec3,
+        /// The end of the line.
+        end: Vec3,
+        /// The color of the line.
+        color: Color,
+    },
+    /// A line with a color gradient, see [`Gizmos::line_gradient`](crate::gizmos::Gizmos::line_gradient).
+    LineGradient {
+        /// The start of the line.
+        start: Vec3,
+        /// The end of the line.
+        end: Vec3,
+        /// The color at the start of the line.
+        start_color: Color,
+        /// The color at the end of the line.
+        end_color: Color,
+    },
+    /// A line strip, see [`Gizmos::linestrip`](crate::gizmos::Gizmos::linestrip).
+    Linestrip {
+        /// The points of the line.
+        positions: Vec<Vec3>,
+        /// The color of the line.
+        color: Color,
+    },
+    /// A line strip with a color gradient,
+    /// see [`Gizmos::linestrip_gradient`](crate::gizmos::Gizmos::linestrip_gradient).
+    LinestripGradient {
+        /// The points of the line and their colors.
+        points: Vec<(Vec3, Color)>,
+    },
+    /// Points, see [`Gizmos::point_cloud`](crate::gizmos::Gizmos::point_cloud).
+    PointCloud {
+        /// The positions of the points.
+        positions: Vec<Vec3>,
+        /// The color of the points.
+        color: Color,
+    },
+    /// Points of different colors,
+    /// see [`Gizmos::point_cloud_colored`](crate::gizmos::Gizmos::point_cloud_colored).
+    PointCloudColored {
+        /// The positions of the points.
+        positions: Vec<Vec3>,
+        /// The color of each point.
+        colors: Vec<Color>,
+    },
+    /// A circle, see [`Gizmos::circle`](crate::gizmos::Gizmos::circle).
+    Circle {
+        /// The center of the circle.
+        position: Vec3,
+        /// The normal of the circle's plane.
+        normal: Vec3,
+        /// The radius of the circle.
+        radius: f32,
+        /// The color of the circle.
+        color: Color,
+    },
+    /// A circle facing the camera, see [`Gizmos::billboard_circle`](crate::gizmos::Gizmos::billboard_circle).
+    BillboardCircle {
+        /// The center of the circle.
+        position: Vec3,
+        /// The radius of the circle.
+        radius: f32,
+        /// The color of the circle.
+        color: Color,
+    },
+    /// An arc in 2D, see [`Gizmos::arc_2d`](crate::gizmos::Gizmos::arc_2d).
+    Arc2d {
+        /// The center of the arc's circle.
+        position: Vec2,
+        /// The clockwise angle in radians from `Vec2::Y` to the middle of the arc.
+        direction_angle: f32,
+        /// The angle of the arc in radians.
+        arc_angle: f32,
+        /// The radius of the arc.
+        radius: f32,
+        /// The color of the arc.
+        color: Color,
+    },
+    /// A sphere, see [`Gizmos::sphere`](crate::gizmos::Gizmos::sphere).
+    Sphere {
+        /// The center of the sphere.
+        position: Vec3,
+        /// The rotation of the sphere.
+        rotation: Quat,
+        /// The radius of the sphere.
+        radius: f32,
+        /// The color of the sphere.
+        color: Color,
+    },
+    /// A rectangle, see [`Gizmos::rect`](crate::gizmos::Gizmos::rect).
+    Rect {
+        /// The center of the rectangle.
+        position: Vec3,
+        /// The rotation of the rectangle from the XY plane.
+        rotation: Quat,
+        /// The size of the rectangle.
+        size: Vec2,
+        /// The color of the rectangle.
+        color: Color,
+    },
+    /// A cuboid, see [`Gizmos::cuboid`](crate::gizmos::Gizmos::cuboid).
+    Cuboid {
+        /// The transform of the unit cube the cuboid was drawn as.
+        transform: Affine3A,
+        /// The color of the cuboid.
+        color: Color,
+    },
+    /// Brackets at the corners of a cube,
+    /// see [`Gizmos::corner_brackets`](crate::gizmos::Gizmos::corner_brackets).
+    ///
+    /// [`Gizmos::corner_brackets_2d`](crate::gizmos::Gizmos::corner_brackets_2d) is recorded
+    /// as the brackets of a cube flattened into the XY plane.
+    CornerBrackets {
+        /// The transform of the unit cube the brackets were drawn at.
+        transform: Affine3A,
+        /// The length of the brackets along each edge.
+        length: f32,
+        /// The color of the brackets.
+        color: Color,
+    },
+    /// An arrow, see [`Gizmos::arrow`](crate::gizmos::Gizmos::arrow).
+    Arrow {
+        /// The start of the arrow.
+        start: Vec3,
+        /// The tip of the arrow.
+        end: Vec3,
+        /// The color of the arrow.
+        color: Color,
+    },
+    /// A grid, see [`Gizmos::grid`](crate::gizmos::Gizmos::grid).
+    Grid {
+        /// The center of the grid.
+        position: Vec3,
+        /// The rotation of the grid from the XY plane.
+        rotation: Quat,
+        /// The number of cells along each axis.
+        cell_count: UVec2,
+        /// The size of the cells.
+        spacing: Vec2,
+        /// The color of the lines.
+        color: Color,
+    },
+    /// A text, see [`Gizmos::text`](crate::gizmos::Gizmos::text).
+    Text {
+        /// The anchor of the text.
+        position: Vec3,
+        /// The rotation of the text from the XY plane.
+        rotation: Quat,
+        /// The text.
+        text: String,
+        /// The height of capital letters.
+        size: f32,
+        /// The color of the text.
+        color: Color,
+    },
+}
+
+/// The shapes captured by a [`GizmoBuffer`](crate::gizmos::GizmoBuffer) of a system.
+#[derive(Default)]
+pub(crate) struct ShapeCapture {
+    /// The shapes and the transforms of their scopes.
+    pub shapes: Vec<(Affine3A, CapturedShape)>,
+    /// The number of shapes being drawn, to only record the outermost one.
+    pub depth: usize,
+}
+
+fn clear_captured_gizmos(mut captured: ResMut<CapturedGizmos>, mut storage: ResMut<GizmoStorage>) {
+    captured.gizmos.clear();
+    storage.clear();
+}
@@ -0,0 +1,398 @@
+//! Additional [`Gizmos`] Functions -- Text
+//!
+//! Includes the implementation of [`Gizmos::text`] and [`Gizmos::text_2d`],
+//! their configuration and the segmented font they are drawn with.
+
+use crate::{gizmos::GizmoStorage, prelude::Gizmos, testing::CapturedShape, GizmoConfig};
+use bevy_ecs::system::{Res, ResMut};
+use bevy_math::{Affine3A, Quat, Vec2, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::color::Color;
+use std::{iter, mem};
+
+/// The width of a glyph, relative to its height.
+const GLYPH_WIDTH: f32 = 0.6;
+/// The horizontal distance between the start of two glyphs, relative to their height.
+const GLYPH_ADVANCE: f32 = 0.8;
+/// The vertical distance between the baseline of two lines, relative to the glyph height.
+const LINE_ADVANCE: f32 = 1.4;
+
+/// The point of a text gizmo placed at its position.
+#[derive(Clone, Copy, Default, Reflect, Debug, PartialEq, Eq)]
+#[reflect(Default)]
+pub enum TextGizmoAnchor {
+    /// The top left corner of the text.
+    TopLeft,
+    /// The middle of the top edge of the text.
+    TopCenter,
+    /// The top right corner of the text.
+    TopRight,
+    /// The middle of the left edge of the text.
+    CenterLeft,
+    /// The center of the text.
+    #[default]
+    Center,
+    /// The middle of the right edge of the text.
+    CenterRight,
+    /// The bottom left corner of the text.
+    BottomLeft,
+    /// The middle of the bottom edge of the text.
+    BottomCenter,
+    /// The bottom right corner of the text.
+    BottomRight,
+}
+
+impl TextGizmoAnchor {
+    /// The position of the anchor relative to the center of the text, from `-0.5` to `0.5`
+    /// times its size.
+    pub fn as_vec(self) -> Vec2 {
+        let (x, y) = match self {
+            TextGizmoAnchor::TopLeft => (-0.5, 0.5),
+            TextGizmoAnchor::TopCenter => (0., 0.5),
+            TextGizmoAnchor::TopRight => (0.5, 0.5),
+            TextGizmoAnchor::CenterLeft => (-0.5, 0.),
+            TextGizmoAnchor::Center => (0., 0.),
+            TextGizmoAnchor::CenterRight => (0.5, 0.),
+            TextGizmoAnchor::BottomLeft => (-0.5, -0.5),
+            TextGizmoAnchor::BottomCenter => (0., -0.5),
+            TextGizmoAnchor::BottomRight => (0.5, -0.5),
+        };
+        Vec2::new(x, y)
+    }
+}
+
+/// Configuration for the texts drawn by [`Gizmos::text`] and [`Gizmos::text_2d`].
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// fn setup(mut config: ResMut<GizmoConfig>) {
+///     // Smaller labels next to their position, readable over a busy scene.
+///     config.text.scale = 0.5;
+///     config.text.anchor = TextGizmoAnchor::BottomLeft;
+///     config.text.background = Some(Color::BLACK);
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// ```
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+pub struct TextGizmoConfig {
+    /// Scales the size of every text, such as to fit denser labels.
+    ///
+    /// Defaults to `1.0`.
+    pub scale: f32,
+    /// The point of texts placed at their position.
+    ///
+    /// Defaults to [`TextGizmoAnchor::Center`].
+    pub anchor: TextGizmoAnchor,
+    /// The color of a rectangle drawn around texts to set them apart from what's behind them,
+    /// if any.
+    ///
+    /// Defaults to `None`.
+    pub background: Option<Color>,
+    /// The space between texts and their background, relative to their size.
+    ///
+    /// Defaults to `0.3`.
+    pub padding: f32,
+}
+
+impl Default for TextGizmoConfig {
+    fn default() -> Self {
+        Self {
+            scale: 1.,
+            anchor: TextGizmoAnchor::Center,
+            background: None,
+            padding: 0.3,
+        }
+    }
+}
+
+impl<'s> Gizmos<'s> {
+    /// Draw `text` in 3D, centered on `position` and facing the local `+Z` axis of `rotation`.
+    ///
+    /// The text is drawn with lines in a segmented font, like a sixteen-segment display,
+    /// so it stays legible at a distance at the cost of looking blocky.
+    /// `size` is the height of capital letters, and `'\n'` starts a new line.
+    /// Lowercase letters are drawn as uppercase, and characters without a glyph as `?`.
+    ///
+    /// The text is laid out at the end of the frame as set in [`GizmoConfig::text`],
+    /// centered on `position` by default.
+    ///
+    /// This should be called for each frame the text needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text(Vec3::Y, Quat::IDENTITY, "Spawn point", 0.2, Color::WHITE);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn text(&mut self, position: Vec3, rotation: Quat, text: &str, size: f32, color: Color) {
+        let shape = || CapturedShape::Text {
+            position,
+            rotation,
+            text: text.to_owned(),
+            size,
+            color,
+        };
+        self.capture(shape, |gizmos| {
+            let transform =
+                Affine3A::from_scale_rotation_translation(Vec3::splat(size), rotation, position);
+            gizmos.pending_text(transform, text, color);
+        });
+    }
+
+    /// Draw `text` in 2D, centered on `position` and rotated counter-clockwise by `rotation`
+    /// radians.
+    ///
+    /// See [`Gizmos::text`] for how the text is drawn.
+    ///
+    /// This should be called for each frame the text needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text_2d(Vec2::ZERO, 0., "Score: 42", 16., Color::WHITE);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn text_2d(&mut self, position: Vec2, rotation: f32, text: &str, size: f32, color: Color) {
+        self.draw_2d(true, |gizmos| {
+            gizmos.text(
+                position.extend(0.),
+                Quat::from_rotation_z(rotation),
+                text,
+                size,
+                color,
+            );
+        });
+    }
+}
+
+/// Lays out the texts drawn this frame as set in [`GizmoConfig::text`]
+/// and adds their lines to the [`GizmoStorage`], or to the lines only drawn in the view
+/// of their camera, see [`GizmoStorage::view_mut`].
+pub(crate) fn resolve_pending_texts(config: Res<GizmoConfig>, mut storage: ResMut<GizmoStorage>) {
+    if storage.pending_texts.is_empty() {
+        return;
+    }
+
+    let text_config = &config.text;
+    let background = text_config
+        .background
+        .map(|color| color.as_linear_rgba_f32());
+    let storage = &mut *storage;
+    let mut pending_texts = mem::take(&mut storage.pending_texts);
+    for (index, text) in pending_texts.drain(..).enumerate() {
+        let size = text_size(&text.text);
+        let offset = -text_config.anchor.as_vec() * size;
+        let to_world = |point: Vec2| {
+            let point = (point + offset) * text_config.scale;
+            text.transform.transform_point3(point.extend(0.)).to_array()
+        };
+
+        let segments = text_segments(&text.text);
+        // Two positions per segment, plus the corners and the NaN separator of the background.
+        let vertex_count = segments.clone().count() * 2 + if background.is_some() { 6 } else { 0 };
+        let target = match text.view {
+            Some(camera) => storage.view_mut(camera),
+            None => &mut *storage,
+        };
+        if !target.make_room(&config, vertex_count) {
+            continue;
+        }
+        let (list_start, strip_start) = (target.list_positions.len(), target.strip_positions.len());
+
+        for (start, end) in segments {
+            target.list_positions.push(to_world(start));
+            target.list_positions.push(to_world(end));
+        }
+        let list_len = target.list_positions.len();
+        target.list_colors.resize(list_len, text.color);
+
+        if let Some(color) = background {
+            let max = size / 2. + text_config.padding;
+            let min = -max;
+            target.strip_positions.extend(
+                [
+                    min,
+                    Vec2::new(max.x, min.y),
+                    max,
+                    Vec2::new(min.x, max.y),
+                    min,
+                ]
+                .map(to_world),
+            );
+            target.strip_positions.push([f32::NAN; 3]);
+            target.strip_colors.extend(iter::repeat(color).take(5));
+            target.strip_colors.push([f32::NAN; 4]);
+        }
+        target.tag_lines(text.tag, list_start, strip_start);
+        let vertices =
+            target.list_positions.len() - list_start + target.strip_positions.len() - strip_start;
+        storage.count_resolved_usage(|usage| &usage.texts, index, vertices);
+    }
+    // Hand the allocation back for the next frame.
+    storage.pending_texts = pending_texts;
+}
+
+/// The size of `text` for a glyph height of 1.
+pub(crate) fn text_size(text: &str) -> Vec2 {
+    let line_count = text.lines().count().max(1);
+    let width = text
+        .lines()
+        .map(|line| line.chars().count().saturating_sub(1) as f32 * GLYPH_ADVANCE + GLYPH_WIDTH)
+        .fold(0., f32::max);
+    Vec2::new(width, (line_count - 1) as f32 * LINE_ADVANCE + 1.)
+}
+
+/// The segments of `text` for a glyph height of 1, centered on the origin.
+pub(crate) fn text_segments(text: &str) -> impl Iterator<Item = (Vec2, Vec2)> + Clone + '_ {
+    let line_count = text.lines().count().max(1);
+    // The distance from the first baseline to the vertical center of the text.
+    let top = ((line_count - 1) as f32 * LINE_ADVANCE + 1.) / 2. - 1.;
+    text.lines().enumerate().flat_map(move |(row, line)| {
+        let width = line.chars().count().saturating_sub(1) as f32 * GLYPH_ADVANCE + GLYPH_WIDTH;
+        let origin = Vec2::new(-width / 2., top - row as f32 * LINE_ADVANCE);
+        line.chars().enumerate().flat_map(move |(column, c)| {
+            let offset = origin + Vec2::X * column as f32 * GLYPH_ADVANCE;
+            let glyph = glyph(c);
+            SEGMENTS
+                .iter()
+                .enumerate()
+                .filter(move |(i, _)| glyph & (1 << i) != 0)
+                .map(move |(_, &(start, end))| (offset + start, offset + end))
+        })
+    })
+}
+
+const LEFT: f32 = 0.;
+const CENTER: f32 = GLYPH_WIDTH / 2.;
+const RIGHT: f32 = GLYPH_WIDTH;
+const TOP: f32 = 1.;
+const MIDDLE: f32 = 0.5;
+const BOTTOM: f32 = 0.;
+
+/// The segments of a glyph from its bottom left corner, indexed by the bits of the glyph masks.
+const SEGMENTS: [(Vec2, Vec2); 18] = [
+    // A1, A2
+    (Vec2::new(LEFT, TOP), Vec2::new(CENTER, TOP)),
+    (Vec2::new(CENTER, TOP), Vec2::new(RIGHT, TOP)),
+    // B, C
+    (Vec2::new(RIGHT, TOP), Vec2::new(RIGHT, MIDDLE)),
+    (Vec2::new(RIGHT, MIDDLE), Vec2::new(RIGHT, BOTTOM)),
+    // D1, D2
+    (Vec2::new(LEFT, BOTTOM), Vec2::new(CENTER, BOTTOM)),
+    (Vec2::new(CENTER, BOTTOM), Vec2::new(RIGHT, BOTTOM)),
+    // E, F
+    (Vec2::new(LEFT, BOTTOM), Vec2::new(LEFT, MIDDLE)),
+    (Vec2::new(LEFT, MIDDLE), Vec2::new(LEFT, TOP)),
+    // G1, G2
+    (Vec2::new(LEFT, MIDDLE), Vec2::new(CENTER, MIDDLE)),
+    (Vec2::new(CENTER, MIDDLE), Vec2::new(RIGHT, MIDDLE)),
+    // H, I, J
+    (Vec2::new(LEFT, TOP), Vec2::new(CENTER, MIDDLE)),
+    (Vec2::new(CENTER, TOP), Vec2::new(CENTER, MIDDLE)),
+    (Vec2::new(RIGHT, TOP), Vec2::new(CENTER, MIDDLE)),
+    // K, L, M
+    (Vec2::new(CENTER, MIDDLE), Vec2::new(LEFT, BOTTOM)),
+    (Vec2::new(CENTER, MIDDLE), Vec2::new(CENTER, BOTTOM)),
+    (Vec2::new(CENTER, MIDDLE), Vec2::new(RIGHT, BOTTOM)),
+    // The dots of `.` and `:`
+    (Vec2::new(CENTER, BOTTOM), Vec2::new(CENTER, 0.1)),
+    (Vec2::new(CENTER, 0.6), Vec2::new(CENTER, 0.7)),
+];
+
+const A1: u32 = 1 << 0;
+const A2: u32 = 1 << 1;
+const B: u32 = 1 << 2;
+const C: u32 = 1 << 3;
+const D1: u32 = 1 << 4;
+const D2: u32 = 1 << 5;
+const E: u32 = 1 << 6;
+const F: u32 = 1 << 7;
+const G1: u32 = 1 << 8;
+const G2: u32 = 1 << 9;
+const H: u32 = 1 << 10;
+const I: u32 = 1 << 11;
+const J: u32 = 1 << 12;
+const K: u32 = 1 << 13;
+const L: u32 = 1 << 14;
+const M: u32 = 1 << 15;
+const DOT: u32 = 1 << 16;
+const UPPER_DOT: u32 = 1 << 17;
+
+const A: u32 = A1 | A2;
+const D: u32 = D1 | D2;
+const G: u32 = G1 | G2;
+
+/// The mask of the [`SEGMENTS`] drawn for `c`.
+fn glyph(c: char) -> u32 {
+    match c.to_ascii_uppercase() {
+        ' ' => 0,
+        '0' => A | B | C | D | E | F | J | K,
+        '1' => B | C | J,
+        '2' => A | B | G | E | D,
+        '3' => A | B | C | D | G2,
+        '4' => F | G | B | C,
+        '5' | 'S' => A | F | G | C | D,
+        '6' => A | F | E | D | C | G,
+        '7' => A | B | C,
+        '8' => A | B | C | D | E | F | G,
+        '9' => A | B | C | D | F | G,
+        'A' => A | B | C | E | F | G,
+        'B' => A | B | C | D | I | L | G2,
+        'C' => A | F | E | D,
+        'D' => A | B | C | D | I | L,
+        'E' => A | F | E | D | G1,
+        'F' => A | F | E | G1,
+        'G' => A | F | E | D | C | G2,
+        'H' => F | E | B | C | G,
+        'I' => A | I | L | D,
+        'J' => B | C | D | E,
+        'K' => F | E | G1 | J | M,
+        'L' => F | E | D,
+        'M' => F | E | B | C | H | J,
+        'N' => F | E | B | C | H | M,
+        'O' => A | B | C | D | E | F,
+        'P' => A | B | F | E | G,
+        'Q' => A | B | C | D | E | F | M,
+        'R' => A | B | F | E | G | M,
+        'T' => A | I | L,
+        'U' => F | E | D | C | B,
+        'V' => F | E | K | J,
+        'W' => F | E | B | C | K | M,
+        'X' => H | J | K | M,
+        'Y' => H | J | L,
+        'Z' => A | J | K | D,
+        '-' => G,
+        '_' => D,
+        '+' => G | I | L,
+        '*' => G | H | I | J | K | L | M,
+        '=' => G | D,
+        '/' => J | K,
+        '\\' => H | M,
+        '|' => I | L,
+        '(' | '<' => J | M,
+        ')' | '>' => H | K,
+        '[' => A2 | I | L | D2,
+        ']' => A1 | I | L | D1,
+        '\'' => I,
+        '"' => F | I,
+        '.' => DOT,
+        ',' => K,
+        ':' => DOT | UPPER_DOT,
+        '!' => I | DOT,
+        '#' => B | C | E | F | G | I | L,
+        '%' => A1 | F | G1 | I | J | K | L | G2 | C | D2,
+        _ => A | B | G2 | L,
+    }
+}
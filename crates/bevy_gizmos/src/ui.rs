@@ -0,0 +1,112 @@
+//! Gizmos drawn in the coordinates of the UI.
+//!
+//! Includes the [`UiGizmos`] system parameter, enabled by the `bevy_ui` feature.
+
+use crate::{gizmos::Gizmos, screen::ScreenGizmos};
+use bevy_ecs::system::{Query, Res, SystemParam};
+use bevy_math::Vec2;
+use bevy_render::{camera::Camera, color::Color};
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::{DefaultUiCamera, Node, UiScale};
+use std::f32::consts::FRAC_PI_6;
+
+/// A [`SystemParam`] drawing gizmos in the coordinates of the UI, for debugging widgets.
+///
+/// Positions and sizes are in the logical pixels UI nodes are laid out in, from the top left
+/// corner of the window, so they match the [`Node`] sizes and [`GlobalTransform`] translations
+/// of the UI and scale with the [`UiScale`].
+///
+/// The gizmos are drawn in the viewport of the default UI camera, which shows the UI of
+/// the primary window, with [`Gizmos::screen`]. They are drawn with the scene of that camera,
+/// so UI nodes are drawn over them.
+///
+/// # Example
+/// ```
+/// # use bevy_gizmos::prelude::*;
+/// # use bevy_render::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use bevy_transform::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::{Interaction, Node};
+/// fn system(mut gizmos: UiGizmos, nodes: Query<(&Node, &GlobalTransform, &Interaction)>) {
+///     for (node, transform, interaction) in &nodes {
+///         if *interaction == Interaction::Hovered {
+///             gizmos.node(node, transform, Color::YELLOW);
+///         }
+///     }
+///     gizmos.arrow(Vec2::new(20., 20.), Vec2::new(200., 80.), Color::CYAN);
+/// }
+/// # bevy_ecs::system::assert_is_system(system);
+/// ```
+#[derive(SystemParam)]
+pub struct UiGizmos<'w, 's> {
+    gizmos: Gizmos<'s>,
+    default_ui_camera: DefaultUiCamera<'w, 's>,
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform)>,
+    ui_scale: Res<'w, UiScale>,
+}
+
+impl UiGizmos<'_, '_> {
+    /// Draws with `draw` in the viewport of the default UI camera, if there is one,
+    /// passing it the scale from UI coordinates to the viewport.
+    fn draw(&mut self, draw: impl FnOnce(&mut ScreenGizmos, f32)) {
+        let Some((camera, camera_transform)) = self
+            .default_ui_camera
+            .get()
+            .and_then(|camera| self.cameras.get(camera).ok())
+        else {
+            return;
+        };
+        draw(
+            &mut self.gizmos.screen(camera, camera_transform),
+            self.ui_scale.0,
+        );
+    }
+
+    /// Draw a line from `start` to `end`.
+    pub fn line(&mut self, start: Vec2, end: Vec2, color: Color) {
+        self.draw(|screen, scale| screen.line(start * scale, end * scale, color));
+    }
+
+    /// Draw lines between a list of points.
+    pub fn linestrip(&mut self, positions: impl IntoIterator<Item = Vec2>, color: Color) {
+        self.draw(|screen, scale| {
+            screen.linestrip(
+                positions.into_iter().map(|position| position * scale),
+                color,
+            );
+        });
+    }
+
+    /// Draw an arrow from `start` to `end`, with a tip a tenth of its length,
+    /// such as to show where something is dragged.
+    pub fn arrow(&mut self, start: Vec2, end: Vec2, color: Color) {
+        let Some(direction) = (start - end).try_normalize() else {
+            return;
+        };
+        let tip_length = start.distance(end) / 10.;
+        let [left, right] = [FRAC_PI_6, -FRAC_PI_6]
+            .map(|angle| end + Vec2::from_angle(angle).rotate(direction) * tip_length);
+        self.draw(|screen, scale| {
+            screen.line(start * scale, end * scale, color);
+            screen.linestrip([left * scale, end * scale, right * scale], color);
+        });
+    }
+
+    /// Draw a rectangle of `size` centered on `position`.
+    pub fn rect(&mut self, position: Vec2, size: Vec2, color: Color) {
+        self.draw(|screen, scale| screen.rect(position * scale, size * scale, color));
+    }
+
+    /// Draw the outline of a UI [`Node`] with the given [`GlobalTransform`].
+    pub fn node(&mut self, node: &Node, transform: &GlobalTransform, color: Color) {
+        self.rect(transform.translation().truncate(), node.size(), color);
+    }
+
+    /// Draw `text` centered on `position`, with capital letters `size` pixels high.
+    ///
+    /// See [`Gizmos::text`] for how the text is drawn.
+    pub fn text(&mut self, position: Vec2, text: &str, size: f32, color: Color) {
+        self.draw(|screen, scale| screen.text(position * scale, text, size * scale, color));
+    }
+}
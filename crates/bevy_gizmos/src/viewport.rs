@@ -0,0 +1,87 @@
+//! Gizmos for the area visible by 2D cameras.
+//!
+//! Includes the [`ViewportGizmo`] component and the system drawing it.
+
+use crate::{color_from_entity, gizmos::Gizmos, GizmoConfig};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::With,
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    camera::{Camera, OrthographicProjection},
+    color::Color,
+};
+use bevy_transform::components::GlobalTransform;
+
+/// Configuration for drawing the area visible by 2D cameras.
+#[derive(Clone, Default, Reflect)]
+#[reflect(Default)]
+pub struct ViewportGizmoConfig {
+    /// Draws the visible area of all cameras with an [`OrthographicProjection`] when set to `true`.
+    ///
+    /// To draw a specific camera's visible area, you can add the [`ViewportGizmo`] component.
+    ///
+    /// Defaults to `false`.
+    pub draw_all: bool,
+    /// The default color for viewport gizmos.
+    ///
+    /// A random color is chosen per camera if `None`.
+    ///
+    /// Defaults to `None`.
+    pub default_color: Option<Color>,
+}
+
+/// Add this [`Component`] to a 2D [`Camera`] to draw the rectangle of the world it sees.
+///
+/// The rectangle is the [`OrthographicProjection::area`], which accounts for the size
+/// of the camera's viewport and the projection's scaling mode and scale,
+/// transformed by the camera's [`GlobalTransform`] onto the XY plane.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct ViewportGizmo {
+    /// The color of the rectangle.
+    ///
+    /// The default color from the [`GizmoConfig`] resource is used if `None`.
+    pub color: Option<Color>,
+}
+
+pub(crate) fn draw_viewports(
+    query: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &OrthographicProjection,
+            Option<&ViewportGizmo>,
+        ),
+        With<Camera>,
+    >,
+    config: Res<GizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    let viewport_config = &config.viewport;
+    for (entity, transform, projection, gizmo) in &query {
+        if !viewport_config.draw_all && gizmo.is_none() {
+            continue;
+        }
+        let color = gizmo
+            .and_then(|gizmo| gizmo.color)
+            .or(viewport_config.default_color)
+            .unwrap_or_else(|| color_from_entity(entity));
+
+        let area = projection.area;
+        let corners = [
+            area.min,
+            Vec2::new(area.max.x, area.min.y),
+            area.max,
+            Vec2::new(area.min.x, area.max.y),
+            area.min,
+        ]
+        .map(|corner| transform.transform_point(corner.extend(0.)).truncate());
+        gizmos.linestrip_2d(corners, color);
+    }
+}
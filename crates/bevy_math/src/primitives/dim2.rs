@@ -60,6 +60,12 @@ impl TryFrom<Vec2> for Direction2d {
     }
 }
 
+impl From<Direction2d> for Vec2 {
+    fn from(value: Direction2d) -> Self {
+        value.0
+    }
+}
+
 impl std::ops::Deref for Direction2d {
     type Target = Vec2;
     fn deref(&self) -> &Self::Target {
@@ -64,6 +64,12 @@ impl TryFrom<Vec3> for Direction3d {
     }
 }
 
+impl From<Direction3d> for Vec3 {
+    fn from(value: Direction3d) -> Self {
+        value.0
+    }
+}
+
 impl std::ops::Deref for Direction3d {
     type Target = Vec3;
     fn deref(&self) -> &Self::Target {
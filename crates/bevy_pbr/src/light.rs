@@ -809,6 +809,36 @@ pub struct Clusters {
 }
 
 impl Clusters {
+    /// The number of clusters in `X` / `Y` / `Z` in the view frustum.
+    #[inline]
+    pub fn dimensions(&self) -> UVec3 {
+        self.dimensions
+    }
+
+    /// The lights assigned to each cluster, indexed by `(y * dimensions.x + x) * dimensions.z + z`.
+    #[inline]
+    pub fn lights(&self) -> &[VisiblePointLights] {
+        &self.lights
+    }
+
+    /// The view space bounds of the cluster at `ijk` that lights were assigned to for `camera`.
+    ///
+    /// Returns `None` if the camera has no viewport size.
+    pub fn cluster_aabb(&self, camera: &Camera, ijk: UVec3) -> Option<Aabb> {
+        let screen_size = camera.physical_viewport_size()?;
+        let projection = camera.projection_matrix();
+        Some(compute_aabb_for_cluster(
+            self.near,
+            self.far,
+            self.tile_size.as_vec2(),
+            screen_size.as_vec2(),
+            projection.inverse(),
+            projection.w_axis.w == 1.0,
+            self.dimensions,
+            ijk,
+        ))
+    }
+
     fn update(&mut self, screen_size: UVec2, requested_dimensions: UVec3) {
         debug_assert!(
             requested_dimensions.x > 0 && requested_dimensions.y > 0 && requested_dimensions.z > 0
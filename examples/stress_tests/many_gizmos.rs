@@ -7,6 +7,8 @@ use bevy::{
 };
 
 const SYSTEM_COUNT: u32 = 10;
+/// The number of segments of each strip in strip mode.
+const STRIP_LENGTH: u32 = 50;
 
 fn main() {
     let mut app = App::new();
@@ -25,6 +27,7 @@ fn main() {
     .insert_resource(Config {
         line_count: 50_000,
         fancy: false,
+        strips: false,
     })
     .add_systems(Startup, setup)
     .add_systems(Update, (input, ui_system));
@@ -40,9 +43,14 @@ fn main() {
 struct Config {
     line_count: u32,
     fancy: bool,
+    strips: bool,
 }
 
-fn input(mut config: ResMut<Config>, input: Res<ButtonInput<KeyCode>>) {
+fn input(
+    mut config: ResMut<Config>,
+    mut gizmo_config: ResMut<GizmoConfig>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
     if input.just_pressed(KeyCode::ArrowUp) {
         config.line_count += 10_000;
     }
@@ -52,10 +60,32 @@ fn input(mut config: ResMut<Config>, input: Res<ButtonInput<KeyCode>>) {
     if input.just_pressed(KeyCode::Space) {
         config.fancy = !config.fancy;
     }
+    if input.just_pressed(KeyCode::KeyS) {
+        config.strips = !config.strips;
+    }
+    if input.just_pressed(KeyCode::KeyI) {
+        gizmo_config.indexed_strips = !gizmo_config.indexed_strips;
+    }
 }
 
 fn system(config: Res<Config>, time: Res<Time>, mut draw: Gizmos) {
-    if !config.fancy {
+    if config.strips {
+        // Draws the same number of lines, but as strips sharing their points.
+        let strip_count = config.line_count / SYSTEM_COUNT / STRIP_LENGTH;
+        for i in 0..strip_count {
+            let angle = i as f32 / strip_count as f32 * TAU;
+            let direction = Vec2::from(angle.sin_cos());
+            let wave = time.elapsed_seconds() + angle;
+
+            draw.linestrip(
+                (0..=STRIP_LENGTH).map(|j| {
+                    let t = j as f32 / STRIP_LENGTH as f32;
+                    (direction * t).extend((wave + t * TAU).sin() * 0.2)
+                }),
+                Color::BLACK,
+            );
+        }
+    } else if !config.fancy {
         for _ in 0..(config.line_count / SYSTEM_COUNT) {
             draw.line(Vec3::NEG_Y, Vec3::Y, Color::BLACK);
         }
@@ -89,7 +119,12 @@ fn setup(mut commands: Commands) {
     ));
 }
 
-fn ui_system(mut query: Query<&mut Text>, config: Res<Config>, diag: Res<DiagnosticsStore>) {
+fn ui_system(
+    mut query: Query<&mut Text>,
+    config: Res<Config>,
+    gizmo_config: Res<GizmoConfig>,
+    diag: Res<DiagnosticsStore>,
+) {
     let mut text = query.single_mut();
 
     let Some(fps) = diag
@@ -101,10 +136,13 @@ fn ui_system(mut query: Query<&mut Text>, config: Res<Config>, diag: Res<Diagnos
 
     text.sections[0].value = format!(
         "Line count: {}\n\
+        Strips: {} (indexed: {})\n\
         FPS: {:.0}\n\n\
         Controls:\n\
         Up/Down: Raise or lower the line count.\n\
-        Spacebar: Toggle fancy mode.",
-        config.line_count, fps,
+        Spacebar: Toggle fancy mode.\n\
+        S: Toggle drawing the lines as strips.\n\
+        I: Toggle indexed strip rendering.",
+        config.line_count, config.strips, gizmo_config.indexed_strips, fps,
     );
 }